@@ -39,12 +39,24 @@ pub struct Reference {
     /// Director
     #[serde(skip_serializing_if = "Option::is_none")]
     pub director: Option<Vec<Name>>,
+    /// Composer
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub composer: Option<Vec<Name>>,
+    /// Illustrator
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub illustrator: Option<Vec<Name>>,
     /// Interviewer
     #[serde(skip_serializing_if = "Option::is_none")]
     pub interviewer: Option<Vec<Name>>,
+    /// Author of a reviewed work
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reviewed_author: Option<Vec<Name>>,
     /// Primary title
     #[serde(skip_serializing_if = "Option::is_none")]
     pub title: Option<String>,
+    /// Title of a reviewed work
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reviewed_title: Option<String>,
     /// Container title (journal, book, etc.)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub container_title: Option<String>,
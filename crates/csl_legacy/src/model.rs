@@ -65,6 +65,7 @@ pub struct Citation {
     pub disambiguate_add_year_suffix: Option<bool>,
     pub disambiguate_add_names: Option<bool>,
     pub disambiguate_add_givenname: Option<bool>,
+    pub givenname_disambiguation_rule: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
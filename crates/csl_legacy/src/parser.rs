@@ -43,6 +43,7 @@ pub fn parse_style(node: Node) -> Result<Style, String> {
         disambiguate_add_year_suffix: None,
         disambiguate_add_names: None,
         disambiguate_add_givenname: None,
+        givenname_disambiguation_rule: None,
     };
     let mut bibliography = None;
 
@@ -181,6 +182,9 @@ fn parse_citation(node: Node) -> Result<Citation, String> {
     let disambiguate_add_givenname = node
         .attribute("disambiguate-add-givenname")
         .map(|s| s == "true");
+    let givenname_disambiguation_rule = node
+        .attribute("givenname-disambiguation-rule")
+        .map(|s| s.to_string());
 
     for child in node.children() {
         if !child.is_element() {
@@ -200,6 +204,7 @@ fn parse_citation(node: Node) -> Result<Citation, String> {
         disambiguate_add_year_suffix,
         disambiguate_add_names,
         disambiguate_add_givenname,
+        givenname_disambiguation_rule,
     })
 }
 
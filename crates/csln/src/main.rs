@@ -2,11 +2,12 @@ use clap::{ArgAction, Args, CommandFactory, Parser, Subcommand, ValueEnum};
 use clap_complete::{Shell, generate};
 use csln_core::locale::RawLocale;
 use csln_core::reference::InputReference;
-use csln_core::{InputBibliography, Locale, Style};
+use csln_core::{CombinedDocument, InputBibliography, Locale, Style};
 use csln_processor::{
-    Bibliography, Citation, CitationItem, DocumentFormat, Processor,
+    Bibliography, Citation, CitationItem, DocumentFormat, Processor, RenderContext,
     io::{load_bibliography, load_citations},
-    processor::document::djot::DjotParser,
+    processor::document::{CitationParser, djot::DjotParser},
+    processor::explain::{Diagnostic, DiagnosticLevel},
     render::{djot::Djot, html::Html, latex::Latex, plain::PlainText},
 };
 #[cfg(feature = "schema")]
@@ -53,6 +54,10 @@ enum OutputFormat {
     Djot,
     Latex,
     Typst,
+    /// The resolved references as a CSL-JSON array (`render refs` only)
+    CslJson,
+    /// The resolved references as BibTeX entries (`render refs` only)
+    Bibtex,
 }
 
 impl std::fmt::Display for OutputFormat {
@@ -63,6 +68,8 @@ impl std::fmt::Display for OutputFormat {
             OutputFormat::Djot => write!(f, "djot"),
             OutputFormat::Latex => write!(f, "latex"),
             OutputFormat::Typst => write!(f, "typst"),
+            OutputFormat::CslJson => write!(f, "csl-json"),
+            OutputFormat::Bibtex => write!(f, "bibtex"),
         }
     }
 }
@@ -97,6 +104,10 @@ enum Commands {
         shell: Shell,
     },
 
+    /// Measure bibliography rendering throughput
+    #[command(hide = true)]
+    Bench(BenchArgs),
+
     /// Legacy alias for `render doc`
     #[command(hide = true)]
     Doc(LegacyDocArgs),
@@ -119,6 +130,32 @@ enum RenderCommands {
 enum StylesCommands {
     /// List all embedded (builtin) style names
     List,
+
+    /// Validate every style (.yaml, .yml, .json) in a directory, reporting
+    /// likely typos in custom fields
+    ValidateAll(ValidateAllArgs),
+}
+
+#[derive(Args, Debug)]
+struct ValidateAllArgs {
+    /// Directory containing style files
+    #[arg(index = 1)]
+    dir: PathBuf,
+}
+
+#[derive(Args, Debug)]
+struct BenchArgs {
+    /// Style file path or builtin name (apa, mla, ieee, etc.)
+    #[arg(short, long, default_value = "apa")]
+    style: String,
+
+    /// Number of generated references in the bibliography
+    #[arg(short = 'n', long, default_value_t = 1000)]
+    entries: usize,
+
+    /// Number of times to render the bibliography
+    #[arg(short = 'm', long, default_value_t = 5)]
+    iterations: usize,
 }
 
 #[derive(Args, Debug)]
@@ -131,9 +168,13 @@ struct RenderDocArgs {
     #[arg(short, long, required = true)]
     style: String,
 
-    /// Path(s) to bibliography input files (repeat for multiple)
+    /// Path(s) to bibliography input files or directories (repeat for multiple)
     #[arg(short, long, required = true, action = ArgAction::Append)]
     bibliography: Vec<PathBuf>,
+
+    /// Also load reference files in subdirectories of --bibliography directories
+    #[arg(long)]
+    recursive: bool,
     #[arg(short = 'c', long, action = ArgAction::Append)]
     citations: Vec<PathBuf>,
 
@@ -157,14 +198,34 @@ struct RenderDocArgs {
     /// Disable semantic classes (HTML spans, Djot attributes)
     #[arg(long)]
     no_semantics: bool,
+
+    /// Force the processing locale (e.g., de-DE), ignoring the style's default-locale
+    #[arg(short = 'L', long, value_name = "ID")]
+    locale: Option<String>,
+
+    /// Locale for month names in dates (e.g., en-US), independent of the terms locale
+    #[arg(long, value_name = "ID")]
+    date_locale: Option<String>,
+
+    /// Render only the document's citations, without appending a bibliography
+    #[arg(long)]
+    no_bibliography: bool,
+
+    /// Fail without output if rendering produces any warning (e.g., a missing reference or field)
+    #[arg(long)]
+    strict: bool,
 }
 
 #[derive(Args, Debug)]
 struct RenderRefsArgs {
-    /// Path(s) to bibliography input files (repeat for multiple)
+    /// Path(s) to bibliography input files or directories (repeat for multiple)
     #[arg(short, long, required = true, action = ArgAction::Append)]
     bibliography: Vec<PathBuf>,
 
+    /// Also load reference files in subdirectories of --bibliography directories
+    #[arg(long)]
+    recursive: bool,
+
     /// Style file path or builtin name (apa, mla, ieee, etc.)
     #[arg(short, long, required = true)]
     style: String,
@@ -181,6 +242,10 @@ struct RenderRefsArgs {
     #[arg(short = 'k', long, value_delimiter = ',')]
     keys: Option<Vec<String>>,
 
+    /// Limit the bibliography to references cited in --citations
+    #[arg(long, requires = "citations")]
+    only_cited: bool,
+
     /// Show reference keys/IDs in human output
     #[arg(long)]
     show_keys: bool,
@@ -205,6 +270,27 @@ struct RenderRefsArgs {
     /// Disable semantic classes (HTML spans, Djot attributes)
     #[arg(long)]
     no_semantics: bool,
+
+    /// Explain how each citation renders (components, suppression, disambiguation)
+    #[arg(long)]
+    explain: bool,
+
+    /// Write rendering diagnostics (missing fields, suppression, disambiguation) for the
+    /// citations and bibliography entries selected by --mode as JSON
+    #[arg(long, value_name = "FILE")]
+    diagnostics: Option<PathBuf>,
+
+    /// Fail without output if rendering produces any warning (e.g., a missing reference or field)
+    #[arg(long)]
+    strict: bool,
+
+    /// Force the processing locale (e.g., de-DE), ignoring the style's default-locale
+    #[arg(short = 'L', long, value_name = "ID")]
+    locale: Option<String>,
+
+    /// Locale for month names in dates (e.g., en-US), independent of the terms locale
+    #[arg(long, value_name = "ID")]
+    date_locale: Option<String>,
 }
 
 #[derive(Args, Debug)]
@@ -224,6 +310,11 @@ struct CheckArgs {
     /// Output as JSON
     #[arg(long)]
     json: bool,
+
+    /// Also lint the style, e.g. for `custom` keys that look like typos of
+    /// known fields (keys prefixed with `x-` are never flagged)
+    #[arg(long)]
+    validate_deep: bool,
 }
 
 #[cfg(feature = "schema")]
@@ -251,6 +342,12 @@ struct ConvertArgs {
     /// Data type (style, bib, locale, citations)
     #[arg(short = 't', long = "type", value_enum)]
     r#type: Option<DataType>,
+
+    /// Split a combined document (top-level `style`, `bibliography`, and
+    /// `citations` keys) into one file per part, named after the output path
+    /// (e.g., `out.yaml` -> `out-style.yaml`, `out-bib.yaml`, `out-citations.yaml`)
+    #[arg(long, conflicts_with = "type")]
+    split: bool,
 }
 
 #[derive(Args, Debug)]
@@ -285,6 +382,8 @@ struct CheckItem {
     ok: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    warnings: Vec<String>,
 }
 
 fn main() {
@@ -306,6 +405,7 @@ fn run() -> Result<(), Box<dyn Error>> {
         Commands::Convert(args) => run_convert(args),
         Commands::Styles { command } => match command.unwrap_or(StylesCommands::List) {
             StylesCommands::List => run_styles_list(),
+            StylesCommands::ValidateAll(args) => run_styles_validate_all(args),
         },
         #[cfg(feature = "schema")]
         Commands::Schema(args) => run_schema(args),
@@ -315,6 +415,7 @@ fn run() -> Result<(), Box<dyn Error>> {
             generate(shell, &mut cmd, name, &mut std::io::stdout());
             Ok(())
         }
+        Commands::Bench(args) => run_bench(args),
         Commands::Doc(args) => {
             eprintln!(
                 "Warning: `csln doc` is deprecated. Use `csln render doc` with positional input."
@@ -323,11 +424,16 @@ fn run() -> Result<(), Box<dyn Error>> {
                 input: args.document,
                 style: args.style.display().to_string(),
                 bibliography: vec![args.references],
+                recursive: false,
                 citations: Vec::new(),
                 input_format: InputFormat::Djot,
                 format: args.format,
                 output: None,
                 no_semantics: false,
+                locale: None,
+                date_locale: None,
+                no_bibliography: false,
+                strict: false,
             };
             run_render_doc(doc_args)
         }
@@ -338,6 +444,7 @@ fn run() -> Result<(), Box<dyn Error>> {
                 bibliography: Vec::new(),
                 citations: Vec::new(),
                 json: false,
+                validate_deep: false,
             })
         }
     }
@@ -409,6 +516,72 @@ fn run_styles_list() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+fn run_styles_validate_all(args: ValidateAllArgs) -> Result<(), Box<dyn Error>> {
+    let checks = validate_style_dir(&args.dir)?;
+    for check in &checks {
+        if check.ok {
+            println!("OK   {}", check.path);
+            for warning in &check.warnings {
+                println!("  warning: {}", warning);
+            }
+        } else {
+            println!("FAIL {}", check.path);
+            if let Some(err) = &check.error {
+                println!("  -> {}", err);
+            }
+        }
+    }
+
+    let failed = checks.iter().filter(|c| !c.ok).count();
+    println!();
+    println!(
+        "{} styles: {} passed, {} failed",
+        checks.len(),
+        checks.len() - failed,
+        failed
+    );
+
+    if failed > 0 {
+        return Err(format!("{} of {} styles failed validation.", failed, checks.len()).into());
+    }
+    Ok(())
+}
+
+/// Load and deep-validate every style file in `dir`, in file name order.
+fn validate_style_dir(dir: &Path) -> Result<Vec<CheckItem>, Box<dyn Error>> {
+    let mut paths: Vec<PathBuf> = fs::read_dir(dir)?
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| {
+            path.is_file()
+                && matches!(
+                    path.extension().and_then(|e| e.to_str()),
+                    Some("yaml" | "yml" | "json")
+                )
+        })
+        .collect();
+    paths.sort();
+
+    Ok(paths
+        .into_iter()
+        .map(|path| match load_style(&path, false) {
+            Ok(style) => CheckItem {
+                kind: "style",
+                path: path.display().to_string(),
+                ok: true,
+                error: None,
+                warnings: custom_field_typos(&style),
+            },
+            Err(e) => CheckItem {
+                kind: "style",
+                path: path.display().to_string(),
+                ok: false,
+                error: Some(e.to_string()),
+                warnings: Vec::new(),
+            },
+        })
+        .collect())
+}
+
 fn truncate(s: &str, max_len: usize) -> String {
     if s.len() <= max_len {
         s.to_string()
@@ -417,9 +590,57 @@ fn truncate(s: &str, max_len: usize) -> String {
     }
 }
 
+fn run_bench(args: BenchArgs) -> Result<(), Box<dyn Error>> {
+    let elapsed = bench_bibliography(&args)?;
+    let rendered = args.entries * args.iterations;
+    let seconds = elapsed.as_secs_f64();
+    println!(
+        "Rendered {} entries x {} iterations in {:.3}s ({:.0} entries/sec)",
+        args.entries,
+        args.iterations,
+        seconds,
+        if seconds > 0.0 {
+            rendered as f64 / seconds
+        } else {
+            0.0
+        }
+    );
+    Ok(())
+}
+
+/// Render a generated bibliography repeatedly, returning the total time
+/// spent rendering. Each iteration uses a fresh processor so caches from a
+/// previous run do not skew the measurement.
+fn bench_bibliography(args: &BenchArgs) -> Result<std::time::Duration, Box<dyn Error>> {
+    let style = load_any_style(&args.style, false)?;
+    let bibliography: Bibliography = (0..args.entries)
+        .map(|i| {
+            let id = format!("ref{}", i);
+            let family = format!("Author{}", i % 97);
+            let year = 1950 + (i % 75) as i32;
+            let title = format!("Generated Work Number {}", i);
+            let reference = if i % 2 == 0 {
+                csln_core::ref_book!(&id, &family, "Alex", year, &title)
+            } else {
+                csln_core::ref_article!(&id, &family, "Alex", year, &title)
+            };
+            (id, reference)
+        })
+        .collect();
+
+    let mut elapsed = std::time::Duration::ZERO;
+    for _ in 0..args.iterations {
+        let processor = create_processor(style.clone(), bibliography.clone(), &args.style, None);
+        let start = std::time::Instant::now();
+        std::hint::black_box(processor.render_bibliography());
+        elapsed += start.elapsed();
+    }
+    Ok(elapsed)
+}
+
 fn run_render_doc(args: RenderDocArgs) -> Result<(), Box<dyn Error>> {
     let style_obj = load_any_style(&args.style, args.no_semantics)?;
-    let bibliography = load_merged_bibliography(&args.bibliography)?;
+    let bibliography = load_merged_bibliography(&args.bibliography, args.recursive)?;
 
     if !args.citations.is_empty() {
         eprintln!(
@@ -427,15 +648,37 @@ fn run_render_doc(args: RenderDocArgs) -> Result<(), Box<dyn Error>> {
         );
     }
 
-    let processor = create_processor(style_obj, bibliography, &args.style);
+    let mut processor =
+        create_processor(style_obj, bibliography, &args.style, args.locale.as_deref());
+    if let Some(locale_id) = &args.date_locale {
+        processor.set_date_locale(resolve_locale(locale_id, &args.style));
+    }
 
     let doc_content = fs::read_to_string(&args.input)?;
+    if args.strict {
+        let citations: Vec<Citation> = DjotParser
+            .parse_citations(&doc_content)
+            .into_iter()
+            .map(|(_, _, citation)| citation)
+            .collect();
+        // The appended bibliography lists each cited reference once.
+        let mut bib_ids: Vec<String> = Vec::new();
+        if !args.no_bibliography {
+            for item in citations.iter().flat_map(|citation| &citation.items) {
+                if !bib_ids.contains(&item.id) {
+                    bib_ids.push(item.id.clone());
+                }
+            }
+        }
+        check_strict(&processor, &citations, &bib_ids)?;
+    }
     let output = match args.input_format {
         InputFormat::Djot => render_doc_with_output_format(
             &processor,
             &doc_content,
             args.format,
             DocumentInput::Djot,
+            !args.no_bibliography,
         )?,
         InputFormat::Markdown => {
             return Err(
@@ -449,9 +692,9 @@ fn run_render_doc(args: RenderDocArgs) -> Result<(), Box<dyn Error>> {
 
 fn run_render_refs(args: RenderRefsArgs) -> Result<(), Box<dyn Error>> {
     let style_obj = load_any_style(&args.style, args.no_semantics)?;
-    let bibliography = load_merged_bibliography(&args.bibliography)?;
+    let bibliography = load_merged_bibliography(&args.bibliography, args.recursive)?;
 
-    let item_ids = if let Some(k) = args.keys.clone() {
+    let mut item_ids = if let Some(k) = args.keys.clone() {
         k
     } else {
         bibliography.keys().cloned().collect()
@@ -463,7 +706,40 @@ fn run_render_refs(args: RenderRefsArgs) -> Result<(), Box<dyn Error>> {
         Some(load_merged_citations(&args.citations)?)
     };
 
-    let processor = create_processor(style_obj, bibliography, &args.style);
+    if args.only_cited
+        && let Some(citations) = &input_citations
+    {
+        let cited: HashSet<&str> = citations
+            .iter()
+            .flat_map(|citation| &citation.items)
+            .map(|item| item.id.as_str())
+            .collect();
+        item_ids.retain(|id| cited.contains(id.as_str()));
+    }
+
+    let mut processor =
+        create_processor(style_obj, bibliography, &args.style, args.locale.as_deref());
+    if let Some(locale_id) = &args.date_locale {
+        processor.set_date_locale(resolve_locale(locale_id, &args.style));
+    }
+
+    // Diagnose what the chosen mode renders: citations, bibliography, or both.
+    if args.diagnostics.is_some() || args.strict {
+        let citations = match args.mode {
+            RenderMode::Bib => Vec::new(),
+            _ => citations_or_default(&item_ids, input_citations.clone()),
+        };
+        let bib_ids = match args.mode {
+            RenderMode::Cite => &[][..],
+            _ => &item_ids[..],
+        };
+        if let Some(path) = &args.diagnostics {
+            write_diagnostics(&processor, &citations, bib_ids, path)?;
+        }
+        if args.strict {
+            check_strict(&processor, &citations, bib_ids)?;
+        }
+    }
 
     let style_name = {
         let path = Path::new(&args.style);
@@ -476,7 +752,9 @@ fn run_render_refs(args: RenderRefsArgs) -> Result<(), Box<dyn Error>> {
         }
     };
 
-    let output = if args.json {
+    let output = if args.explain {
+        render_refs_explain(&processor, &item_ids, input_citations, args.json)?
+    } else if args.json {
         render_refs_json(
             &processor,
             &style_name,
@@ -500,28 +778,41 @@ fn run_render_refs(args: RenderRefsArgs) -> Result<(), Box<dyn Error>> {
     write_output(&output, args.output.as_ref())
 }
 
-fn create_processor(style: Style, bib: Bibliography, style_input: &str) -> Processor {
+fn create_processor(
+    mut style: Style,
+    bib: Bibliography,
+    style_input: &str,
+    forced_locale: Option<&str>,
+) -> Processor {
+    if let Some(locale_id) = forced_locale {
+        style.info.default_locale = Some(locale_id.to_string());
+    }
     if let Some(ref locale_id) = style.info.default_locale {
-        let path = Path::new(style_input);
-        let locale = if path.exists() && path.is_file() {
-            // File-based style: search for locale on disk, fall back to embedded.
-            let locales_dir = find_locales_dir(style_input);
-            let disk_locale = Locale::load(locale_id, &locales_dir);
-            if disk_locale.locale == *locale_id || locale_id == "en-US" {
-                disk_locale
-            } else {
-                load_locale_builtin(locale_id)
-            }
-        } else {
-            // Builtin style: use embedded locale directly.
-            load_locale_builtin(locale_id)
-        };
+        let locale = resolve_locale(locale_id, style_input);
         Processor::with_locale(style, bib, locale)
     } else {
         Processor::new(style, bib)
     }
 }
 
+/// Load a locale by ID, looking next to a file-based style first.
+fn resolve_locale(locale_id: &str, style_input: &str) -> Locale {
+    let path = Path::new(style_input);
+    if path.exists() && path.is_file() {
+        // File-based style: search for locale on disk, fall back to embedded.
+        let locales_dir = find_locales_dir(style_input);
+        let disk_locale = Locale::load(locale_id, &locales_dir);
+        if disk_locale.locale == locale_id || locale_id == "en-US" {
+            disk_locale
+        } else {
+            load_locale_builtin(locale_id)
+        }
+    } else {
+        // Builtin style: use embedded locale directly.
+        load_locale_builtin(locale_id)
+    }
+}
+
 /// Load a style from a file path, or fallback to builtin name / alias.
 fn load_any_style(style_input: &str, no_semantics: bool) -> Result<Style, Box<dyn Error>> {
     let path = Path::new(style_input);
@@ -562,17 +853,23 @@ fn run_check(args: CheckArgs) -> Result<(), Box<dyn Error>> {
 
     if let Some(style_input) = args.style {
         let status = match load_any_style(&style_input, false) {
-            Ok(_) => CheckItem {
+            Ok(style) => CheckItem {
                 kind: "style",
                 path: style_input,
                 ok: true,
                 error: None,
+                warnings: if args.validate_deep {
+                    custom_field_typos(&style)
+                } else {
+                    Vec::new()
+                },
             },
             Err(e) => CheckItem {
                 kind: "style",
                 path: style_input,
                 ok: false,
                 error: Some(e.to_string()),
+                warnings: Vec::new(),
             },
         };
         checks.push(status);
@@ -586,12 +883,14 @@ fn run_check(args: CheckArgs) -> Result<(), Box<dyn Error>> {
                 path: display,
                 ok: true,
                 error: None,
+                warnings: Vec::new(),
             },
             Err(e) => CheckItem {
                 kind: "bibliography",
                 path: display,
                 ok: false,
                 error: Some(e.to_string()),
+                warnings: Vec::new(),
             },
         };
         checks.push(status);
@@ -605,12 +904,14 @@ fn run_check(args: CheckArgs) -> Result<(), Box<dyn Error>> {
                 path: display,
                 ok: true,
                 error: None,
+                warnings: Vec::new(),
             },
             Err(e) => CheckItem {
                 kind: "citations",
                 path: display,
                 ok: false,
                 error: Some(e.to_string()),
+                warnings: Vec::new(),
             },
         };
         checks.push(status);
@@ -622,6 +923,9 @@ fn run_check(args: CheckArgs) -> Result<(), Box<dyn Error>> {
         for check in &checks {
             if check.ok {
                 println!("OK   {:<12} {}", check.kind, check.path);
+                for warning in &check.warnings {
+                    println!("  warning: {}", warning);
+                }
             } else {
                 println!("FAIL {:<12} {}", check.kind, check.path);
                 if let Some(err) = &check.error {
@@ -638,7 +942,117 @@ fn run_check(args: CheckArgs) -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+/// Fields accepted at each level of a style that carries a `custom` map.
+const STYLE_FIELDS: &[&str] = &[
+    "version",
+    "info",
+    "templates",
+    "options",
+    "citation",
+    "bibliography",
+];
+const CITATION_FIELDS: &[&str] = &[
+    "options",
+    "use-preset",
+    "template",
+    "wrap",
+    "citation-number-form",
+    "prefix",
+    "suffix",
+    "delimiter",
+    "multi-cite-delimiter",
+    "merge-parentheticals",
+    "sort",
+    "integral",
+    "non-integral",
+];
+const BIBLIOGRAPHY_FIELDS: &[&str] = &[
+    "options",
+    "use-preset",
+    "template",
+    "type-templates",
+    "sort",
+    "groups",
+    "keyword-groups",
+];
+const OPTIONS_FIELDS: &[&str] = &[
+    "substitute",
+    "processing",
+    "localize",
+    "multilingual",
+    "contributors",
+    "dates",
+    "titles",
+    "page-range-format",
+    "bibliography",
+    "links",
+    "link-precedence",
+    "suppress-access-when-doi",
+    "punctuation-in-quote",
+    "volume-pages-delimiter",
+    "semantic-classes",
+    "strip-periods",
+    "note-marker",
+];
+
+/// Report `custom` keys within a small edit distance of a field the
+/// enclosing section accepts, e.g. `delimeter` for `delimiter`.
+fn custom_field_typos(style: &Style) -> Vec<String> {
+    let mut sections = vec![("", style.custom.as_ref(), STYLE_FIELDS)];
+    let options = [
+        ("options.", style.options.as_ref()),
+        (
+            "citation.options.",
+            style.citation.as_ref().and_then(|c| c.options.as_ref()),
+        ),
+        (
+            "bibliography.options.",
+            style.bibliography.as_ref().and_then(|b| b.options.as_ref()),
+        ),
+    ];
+    for (path, options) in options {
+        if let Some(options) = options {
+            sections.push((path, options.custom.as_ref(), OPTIONS_FIELDS));
+        }
+    }
+    if let Some(citation) = &style.citation {
+        sections.push(("citation.", citation.custom.as_ref(), CITATION_FIELDS));
+    }
+    if let Some(bibliography) = &style.bibliography {
+        sections.push((
+            "bibliography.",
+            bibliography.custom.as_ref(),
+            BIBLIOGRAPHY_FIELDS,
+        ));
+    }
+
+    let mut warnings = Vec::new();
+    for (path, custom, fields) in sections {
+        let mut keys: Vec<_> = custom.into_iter().flat_map(|c| c.keys()).collect();
+        keys.sort();
+        for key in keys.into_iter().filter(|k| !k.starts_with("x-")) {
+            if let Some(field) = fields
+                .iter()
+                .filter(|f| strsim::levenshtein(key, f) <= 2)
+                .min_by_key(|f| strsim::levenshtein(key, f))
+            {
+                warnings.push(format!(
+                    "{path}custom.{key}: unknown key, likely a typo of `{field}`"
+                ));
+            }
+        }
+    }
+    warnings
+}
+
 fn run_convert(args: ConvertArgs) -> Result<(), Box<dyn Error>> {
+    if args.split {
+        for path in split_combined_document(&args.input, &args.output)? {
+            println!("Wrote {}", path.display());
+        }
+        return Ok(());
+    }
+
     let input_bytes = fs::read(&args.input)?;
     let input_ext = args
         .input
@@ -677,12 +1091,17 @@ fn run_convert(args: ConvertArgs) -> Result<(), Box<dyn Error>> {
         DataType::Bib => {
             let bib_obj = load_bibliography(&args.input)?;
             let references: Vec<InputReference> = bib_obj.into_iter().map(|(_, r)| r).collect();
-            let input_bib = InputBibliography {
-                references,
-                ..Default::default()
-            };
-            let out_bytes = serialize_any(&input_bib, output_ext)?;
-            fs::write(&args.output, out_bytes)?;
+            if output_ext == "bib" {
+                let entries: Vec<String> = references.iter().map(|r| r.to_bibtex()).collect();
+                fs::write(&args.output, entries.join("\n"))?;
+            } else {
+                let input_bib = InputBibliography {
+                    references,
+                    ..Default::default()
+                };
+                let out_bytes = serialize_any(&input_bib, output_ext)?;
+                fs::write(&args.output, out_bytes)?;
+            }
         }
         DataType::Locale => {
             let locale: RawLocale = deserialize_any(&input_bytes, input_ext)?;
@@ -705,6 +1124,56 @@ fn run_convert(args: ConvertArgs) -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+/// Split a combined document into separate style, bibliography, and citations
+/// files, returning the paths written.
+fn split_combined_document(input: &Path, output: &Path) -> Result<Vec<PathBuf>, Box<dyn Error>> {
+    let ext_of = |path: &Path| {
+        path.extension()
+            .and_then(|e| e.to_str())
+            .unwrap_or("yaml")
+            .to_string()
+    };
+    let output_ext = ext_of(output);
+    let doc: CombinedDocument = deserialize_any(&fs::read(input)?, &ext_of(input)).map_err(|e| {
+        format!(
+            "{} is not a combined document (expected top-level `style`, `bibliography`, or `citations` keys): {}",
+            input.display(),
+            e
+        )
+    })?;
+
+    let stem = output
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or("document");
+    let part_path = |part: &str| output.with_file_name(format!("{stem}-{part}.{output_ext}"));
+
+    let mut written = Vec::new();
+    if let Some(style) = &doc.style {
+        let path = part_path("style");
+        fs::write(&path, serialize_any(style, &output_ext)?)?;
+        written.push(path);
+    }
+    if let Some(bibliography) = &doc.bibliography {
+        let path = part_path("bib");
+        fs::write(&path, serialize_any(bibliography, &output_ext)?)?;
+        written.push(path);
+    }
+    if let Some(citations) = &doc.citations {
+        let path = part_path("citations");
+        fs::write(&path, serialize_any(citations, &output_ext)?)?;
+        written.push(path);
+    }
+    if written.is_empty() {
+        return Err(format!(
+            "{} has no style, bibliography, or citations",
+            input.display()
+        )
+        .into());
+    }
+    Ok(written)
+}
+
 enum DocumentInput {
     Djot,
 }
@@ -714,30 +1183,63 @@ fn render_doc_with_output_format(
     content: &str,
     output_format: OutputFormat,
     input_format: DocumentInput,
+    with_bibliography: bool,
 ) -> Result<String, Box<dyn Error>> {
     let doc_format = to_document_format(output_format)?;
 
+    fn render<F>(
+        processor: &Processor,
+        content: &str,
+        doc_format: DocumentFormat,
+        with_bibliography: bool,
+    ) -> String
+    where
+        F: csln_processor::render::format::OutputFormat<Output = String>,
+    {
+        let parser = DjotParser;
+        if with_bibliography {
+            processor.process_document::<_, F>(content, &parser, doc_format)
+        } else {
+            processor.process_document_citations::<_, F>(content, &parser, doc_format)
+        }
+    }
+
     match input_format {
-        DocumentInput::Djot => {
-            let parser = DjotParser;
-            match output_format {
-                OutputFormat::Plain => {
-                    Ok(processor.process_document::<_, PlainText>(content, &parser, doc_format))
-                }
-                OutputFormat::Html => {
-                    Ok(processor.process_document::<_, Html>(content, &parser, doc_format))
-                }
-                OutputFormat::Djot => {
-                    Ok(processor.process_document::<_, Djot>(content, &parser, doc_format))
-                }
-                OutputFormat::Latex => {
-                    Ok(processor.process_document::<_, Latex>(content, &parser, doc_format))
-                }
-                OutputFormat::Typst => Err(
-                    "Output format `typst` is not implemented yet for document rendering.".into(),
-                ),
+        DocumentInput::Djot => match output_format {
+            OutputFormat::Plain => Ok(render::<PlainText>(
+                processor,
+                content,
+                doc_format,
+                with_bibliography,
+            )),
+            OutputFormat::Html => Ok(render::<Html>(
+                processor,
+                content,
+                doc_format,
+                with_bibliography,
+            )),
+            OutputFormat::Djot => Ok(render::<Djot>(
+                processor,
+                content,
+                doc_format,
+                with_bibliography,
+            )),
+            OutputFormat::Latex => Ok(render::<Latex>(
+                processor,
+                content,
+                doc_format,
+                with_bibliography,
+            )),
+            OutputFormat::Typst => {
+                Err("Output format `typst` is not implemented yet for document rendering.".into())
             }
-        }
+            OutputFormat::CslJson => {
+                Err("Output format `csl-json` is only supported by `render refs`.".into())
+            }
+            OutputFormat::Bibtex => {
+                Err("Output format `bibtex` is only supported by `render refs`.".into())
+            }
+        },
     }
 }
 
@@ -750,6 +1252,12 @@ fn to_document_format(output_format: OutputFormat) -> Result<DocumentFormat, Box
         OutputFormat::Typst => {
             Err("Output format `typst` is not implemented yet for document rendering.".into())
         }
+        OutputFormat::CslJson => {
+            Err("Output format `csl-json` is only supported by `render refs`.".into())
+        }
+        OutputFormat::Bibtex => {
+            Err("Output format `bibtex` is only supported by `render refs`.".into())
+        }
     }
 }
 
@@ -784,6 +1292,8 @@ fn render_refs_human(
         OutputFormat::Typst => {
             Err("Output format `typst` is not implemented yet for reference rendering.".into())
         }
+        OutputFormat::CslJson => Ok(export_csl_json(processor, item_ids)?),
+        OutputFormat::Bibtex => Ok(export_bibtex(processor, item_ids)),
     }
 }
 
@@ -813,9 +1323,132 @@ fn render_refs_json(
         OutputFormat::Typst => {
             Err("Output format `typst` is not implemented yet for JSON reference rendering.".into())
         }
+        OutputFormat::CslJson => Ok(export_csl_json(processor, item_ids)?),
+        OutputFormat::Bibtex => Ok(export_bibtex(processor, item_ids)),
     }
 }
 
+/// Serialize the selected references as a CSL-JSON array.
+fn export_csl_json(processor: &Processor, item_ids: &[String]) -> Result<String, Box<dyn Error>> {
+    let references: Vec<csl_legacy::csl_json::Reference> = item_ids
+        .iter()
+        .filter_map(|id| processor.bibliography.get(id))
+        .map(csl_legacy::csl_json::Reference::from)
+        .collect();
+    Ok(serde_json::to_string_pretty(&references)?)
+}
+
+/// Serialize the selected references as BibTeX entries.
+fn export_bibtex(processor: &Processor, item_ids: &[String]) -> String {
+    item_ids
+        .iter()
+        .filter_map(|id| processor.bibliography.get(id))
+        .map(|reference| reference.to_bibtex())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn render_refs_explain(
+    processor: &Processor,
+    item_ids: &[String],
+    citations: Option<Vec<Citation>>,
+    json: bool,
+) -> Result<String, Box<dyn Error>> {
+    use serde_json::json;
+
+    let citations = citations_or_default(item_ids, citations);
+
+    let explanations: Vec<_> = citations
+        .iter()
+        .map(|c| (c.id.clone(), processor.explain_citation(c)))
+        .collect();
+
+    if json {
+        let entries: Vec<_> = explanations
+            .into_iter()
+            .map(|(id, explanation)| json!({ "id": id, "explanation": explanation }))
+            .collect();
+        return Ok(serde_json::to_string_pretty(&entries)?);
+    }
+
+    let mut output = String::new();
+    for (i, (id, explanation)) in explanations.iter().enumerate() {
+        let label = id.clone().unwrap_or_else(|| i.to_string());
+        let _ = writeln!(output, "[{}]\n{}", label, explanation);
+    }
+    Ok(output)
+}
+
+/// Use the given citations, or cite each item on its own.
+fn citations_or_default(item_ids: &[String], citations: Option<Vec<Citation>>) -> Vec<Citation> {
+    citations.unwrap_or_else(|| {
+        item_ids
+            .iter()
+            .map(|id| Citation {
+                id: Some(id.clone()),
+                items: vec![CitationItem {
+                    id: id.clone(),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            })
+            .collect()
+    })
+}
+
+/// Collect diagnostics for each citation and each bibliography entry.
+fn collect_diagnostics(
+    processor: &Processor,
+    citations: &[Citation],
+    bib_ids: &[String],
+) -> Vec<Diagnostic> {
+    let cited = citations
+        .iter()
+        .flat_map(|c| processor.explain_citation(c).diagnostics());
+    let listed = bib_ids.iter().flat_map(|id| {
+        processor
+            .explain_bibliography_entry(id)
+            .diagnostics(RenderContext::Bibliography)
+    });
+    cited.chain(listed).collect()
+}
+
+/// Collect diagnostics for the rendered citations and bibliography entries
+/// and write them to `path` as JSON.
+fn write_diagnostics(
+    processor: &Processor,
+    citations: &[Citation],
+    bib_ids: &[String],
+    path: &Path,
+) -> Result<(), Box<dyn Error>> {
+    let diagnostics = collect_diagnostics(processor, citations, bib_ids);
+    fs::write(path, serde_json::to_string_pretty(&diagnostics)?)?;
+    Ok(())
+}
+
+/// Fail with every warning-level diagnostic for `citations` and `bib_ids`,
+/// if any.
+fn check_strict(
+    processor: &Processor,
+    citations: &[Citation],
+    bib_ids: &[String],
+) -> Result<(), Box<dyn Error>> {
+    let warnings: Vec<String> = collect_diagnostics(processor, citations, bib_ids)
+        .into_iter()
+        .filter(|d| d.level == DiagnosticLevel::Warning)
+        .map(|d| d.message)
+        .collect();
+    if warnings.is_empty() {
+        return Ok(());
+    }
+    Err(format!(
+        "--strict: rendering produced {} warning(s):\n  {}",
+        warnings.len(),
+        warnings.join("\n  ")
+    )
+    .into())
+}
+
 fn find_locales_dir(style_path: &str) -> PathBuf {
     let style_dir = Path::new(style_path).parent().unwrap_or(Path::new("."));
     let candidates = [
@@ -869,22 +1502,53 @@ fn load_locale_builtin(locale_id: &str) -> Locale {
     }
 }
 
-fn load_merged_bibliography(paths: &[PathBuf]) -> Result<Bibliography, Box<dyn Error>> {
+fn load_merged_bibliography(
+    paths: &[PathBuf],
+    recursive: bool,
+) -> Result<Bibliography, Box<dyn Error>> {
     if paths.is_empty() {
         return Err("At least one --bibliography file is required.".into());
     }
 
     let mut merged = Bibliography::new();
     for path in paths {
-        let loaded = load_bibliography(path)?;
-        for (id, reference) in loaded {
-            merged.insert(id, reference);
+        if path.is_dir() {
+            // Skip unparsable files in a directory so one bad file does not
+            // block the rest of the project's references.
+            for file in bibliography_files(path, recursive)? {
+                match load_bibliography(&file) {
+                    Ok(loaded) => merged.extend(loaded),
+                    Err(e) => eprintln!("Warning: skipping {}: {}", file.display(), e),
+                }
+            }
+        } else {
+            merged.extend(load_bibliography(path)?);
         }
     }
 
     Ok(merged)
 }
 
+/// List the reference files (YAML, JSON, CBOR) in a directory, sorted by path.
+fn bibliography_files(dir: &Path, recursive: bool) -> Result<Vec<PathBuf>, Box<dyn Error>> {
+    let mut files = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            if recursive {
+                files.extend(bibliography_files(&path, true)?);
+            }
+        } else if matches!(
+            path.extension().and_then(|e| e.to_str()),
+            Some("yaml" | "yml" | "json" | "cbor")
+        ) {
+            files.push(path);
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
 fn load_merged_citations(paths: &[PathBuf]) -> Result<Vec<Citation>, Box<dyn Error>> {
     let mut merged = Vec::new();
     for path in paths {
@@ -971,8 +1635,12 @@ where
     if show_cite {
         if let Some(cite_list) = citations {
             let _ = writeln!(output, "CITATIONS (From file):");
+            let mut cited_before = HashSet::new();
             for (i, citation) in cite_list.iter().enumerate() {
-                match processor.process_citation_with_format::<F>(citation) {
+                let rendered =
+                    processor.process_citation_after_with_format::<F>(citation, &cited_before);
+                cited_before.extend(citation.items.iter().map(|item| item.id.clone()));
+                match rendered {
                     Ok(text) => {
                         if show_keys {
                             let _ = writeln!(
@@ -1054,29 +1722,25 @@ where
         let _ = writeln!(output, "BIBLIOGRAPHY:");
         let filter: HashSet<&str> = item_ids.iter().map(|id| id.as_str()).collect();
         let processed = processor.process_references();
-        let mut rendered_entries = Vec::new();
-
-        for entry in processed.bibliography {
-            if filter.contains(entry.id.as_str()) {
-                let text =
-                    csln_processor::render::refs_to_string_with_format::<F>(vec![entry.clone()]);
-                let trimmed = text.trim();
-                if !trimmed.is_empty() {
-                    if show_keys {
-                        rendered_entries.push(format!("  [{}] {}", entry.id, trimmed));
-                    } else {
-                        rendered_entries.push(trimmed.to_string());
-                    }
-                }
-            }
-        }
+        let (entries, texts): (Vec<_>, Vec<_>) = processed
+            .bibliography
+            .into_iter()
+            .filter(|entry| filter.contains(entry.id.as_str()))
+            .map(|entry| {
+                let text = csln_processor::render::ref_to_string_with_format::<F>(&entry);
+                (entry, text.trim().to_string())
+            })
+            .filter(|(_, text)| !text.is_empty())
+            .unzip();
 
         if show_keys {
-            for entry in rendered_entries {
-                let _ = writeln!(output, "{}", entry);
+            for (entry, text) in entries.iter().zip(texts) {
+                let _ = writeln!(output, "  [{}] {}", entry.id, text);
             }
-        } else if !rendered_entries.is_empty() {
-            let _ = writeln!(output, "{}", rendered_entries.join("\n\n"));
+        } else if !entries.is_empty() {
+            // Render the list in one pass so the container wraps it once.
+            let text = csln_processor::render::refs_to_string_with_format::<F>(entries);
+            let _ = writeln!(output, "{}", text.trim());
         }
     }
 
@@ -1103,14 +1767,17 @@ where
 
     if show_cite {
         if let Some(cite_list) = citations {
+            let mut cited_before = HashSet::new();
             let rendered: Vec<_> = cite_list
                 .iter()
                 .map(|c| {
+                    let text = processor
+                        .process_citation_after_with_format::<F>(c, &cited_before)
+                        .unwrap_or_else(|e| e.to_string());
+                    cited_before.extend(c.items.iter().map(|item| item.id.clone()));
                     json!({
                         "id": c.id,
-                        "text": processor
-                            .process_citation_with_format::<F>(c)
-                            .unwrap_or_else(|e| e.to_string())
+                        "text": text
                     })
                 })
                 .collect();
@@ -1173,8 +1840,7 @@ where
             .into_iter()
             .filter(|entry| filter.contains(entry.id.as_str()))
             .map(|entry| {
-                let text =
-                    csln_processor::render::refs_to_string_with_format::<F>(vec![entry.clone()]);
+                let text = csln_processor::render::ref_to_string_with_format::<F>(&entry);
                 json!({
                     "id": entry.id,
                     "text": text.trim()
@@ -1187,3 +1853,613 @@ where
 
     Ok(serde_json::to_string_pretty(&result)?)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A scratch directory for one test, removed by the test when done.
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("csln-{}-{}", name, std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    /// Arguments rendering `bibliography` with `style` as plain text to
+    /// stdout; tests override the fields they exercise.
+    fn refs_args(bibliography: &Path, style: &str) -> RenderRefsArgs {
+        RenderRefsArgs {
+            bibliography: vec![bibliography.to_path_buf()],
+            recursive: false,
+            style: style.to_string(),
+            citations: Vec::new(),
+            mode: RenderMode::Both,
+            keys: None,
+            only_cited: false,
+            show_keys: false,
+            json: false,
+            format: OutputFormat::Plain,
+            output: None,
+            no_semantics: true,
+            explain: false,
+            diagnostics: None,
+            strict: false,
+            locale: None,
+            date_locale: None,
+        }
+    }
+
+    const COMBINED: &str = r#"
+style:
+  info:
+    title: Split Test
+  citation:
+    template:
+      - contributor: author
+        form: short
+bibliography:
+  references:
+    - id: kuhn1962
+      type: book
+      title: The Structure of Scientific Revolutions
+      author:
+        family: Kuhn
+        given: Thomas S.
+      issued: "1962"
+citations:
+  - items:
+      - id: kuhn1962
+"#;
+
+    #[test]
+    fn test_bench_smoke() {
+        let args = BenchArgs {
+            style: "apa".to_string(),
+            entries: 10,
+            iterations: 1,
+        };
+        assert!(bench_bibliography(&args).is_ok());
+    }
+
+    #[test]
+    fn test_split_combined_document_round_trip() {
+        let dir = temp_dir("split");
+        let input = dir.join("combined.yaml");
+        fs::write(&input, COMBINED).unwrap();
+
+        let written = split_combined_document(&input, &dir.join("paper.json")).unwrap();
+        let names: Vec<_> = written
+            .iter()
+            .map(|p| p.file_name().unwrap().to_str().unwrap())
+            .collect();
+        assert_eq!(
+            names,
+            ["paper-style.json", "paper-bib.json", "paper-citations.json"]
+        );
+
+        let read = |path: &Path| fs::read(path).unwrap();
+        let rejoined = CombinedDocument {
+            style: Some(deserialize_any(&read(&written[0]), "json").unwrap()),
+            bibliography: Some(deserialize_any(&read(&written[1]), "json").unwrap()),
+            citations: Some(deserialize_any(&read(&written[2]), "json").unwrap()),
+        };
+        let original: CombinedDocument = serde_yaml::from_str(COMBINED).unwrap();
+        assert_eq!(
+            serde_json::to_value(&rejoined).unwrap(),
+            serde_json::to_value(&original).unwrap()
+        );
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_diagnostics_report_missing_field() {
+        let dir = temp_dir("diagnostics");
+        let style = dir.join("style.yaml");
+        fs::write(
+            &style,
+            r#"
+info:
+  title: Diagnostics Test
+citation:
+  template:
+    - contributor: author
+      form: short
+    - variable: publisher
+bibliography:
+  template:
+    - title: primary
+    - variable: publisher
+"#,
+        )
+        .unwrap();
+        let bibliography = dir.join("bib.yaml");
+        fs::write(
+            &bibliography,
+            r#"
+references:
+  - id: kuhn
+    type: book
+    title: The Structure of Scientific Revolutions
+    author:
+      family: Kuhn
+      given: Thomas S.
+    issued: "1962"
+"#,
+        )
+        .unwrap();
+        let diagnostics = dir.join("diagnostics.json");
+
+        run_render_refs(RenderRefsArgs {
+            output: Some(dir.join("out.txt")),
+            diagnostics: Some(diagnostics.clone()),
+            ..refs_args(&bibliography, &style.to_string_lossy())
+        })
+        .unwrap();
+
+        let report: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&diagnostics).unwrap()).unwrap();
+        assert_eq!(
+            report,
+            serde_json::json!([
+                {
+                    "level": "warning",
+                    "code": "missing-field",
+                    "context": "citation",
+                    "item": "kuhn",
+                    "component": "variable:publisher",
+                    "message": "'kuhn' has no value for variable:publisher"
+                },
+                {
+                    "level": "warning",
+                    "code": "missing-field",
+                    "context": "bibliography",
+                    "item": "kuhn",
+                    "component": "variable:publisher",
+                    "message": "'kuhn' has no value for variable:publisher"
+                }
+            ])
+        );
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_strict_fails_on_missing_key() {
+        let dir = temp_dir("strict");
+        let style = dir.join("style.yaml");
+        fs::write(
+            &style,
+            r#"
+info:
+  title: Strict Test
+citation:
+  template:
+    - contributor: author
+      form: short
+"#,
+        )
+        .unwrap();
+        let bibliography = dir.join("bib.yaml");
+        fs::write(
+            &bibliography,
+            r#"
+references:
+  - id: kuhn
+    type: book
+    title: The Structure of Scientific Revolutions
+    author:
+      family: Kuhn
+      given: Thomas S.
+    issued: "1962"
+"#,
+        )
+        .unwrap();
+        let render = |cited: &str| {
+            let citations = dir.join("citations.yaml");
+            fs::write(&citations, format!("- items:\n    - id: {cited}\n")).unwrap();
+            let output = dir.join("out.txt");
+            let _ = fs::remove_file(&output);
+            let result = run_render_refs(RenderRefsArgs {
+                citations: vec![citations],
+                mode: RenderMode::Cite,
+                output: Some(output.clone()),
+                strict: true,
+                ..refs_args(&bibliography, &style.to_string_lossy())
+            });
+            (result, output.exists())
+        };
+
+        let (result, written) = render("missing");
+        let error = result.unwrap_err().to_string();
+        assert!(error.contains("'missing' not found"), "{error}");
+        assert!(!written);
+
+        let (result, written) = render("kuhn");
+        assert!(result.is_ok());
+        assert!(written);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_forced_locale_overrides_style_default() {
+        let dir = temp_dir("locale");
+        let style = dir.join("style.yaml");
+        fs::write(
+            &style,
+            r#"
+info:
+  title: Locale Test
+  default-locale: en-US
+citation:
+  template:
+    - term: retrieved
+    - title: primary
+"#,
+        )
+        .unwrap();
+        let bibliography = dir.join("bib.yaml");
+        fs::write(
+            &bibliography,
+            r#"
+references:
+  - id: kuhn
+    type: book
+    title: Structure
+    issued: "1962"
+"#,
+        )
+        .unwrap();
+
+        let render = |locale: Option<&str>| {
+            let output = dir.join("out.txt");
+            run_render_refs(RenderRefsArgs {
+                mode: RenderMode::Cite,
+                output: Some(output.clone()),
+                locale: locale.map(str::to_string),
+                ..refs_args(&bibliography, &style.to_string_lossy())
+            })
+            .unwrap();
+            fs::read_to_string(output).unwrap()
+        };
+
+        assert!(render(None).contains("retrieved, Structure"));
+        assert!(render(Some("de-DE")).contains("abgerufen, Structure"));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_render_doc_with_bibliography() {
+        let dir = temp_dir("doc-bib");
+        let style = dir.join("style.yaml");
+        fs::write(
+            &style,
+            r#"
+info:
+  title: Doc Test
+citation:
+  template:
+    - contributor: author
+      form: short
+    - date: issued
+      form: year
+  wrap: parentheses
+bibliography:
+  template:
+    - contributor: author
+      form: short
+    - title: primary
+      prefix: ". "
+"#,
+        )
+        .unwrap();
+        let bibliography = dir.join("bib.yaml");
+        fs::write(
+            &bibliography,
+            r#"
+references:
+  - id: kuhn
+    type: book
+    title: Structure
+    author:
+      - family: Kuhn
+        given: Thomas
+    issued: "1962"
+  - id: popper
+    type: book
+    title: Conjectures
+    author:
+      - family: Popper
+        given: Karl
+    issued: "1963"
+  - id: uncited
+    type: book
+    title: Uncited
+    author:
+      - family: Feyerabend
+        given: Paul
+    issued: "1975"
+"#,
+        )
+        .unwrap();
+        let input = dir.join("doc.djot");
+        fs::write(&input, "See [@kuhn] and [@popper].\n").unwrap();
+
+        let render = |no_bibliography: bool| {
+            let output = dir.join("out.txt");
+            run_render_doc(RenderDocArgs {
+                input: input.clone(),
+                style: style.to_string_lossy().to_string(),
+                bibliography: vec![bibliography.clone()],
+                recursive: false,
+                citations: Vec::new(),
+                input_format: InputFormat::Djot,
+                format: OutputFormat::Plain,
+                output: Some(output.clone()),
+                no_semantics: true,
+                locale: None,
+                date_locale: None,
+                no_bibliography,
+                strict: false,
+            })
+            .unwrap();
+            fs::read_to_string(output).unwrap()
+        };
+
+        let without = render(true);
+        assert!(without.contains("See (Kuhn, 1962) and (Popper, 1963)."));
+        assert!(!without.contains("# Bibliography"));
+
+        // The bibliography is appended by default.
+        let with = render(false);
+        let (_, bib) = with.split_once("# Bibliography").unwrap();
+        assert!(bib.contains("Kuhn. Structure"));
+        assert!(bib.contains("Popper. Conjectures"));
+        assert!(!bib.contains("Uncited"));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_validate_style_dir() {
+        let dir = temp_dir("validate-all");
+        fs::write(dir.join("good.yaml"), "info:\n  title: Good\n").unwrap();
+        fs::write(
+            dir.join("broken.json"),
+            r#"{"info": {"title": "Broken"}, "options": {"no-such-option": true}}"#,
+        )
+        .unwrap();
+        fs::write(
+            dir.join("typo.yaml"),
+            "info:\n  title: Typo\ncitation:\n  template: []\n  custom:\n    delimeter: \", \"\n",
+        )
+        .unwrap();
+        fs::write(dir.join("notes.txt"), "not a style").unwrap();
+
+        let checks = validate_style_dir(&dir).unwrap();
+        let results: Vec<_> = checks
+            .iter()
+            .map(|c| {
+                let name = Path::new(&c.path).file_name().unwrap().to_str().unwrap();
+                (name.to_string(), c.ok)
+            })
+            .collect();
+        assert_eq!(
+            results,
+            [
+                ("broken.json".to_string(), false),
+                ("good.yaml".to_string(), true),
+                ("typo.yaml".to_string(), true)
+            ]
+        );
+        assert!(checks[0].error.as_ref().unwrap().contains("no-such-option"));
+        assert!(checks[1].warnings.is_empty());
+        assert_eq!(
+            checks[2].warnings,
+            ["citation.custom.delimeter: unknown key, likely a typo of `delimiter`"]
+        );
+        assert!(run_styles_validate_all(ValidateAllArgs { dir: dir.clone() }).is_err());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_load_bibliography_dir() {
+        let dir = temp_dir("bib-dir");
+        let nested = dir.join("nested");
+        fs::create_dir_all(&nested).unwrap();
+        fs::write(
+            dir.join("a.yaml"),
+            "references:\n  - id: kuhn\n    type: book\n    title: Structure\n    issued: \"1962\"\n",
+        )
+        .unwrap();
+        fs::write(
+            dir.join("b.json"),
+            r#"[{"id": "doe", "type": "book", "title": "Other"}]"#,
+        )
+        .unwrap();
+        fs::write(dir.join("broken.json"), "{not json").unwrap();
+        fs::write(dir.join("notes.txt"), "not references").unwrap();
+        fs::write(
+            nested.join("c.json"),
+            r#"[{"id": "roe", "type": "book", "title": "Nested"}]"#,
+        )
+        .unwrap();
+
+        let bibliography = load_merged_bibliography(std::slice::from_ref(&dir), false).unwrap();
+        assert_eq!(bibliography.keys().collect::<Vec<_>>(), ["kuhn", "doe"]);
+
+        let bibliography = load_merged_bibliography(std::slice::from_ref(&dir), true).unwrap();
+        assert_eq!(
+            bibliography.keys().collect::<Vec<_>>(),
+            ["kuhn", "doe", "roe"]
+        );
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_only_cited_limits_bibliography() {
+        let dir = temp_dir("only-cited");
+        let bibliography = dir.join("refs.yaml");
+        fs::write(
+            &bibliography,
+            "references:\n  - id: kuhn\n    type: book\n    title: Structure\n    issued: \"1962\"\n  - id: doe\n    type: book\n    title: Other\n    issued: \"2001\"\n  - id: roe\n    type: book\n    title: Third\n    issued: \"2010\"\n",
+        )
+        .unwrap();
+        let citations = dir.join("citations.yaml");
+        fs::write(&citations, "- items:\n    - id: doe\n").unwrap();
+        let output = dir.join("refs.txt");
+
+        run_render_refs(RenderRefsArgs {
+            citations: vec![citations],
+            mode: RenderMode::Bib,
+            only_cited: true,
+            show_keys: true,
+            output: Some(output.clone()),
+            ..refs_args(&bibliography, "apa")
+        })
+        .unwrap();
+
+        let rendered = fs::read_to_string(&output).unwrap();
+        assert!(rendered.contains("Other"), "{rendered}");
+        assert!(!rendered.contains("Structure"), "{rendered}");
+        assert!(!rendered.contains("Third"), "{rendered}");
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_csl_json_export_round_trip() {
+        let dir = temp_dir("csl-json");
+        let bibliography = dir.join("refs.json");
+        fs::write(
+            &bibliography,
+            r#"[{
+                "id": "smith2020",
+                "type": "article-journal",
+                "title": "Scaling Laws",
+                "container-title": "Journal of Examples",
+                "author": [{"family": "Smith", "given": "Jane"}, {"literal": "Example Lab"}],
+                "issued": {"date-parts": [[2020, 5, 15]]},
+                "volume": 12,
+                "issue": "3",
+                "page": "45-67",
+                "DOI": "10.1234/abc",
+                "PMID": "123456"
+            }]"#,
+        )
+        .unwrap();
+        let output = dir.join("export.json");
+
+        run_render_refs(RenderRefsArgs {
+            mode: RenderMode::Bib,
+            format: OutputFormat::CslJson,
+            output: Some(output.clone()),
+            ..refs_args(&bibliography, "apa")
+        })
+        .unwrap();
+
+        let exported: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&output).unwrap()).unwrap();
+        assert_eq!(exported[0]["type"], "article-journal");
+        assert_eq!(
+            exported[0]["issued"]["date-parts"],
+            serde_json::json!([[2020, 5, 15]])
+        );
+
+        let original = load_bibliography(&bibliography).unwrap();
+        let reimported = load_bibliography(&output).unwrap();
+        assert_eq!(reimported, original);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_csl_json_export_uses_csl_type_names() {
+        let dir = temp_dir("csl-json-types");
+        let bibliography = dir.join("refs.json");
+        fs::write(
+            &bibliography,
+            r#"[
+                {"id": "film", "type": "motion_picture", "title": "Metropolis",
+                 "genre": "Film", "issued": {"date-parts": [[1927]]}},
+                {"id": "case", "type": "legal_case", "title": "Brown v. Board of Education",
+                 "authority": "U.S. Supreme Court", "issued": {"date-parts": [[1954]]}},
+                {"id": "act", "type": "legislation", "title": "Civil Rights Act of 1964",
+                 "issued": {"date-parts": [[1964]]}},
+                {"id": "letter", "type": "personal_communication", "title": "Letter to the editor",
+                 "issued": {"date-parts": [[2001]]}}
+            ]"#,
+        )
+        .unwrap();
+        let output = dir.join("export.json");
+
+        run_render_refs(RenderRefsArgs {
+            mode: RenderMode::Bib,
+            keys: Some(["film", "case", "act", "letter"].map(String::from).to_vec()),
+            format: OutputFormat::CslJson,
+            output: Some(output.clone()),
+            ..refs_args(&bibliography, "apa")
+        })
+        .unwrap();
+
+        let exported: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&output).unwrap()).unwrap();
+        let types: Vec<_> = exported
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|r| r["type"].as_str().unwrap())
+            .collect();
+        assert_eq!(
+            types,
+            [
+                "motion_picture",
+                "legal_case",
+                "legislation",
+                "personal_communication"
+            ]
+        );
+
+        let original = load_bibliography(&bibliography).unwrap();
+        let reimported = load_bibliography(&output).unwrap();
+        assert_eq!(reimported, original);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_custom_field_typos() {
+        let style: Style = serde_yaml::from_str(
+            r#"
+info:
+  title: Typo
+citation:
+  template: []
+  custom:
+    delimeter: ", "
+    x-delimeter: ", "
+    publisher-note: true
+"#,
+        )
+        .unwrap();
+        assert_eq!(
+            custom_field_typos(&style),
+            ["citation.custom.delimeter: unknown key, likely a typo of `delimiter`"]
+        );
+    }
+
+    #[test]
+    fn test_split_rejects_non_combined_document() {
+        let dir = temp_dir("split-bad");
+        let input = dir.join("style.yaml");
+        fs::write(&input, "info:\n  title: Not Combined\n").unwrap();
+
+        assert!(split_combined_document(&input, &dir.join("out.yaml")).is_err());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
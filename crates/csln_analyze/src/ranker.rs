@@ -158,7 +158,7 @@ pub fn run_parent_ranker(styles_dir: &str, json_output: bool, format_filter: Opt
         .collect();
 
     // Sort by dependent count descending
-    rankings.sort_by(|a, b| b.dependent_count.cmp(&a.dependent_count));
+    rankings.sort_by_key(|r| std::cmp::Reverse(r.dependent_count));
     stats.parent_rankings = rankings;
 
     if json_output {
@@ -189,10 +189,8 @@ fn extract_dependent_info(path: &Path) -> Result<DependentInfo, String> {
         if child.tag_name().name() == "info" {
             for info_child in child.children() {
                 match info_child.tag_name().name() {
-                    "link" => {
-                        if info_child.attribute("rel") == Some("independent-parent") {
-                            parent_id = info_child.attribute("href").map(|s| s.to_string());
-                        }
+                    "link" if info_child.attribute("rel") == Some("independent-parent") => {
+                        parent_id = info_child.attribute("href").map(|s| s.to_string());
                     }
                     "category" => {
                         if let Some(fmt) = info_child.attribute("citation-format") {
@@ -112,6 +112,17 @@ pub enum LocatorType {
     Issue,
 }
 
+/// One labeled part of a composite locator (e.g., "ch. 3").
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "kebab-case")]
+pub struct LocatorSegment {
+    /// Locator type (book, chapter, etc.)
+    pub label: LocatorType,
+    /// Locator value (e.g., "3")
+    pub locator: String,
+}
+
 /// A single citation item referencing a bibliography entry.
 #[derive(Debug, Clone, Default, Deserialize, Serialize)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
@@ -125,6 +136,10 @@ pub struct CitationItem {
     /// Locator value (e.g., "42-45" for pages)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub locator: Option<String>,
+    /// Composite locator with several labeled parts (e.g., "bk. 2, ch. 3").
+    /// Takes precedence over `label` and `locator` when present.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub locators: Option<Vec<LocatorSegment>>,
     /// Prefix text before this item
     #[serde(skip_serializing_if = "Option::is_none")]
     pub prefix: Option<String>,
@@ -1,3 +1,4 @@
+use indexmap::IndexMap;
 #[cfg(feature = "schema")]
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -61,6 +62,38 @@ pub struct BibliographyGroup {
     pub disambiguate: Option<DisambiguationScope>,
 }
 
+/// Dynamic bibliography sections derived from each reference's keywords.
+///
+/// Each reference is placed under the label of its first keyword, or of
+/// its first keyword found in `mapping` when one is given. References
+/// without a matching keyword go in a final "Uncategorized" section.
+/// Sections are ordered alphabetically by label.
+///
+/// # Examples
+///
+/// ```yaml
+/// keyword-groups:
+///   mapping:
+///     primary: Primary Sources
+///     archival: Primary Sources
+///     secondary: Secondary Literature
+///   uncategorized: Other Works
+/// ```
+#[derive(Debug, Clone, Deserialize, Serialize, Default, PartialEq)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "kebab-case")]
+pub struct KeywordGrouping {
+    /// Map keywords (matched case-insensitively) to section labels.
+    /// Keywords not in the map are ignored. If several keys match the same
+    /// keyword, the first listed wins.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mapping: Option<IndexMap<String, String>>,
+    /// Heading for references without a matching keyword.
+    /// Defaults to "Uncategorized".
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uncategorized: Option<String>,
+}
+
 /// Localizable heading source for bibliography groups.
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
@@ -163,6 +196,7 @@ pub enum FieldMatcher {
 ///
 /// Sorting follows a template of sort keys, applied in order.
 /// The first key is the primary sort, second is the tiebreaker, etc.
+/// References that tie on every key are ordered by their id.
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(rename_all = "kebab-case")]
@@ -195,6 +229,13 @@ pub struct GroupSortKey {
     /// Example: `given-family` for Vietnamese, `family-given` for Western.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub sort_order: Option<NameSortOrder>,
+
+    /// For author sorting: where references without an author or editor go.
+    ///
+    /// Defaults to `last`, unless the template also sorts by title. Use
+    /// `title` to interleave them by title, as numeric styles usually do.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub empty_author: Option<EmptyAuthorSort>,
 }
 
 fn default_true() -> bool {
@@ -213,12 +254,28 @@ pub enum SortKey {
     Author,
     /// Sort by title.
     Title,
-    /// Sort by issued date.
+    /// Sort by issued date, chronologically.
+    #[serde(alias = "year")]
     Issued,
-    /// Sort by custom field.
+    /// Sort by a reference variable, e.g. `key: !field publisher`.
+    ///
+    /// Names sort in sort order, dates chronologically, and titles without
+    /// leading articles. References missing the variable sort last.
+    #[serde(alias = "variable")]
     Field(String),
 }
 
+/// Placement of author-less references under an author sort key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "kebab-case")]
+pub enum EmptyAuthorSort {
+    /// Sort after all references with names.
+    Last,
+    /// Sort by title among references with names.
+    Title,
+}
+
 /// Name sorting order for culturally appropriate collation.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
@@ -348,6 +405,23 @@ sort:
         assert!(!sort.template[1].ascending);
     }
 
+    #[test]
+    fn test_sort_key_aliases() {
+        let yaml = r#"
+template:
+  - key: year
+    ascending: false
+  - key: !variable publisher
+"#;
+        let sort: GroupSort = serde_yaml::from_str(yaml).unwrap();
+        assert!(matches!(sort.template[0].key, SortKey::Issued));
+        assert!(!sort.template[0].ascending);
+        match &sort.template[1].key {
+            SortKey::Field(name) => assert_eq!(name, "publisher"),
+            _ => panic!("Expected Field"),
+        }
+    }
+
     #[test]
     fn test_type_order_sorting() {
         let yaml = r#"
@@ -389,4 +463,19 @@ form: short
             _ => panic!("Expected term heading"),
         }
     }
+
+    #[test]
+    fn test_keyword_grouping_mapping() {
+        let yaml = r#"
+mapping:
+  primary: Primary Sources
+uncategorized: Other Works
+"#;
+        let grouping: KeywordGrouping = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(
+            grouping.mapping.unwrap().get("primary").unwrap(),
+            "Primary Sources"
+        );
+        assert_eq!(grouping.uncategorized.as_deref(), Some("Other Works"));
+    }
 }
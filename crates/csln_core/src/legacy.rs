@@ -349,8 +349,10 @@ pub struct EtAlOptions {
     /// Optional separate configuration for subsequent citations (CSL 1.0 legacy).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub subsequent: Option<Box<EtAlSubsequent>>,
-    /// The term to use (e.g., "et al.", "and others").
-    pub term: String,
+    /// The CSL term to use ("et-al" or "and others"). None uses the
+    /// locale's "et al." term.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub term: Option<String>,
     /// Formatting for the term (italic, bold).
     pub formatting: FormattingOptions,
 }
@@ -25,7 +25,7 @@ pub mod macros;
 pub use citation::{Citation, CitationItem, CitationMode, Citations, LocatorType};
 pub use grouping::{
     BibliographyGroup, CitedStatus, FieldMatcher, GroupHeading, GroupSelector, GroupSort,
-    GroupSortKey, NameSortOrder, SortKey, TypeSelector,
+    GroupSortKey, KeywordGrouping, NameSortOrder, SortKey, TypeSelector,
 };
 pub use legacy::{
     AndTerm, ConditionBlock, CslnInfo, CslnLocale, CslnNode, CslnStyle, DateBlock, DateForm,
@@ -65,6 +65,22 @@ pub struct InputBibliographyInfo {
     pub author: Option<String>,
 }
 
+/// A single-file project holding a style, bibliography, and citations.
+///
+/// Each top-level key is optional; `csln convert --split` writes each present
+/// part to its own file.
+#[derive(Debug, Default, Deserialize, Serialize, Clone)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct CombinedDocument {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub style: Option<Style>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bibliography: Option<InputBibliography>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub citations: Option<citation::Citations>,
+}
+
 /// A named template (reusable sequence of components).
 pub type Template = Vec<TemplateComponent>;
 
@@ -156,6 +172,8 @@ impl TemplatePreset {
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(rename_all = "kebab-case", deny_unknown_fields)]
 pub struct CitationSpec {
+    /// Citation-specific options, merged over the style's global options
+    /// (e.g., a lower et-al threshold than the bibliography's).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub options: Option<Config>,
     /// Reference to an embedded template preset.
@@ -167,6 +185,10 @@ pub struct CitationSpec {
     /// Wrap the entire citation in punctuation. Preferred over prefix/suffix.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub wrap: Option<template::WrapPunctuation>,
+    /// How numeric citations are set off (bracket, parenthesis, superscript,
+    /// or plain). Takes precedence over `wrap` for non-integral citations.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub citation_number_form: Option<template::CitationNumberForm>,
     /// Prefix for the citation (use only when `wrap` doesn't suffice, e.g., " (" or "[Ref ").
     #[serde(skip_serializing_if = "Option::is_none")]
     pub prefix: Option<String>,
@@ -182,6 +204,10 @@ pub struct CitationSpec {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "multi-cite-delimiter")]
     pub multi_cite_delimiter: Option<String>,
+    /// Whether multiple cited items share one wrap ("(A 2019; B 2020)") or
+    /// each gets its own ("(A 2019) (B 2020)"). Defaults to true.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub merge_parentheticals: Option<bool>,
     /// Optional citation sorting specification.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub sort: Option<grouping::GroupSort>,
@@ -243,6 +269,9 @@ impl CitationSpec {
                 if spec.wrap.is_some() {
                     merged.wrap = spec.wrap.clone();
                 }
+                if spec.citation_number_form.is_some() {
+                    merged.citation_number_form = spec.citation_number_form.clone();
+                }
                 if spec.prefix.is_some() {
                     merged.prefix = spec.prefix.clone();
                 }
@@ -255,6 +284,9 @@ impl CitationSpec {
                 if spec.multi_cite_delimiter.is_some() {
                     merged.multi_cite_delimiter = spec.multi_cite_delimiter.clone();
                 }
+                if spec.merge_parentheticals.is_some() {
+                    merged.merge_parentheticals = spec.merge_parentheticals;
+                }
                 if spec.sort.is_some() {
                     merged.sort = spec.sort.clone();
                 }
@@ -271,6 +303,7 @@ impl CitationSpec {
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(rename_all = "kebab-case", deny_unknown_fields)]
 pub struct BibliographySpec {
+    /// Bibliography-specific options, merged over the style's global options.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub options: Option<Config>,
     /// Reference to an embedded template preset.
@@ -300,6 +333,10 @@ pub struct BibliographySpec {
     /// See `BibliographyGroup` for examples.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub groups: Option<Vec<grouping::BibliographyGroup>>,
+    /// Divide the bibliography into sections by reference keyword.
+    /// Ignored when `groups` is set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub keyword_groups: Option<grouping::KeywordGrouping>,
     /// Custom user-defined fields for extensions.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub custom: Option<HashMap<String, serde_json::Value>>,
@@ -50,6 +50,20 @@ pub struct Locale {
     /// These should be lowercase and will be matched case-insensitively.
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub sort_articles: Vec<String>,
+    /// Item type names keyed by CSL type (e.g., "report", "thesis").
+    /// Used as fallback genre labels.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub item_types: HashMap<String, SimpleTerm>,
+    /// Whether initials are written without periods. When true, periods
+    /// and spaces are dropped from `initialize-with` ("JP").
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub unpunctuated_initials: bool,
+    /// Ordinal suffixes and spelled-out ordinals.
+    #[serde(default)]
+    pub ordinals: OrdinalTerms,
+    /// The space between a label and its value (e.g., "p. 42").
+    #[serde(default)]
+    pub label_space: LabelSpace,
 }
 
 impl Locale {
@@ -111,6 +125,42 @@ impl Locale {
             },
         );
 
+        roles.insert(
+            ContributorRole::Composer,
+            ContributorTerm {
+                singular: SimpleTerm {
+                    long: "composer".into(),
+                    short: "comp.".into(),
+                },
+                plural: SimpleTerm {
+                    long: "composers".into(),
+                    short: "comps.".into(),
+                },
+                verb: SimpleTerm {
+                    long: "composed by".into(),
+                    short: "comp. by".into(),
+                },
+            },
+        );
+
+        roles.insert(
+            ContributorRole::Illustrator,
+            ContributorTerm {
+                singular: SimpleTerm {
+                    long: "illustrator".into(),
+                    short: "ill.".into(),
+                },
+                plural: SimpleTerm {
+                    long: "illustrators".into(),
+                    short: "ills.".into(),
+                },
+                verb: SimpleTerm {
+                    long: "illustrated by".into(),
+                    short: "ill. by".into(),
+                },
+            },
+        );
+
         // Populate basic locator terms
         let mut locators = HashMap::new();
         locators.insert(
@@ -128,6 +178,21 @@ impl Locale {
             },
         );
 
+        locators.insert(
+            LocatorType::Book,
+            LocatorTerm {
+                long: Some(SingularPlural {
+                    singular: "book".into(),
+                    plural: "books".into(),
+                }),
+                short: Some(SingularPlural {
+                    singular: "bk.".into(),
+                    plural: "bks.".into(),
+                }),
+                symbol: None,
+            },
+        );
+
         locators.insert(
             LocatorType::Chapter,
             LocatorTerm {
@@ -158,6 +223,21 @@ impl Locale {
             },
         );
 
+        locators.insert(
+            LocatorType::Issue,
+            LocatorTerm {
+                long: Some(SingularPlural {
+                    singular: "issue".into(),
+                    plural: "issues".into(),
+                }),
+                short: Some(SingularPlural {
+                    singular: "no.".into(),
+                    plural: "nos.".into(),
+                }),
+                symbol: None,
+            },
+        );
+
         locators.insert(
             LocatorType::Section,
             LocatorTerm {
@@ -176,6 +256,24 @@ impl Locale {
             },
         );
 
+        let item_types = [
+            ("dataset", "dataset", "dataset"),
+            ("report", "report", "rep."),
+            ("software", "software", "sftw."),
+            ("thesis", "thesis", "thes."),
+        ]
+        .into_iter()
+        .map(|(ty, long, short)| {
+            (
+                ty.to_string(),
+                SimpleTerm {
+                    long: long.into(),
+                    short: short.into(),
+                },
+            )
+        })
+        .collect();
+
         Self {
             locale: "en-US".into(),
             dates: DateTerms::en_us(),
@@ -184,6 +282,10 @@ impl Locale {
             terms: Terms::en_us(),
             punctuation_in_quote: true, // American English convention
             sort_articles: vec!["the".into(), "a".into(), "an".into()],
+            item_types,
+            unpunctuated_initials: false,
+            ordinals: OrdinalTerms::en_us(),
+            label_space: LabelSpace::Space,
         }
     }
 
@@ -329,6 +431,7 @@ impl Locale {
             GeneralTerm::At => self.terms.at.as_deref(),
             GeneralTerm::By => self.terms.by.as_deref(),
             GeneralTerm::From => self.terms.from.as_deref(),
+            GeneralTerm::AvailableAt => self.terms.available_at.as_deref(),
             GeneralTerm::Anonymous => Some(&self.terms.anonymous.long),
             GeneralTerm::Circa => Some(&self.terms.circa.long),
             // Fallback to locators for shared terms
@@ -341,6 +444,41 @@ impl Locale {
         }
     }
 
+    /// Format a number as an ordinal (e.g., 2 → "2nd", 11 → "11th").
+    ///
+    /// A suffix matching the last two digits wins over one matching the last
+    /// digit, which wins over the default suffix.
+    pub fn ordinal(&self, n: u32) -> String {
+        let terms = &self.ordinals;
+        let suffix = (n % 100 >= 10)
+            .then(|| terms.suffixes.get(&(n % 100)))
+            .flatten()
+            .or_else(|| terms.suffixes.get(&(n % 10)))
+            .or(terms.suffix.as_ref());
+        format!("{}{}", n, suffix.map(String::as_str).unwrap_or(""))
+    }
+
+    /// Spell out a number as an ordinal (e.g., 2 → "second").
+    ///
+    /// Falls back to [`Locale::ordinal`] when no long form is defined.
+    pub fn long_ordinal(&self, n: u32) -> String {
+        self.ordinals
+            .long
+            .get(&n)
+            .cloned()
+            .unwrap_or_else(|| self.ordinal(n))
+    }
+
+    /// Get the localized name of an item type (e.g., "report" for `report`).
+    pub fn item_type_term(&self, ref_type: &str, form: TermForm) -> Option<&str> {
+        let term = self.item_types.get(ref_type)?;
+        let value = match form {
+            TermForm::Short if !term.short.is_empty() => &term.short,
+            _ => &term.long,
+        };
+        (!value.is_empty()).then_some(value.as_str())
+    }
+
     /// Get the "and" term based on style preference.
     pub fn and_term(&self, use_symbol: bool) -> &str {
         if use_symbol {
@@ -355,6 +493,11 @@ impl Locale {
         self.terms.et_al.as_deref().unwrap_or("et al.")
     }
 
+    /// Get the spelled-out "and others" term.
+    pub fn and_others(&self) -> &str {
+        self.terms.and_others.as_deref().unwrap_or("and others")
+    }
+
     /// Get a month name.
     pub fn month_name(&self, month: u8, short: bool) -> &str {
         let idx = (month.saturating_sub(1)) as usize;
@@ -468,11 +611,19 @@ impl Locale {
             seasons: raw.dates.seasons,
             uncertainty_term: raw.dates.uncertainty_term,
             open_ended_term: raw.dates.open_ended_term,
+            numeric_delimiter: raw.dates.numeric_delimiter,
         };
         locale.punctuation_in_quote = punctuation_in_quote;
+        locale.unpunctuated_initials = raw.unpunctuated_initials;
+        locale.label_space = raw.label_space;
         // Set locale-specific articles based on language
         locale.sort_articles = Self::default_articles_for_locale(&raw.locale);
 
+        // Ordinal rules are language-specific, so don't mix in en-US suffixes.
+        if raw.terms.keys().any(|k| k.contains("ordinal")) {
+            locale.ordinals = OrdinalTerms::default();
+        }
+
         // Map raw terms to structured terms and locators
         for (key, value) in &raw.terms {
             // First try to parse as a locator
@@ -536,11 +687,31 @@ impl Locale {
                         }
                     }
                 }
+                "ordinal" => {
+                    locale.ordinals.suffix = Some(Self::extract_simple_term_from_raw(value).long);
+                }
+                _ if key.starts_with("ordinal_") || key.starts_with("long_ordinal_") => {
+                    let (long, digits) = match key.strip_prefix("long_ordinal_") {
+                        Some(digits) => (true, digits),
+                        None => (false, &key["ordinal_".len()..]),
+                    };
+                    if let Ok(n) = digits.parse::<u32>() {
+                        let term = Self::extract_simple_term_from_raw(value).long;
+                        if long {
+                            locale.ordinals.long.insert(n, term);
+                        } else {
+                            locale.ordinals.suffixes.insert(n, term);
+                        }
+                    }
+                }
                 _ => {
-                    // Try to parse as GeneralTerm
+                    // Try to parse as GeneralTerm, then as an item type name
                     if let Some(general_term) = Self::parse_general_term(key) {
                         let simple = Self::extract_simple_term_from_raw(value);
                         locale.terms.general.insert(general_term, simple);
+                    } else if let Some(item_type) = Self::parse_item_type(key) {
+                        let simple = Self::extract_simple_term_from_raw(value);
+                        locale.item_types.insert(item_type.to_string(), simple);
                     }
                 }
             }
@@ -548,6 +719,10 @@ impl Locale {
 
         // Map raw roles to structured roles (simplified for now)
         for (key, role_term) in &raw.roles {
+            // "compiler" stands in for a missing composer term only.
+            if key == "compiler" && raw.roles.contains_key("composer") {
+                continue;
+            }
             if let Some(role) = Self::parse_role_name(key) {
                 let contributor_term = ContributorTerm {
                     singular: Self::extract_simple_term(&role_term.long, &role_term.short, false),
@@ -591,6 +766,57 @@ impl Locale {
         }
     }
 
+    fn parse_item_type(name: &str) -> Option<&'static str> {
+        const ITEM_TYPES: &[&str] = &[
+            "article",
+            "article-journal",
+            "article-magazine",
+            "article-newspaper",
+            "bill",
+            "book",
+            "broadcast",
+            "chapter",
+            "classic",
+            "collection",
+            "dataset",
+            "document",
+            "entry",
+            "entry-dictionary",
+            "entry-encyclopedia",
+            "event",
+            "graphic",
+            "hearing",
+            "interview",
+            "legal-case",
+            "legislation",
+            "manuscript",
+            "map",
+            "motion-picture",
+            "musical-score",
+            "pamphlet",
+            "paper-conference",
+            "patent",
+            "performance",
+            "periodical",
+            "personal-communication",
+            "post",
+            "post-weblog",
+            "regulation",
+            "report",
+            "review",
+            "review-book",
+            "software",
+            "song",
+            "speech",
+            "standard",
+            "thesis",
+            "treaty",
+            "webpage",
+        ];
+        let normalized = name.replace('_', "-");
+        ITEM_TYPES.iter().copied().find(|t| *t == normalized)
+    }
+
     fn parse_role_name(name: &str) -> Option<ContributorRole> {
         match name {
             "author" => Some(ContributorRole::Author),
@@ -711,12 +937,20 @@ impl Locale {
             "available-at" | "available_at" | "available at" => Some(GeneralTerm::AvailableAt),
             "ibid" => Some(GeneralTerm::Ibid),
             "and" => Some(GeneralTerm::And),
+            "between" => Some(GeneralTerm::Between),
+            "bc" => Some(GeneralTerm::Bc),
+            "ad" => Some(GeneralTerm::Ad),
+            "bce" => Some(GeneralTerm::Bce),
+            "ce" => Some(GeneralTerm::Ce),
+            "floruit" => Some(GeneralTerm::Floruit),
             "et-al" | "et_al" | "et al" => Some(GeneralTerm::EtAl),
             "and-others" | "and_others" | "and others" => Some(GeneralTerm::AndOthers),
             "forthcoming" => Some(GeneralTerm::Forthcoming),
             "online" => Some(GeneralTerm::Online),
             "review-of" | "review_of" | "review of" => Some(GeneralTerm::ReviewOf),
-            "original-work-published" => Some(GeneralTerm::OriginalWorkPublished),
+            "original-work-published" | "original_work_published" | "original work published" => {
+                Some(GeneralTerm::OriginalWorkPublished)
+            }
             "patent" => Some(GeneralTerm::Patent),
             "volume" => Some(GeneralTerm::Volume),
             "issue" => Some(GeneralTerm::Issue),
@@ -724,6 +958,13 @@ impl Locale {
             "chapter" => Some(GeneralTerm::Chapter),
             "edition" => Some(GeneralTerm::Edition),
             "section" => Some(GeneralTerm::Section),
+            "call-number" | "call_number" | "call number" => Some(GeneralTerm::CallNumber),
+            "scale" => Some(GeneralTerm::Scale),
+            "submitted" => Some(GeneralTerm::Submitted),
+            "version" => Some(GeneralTerm::Version),
+            "hour" => Some(GeneralTerm::Hour),
+            "minute" => Some(GeneralTerm::Minute),
+            "second" => Some(GeneralTerm::Second),
             _ => None,
         }
     }
@@ -735,16 +976,20 @@ impl Locale {
                 short: s.clone(),
             },
             raw::RawTermValue::Forms(forms) => {
-                let long = forms
-                    .get("long")
-                    .and_then(|v| v.as_string())
-                    .unwrap_or("")
-                    .to_string();
+                // Forms may be plain strings or singular/plural pairs.
+                let singular = |v: &raw::RawTermValue| match v {
+                    raw::RawTermValue::SingularPlural { singular, .. } => Some(singular.clone()),
+                    raw::RawTermValue::Forms(numbers) => numbers
+                        .get("singular")
+                        .and_then(|s| s.as_string())
+                        .map(str::to_string),
+                    other => other.as_string().map(str::to_string),
+                };
+                let long = forms.get("long").and_then(singular).unwrap_or_default();
                 let short = forms
                     .get("short")
-                    .and_then(|v| v.as_string())
-                    .unwrap_or(&long)
-                    .to_string();
+                    .and_then(singular)
+                    .unwrap_or_else(|| long.clone());
                 SimpleTerm { long, short }
             }
             raw::RawTermValue::SingularPlural { singular, .. } => SimpleTerm {
@@ -872,4 +1117,70 @@ terms:
         assert_eq!(locale.month_name(1, false), "Januar");
         assert_eq!(locale.month_name(3, false), "März");
     }
+
+    #[test]
+    fn test_yaml_item_type_terms() {
+        let yaml = r#"
+locale: de-DE
+terms:
+  report:
+    long: Bericht
+  motion_picture:
+    long: Videoaufnahme
+    short: Videoaufn.
+"#;
+
+        let locale = Locale::from_yaml_str(yaml).unwrap();
+        assert_eq!(
+            locale.item_type_term("report", TermForm::Long),
+            Some("Bericht")
+        );
+        assert_eq!(
+            locale.item_type_term("motion-picture", TermForm::Short),
+            Some("Videoaufn.")
+        );
+        assert_eq!(
+            locale.item_type_term("thesis", TermForm::Long),
+            Some("thesis")
+        );
+    }
+
+    #[test]
+    fn test_ordinals() {
+        let locale = Locale::en_us();
+        assert_eq!(locale.ordinal(1), "1st");
+        assert_eq!(locale.ordinal(2), "2nd");
+        assert_eq!(locale.ordinal(11), "11th");
+        assert_eq!(locale.ordinal(22), "22nd");
+        assert_eq!(locale.ordinal(113), "113th");
+        assert_eq!(locale.long_ordinal(2), "second");
+        assert_eq!(locale.long_ordinal(12), "12th");
+    }
+
+    #[test]
+    fn test_yaml_ordinal_terms() {
+        let yaml = r#"
+locale: de-DE
+terms:
+  ordinal:
+    long: "."
+  long_ordinal_02:
+    long: zweite
+  edition:
+    long:
+      singular: Auflage
+      plural: Auflagen
+    short:
+      singular: Aufl.
+      plural: Aufl.
+"#;
+        let locale = Locale::from_yaml_str(yaml).unwrap();
+        assert_eq!(locale.ordinal(1), "1.");
+        assert_eq!(locale.ordinal(2), "2.");
+        assert_eq!(locale.long_ordinal(2), "zweite");
+        assert_eq!(
+            locale.general_term(&GeneralTerm::Edition, TermForm::Short),
+            Some("Aufl.")
+        );
+    }
 }
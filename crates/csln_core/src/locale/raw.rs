@@ -25,6 +25,13 @@ pub struct RawLocale {
     /// General terms keyed by term name.
     #[serde(default)]
     pub terms: HashMap<String, RawTermValue>,
+    /// Whether initials are written without periods ("JP" rather than
+    /// "J. P.").
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub unpunctuated_initials: bool,
+    /// The space between a label and its value (e.g., "p. 42").
+    #[serde(default)]
+    pub label_space: crate::locale::LabelSpace,
 }
 
 /// Raw date terms for YAML parsing.
@@ -40,6 +47,8 @@ pub struct RawDateTerms {
     pub uncertainty_term: Option<String>,
     #[serde(default)]
     pub open_ended_term: Option<String>,
+    #[serde(default)]
+    pub numeric_delimiter: Option<String>,
 }
 
 /// Raw month names for YAML parsing.
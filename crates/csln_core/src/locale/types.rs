@@ -6,6 +6,33 @@ SPDX-FileCopyrightText: © 2023-2026 Bruce D'Arcus
 #[cfg(feature = "schema")]
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// The space between a label and its value (e.g., "p. 42", "§ 5").
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "kebab-case")]
+pub enum LabelSpace {
+    /// An ordinary space.
+    #[default]
+    Space,
+    /// A no-break space (U+00A0).
+    NoBreak,
+    /// A narrow no-break space (U+202F).
+    NarrowNoBreak,
+}
+
+impl LabelSpace {
+    /// The space character. Output formats render the no-break forms in
+    /// their own syntax (e.g., `&nbsp;` in HTML, `~` in LaTeX).
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LabelSpace::Space => " ",
+            LabelSpace::NoBreak => "\u{00A0}",
+            LabelSpace::NarrowNoBreak => "\u{202F}",
+        }
+    }
+}
 
 /// Form for term lookup.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -37,6 +64,12 @@ pub enum GeneralTerm {
     AvailableAt,
     Ibid,
     And,
+    Between,
+    Bc,
+    Ad,
+    Bce,
+    Ce,
+    Floruit,
     EtAl,
     AndOthers,
     Forthcoming,
@@ -50,6 +83,13 @@ pub enum GeneralTerm {
     Chapter,
     Edition,
     Section,
+    CallNumber,
+    Scale,
+    Submitted,
+    Version,
+    Hour,
+    Minute,
+    Second,
 }
 
 /// General terms used in citations and bibliographies.
@@ -91,7 +131,7 @@ pub struct Terms {
     pub retrieved: Option<String>,
     /// All other general terms.
     #[serde(flatten, default)]
-    pub general: std::collections::HashMap<GeneralTerm, SimpleTerm>,
+    pub general: HashMap<GeneralTerm, SimpleTerm>,
 }
 
 impl Terms {
@@ -119,7 +159,120 @@ impl Terms {
             in_: Some("in".into()),
             no_date: Some("n.d.".into()),
             retrieved: Some("retrieved".into()),
-            general: std::collections::HashMap::new(),
+            general: HashMap::from([
+                (
+                    GeneralTerm::Edition,
+                    SimpleTerm {
+                        long: "edition".into(),
+                        short: "ed.".into(),
+                    },
+                ),
+                (
+                    GeneralTerm::CallNumber,
+                    SimpleTerm {
+                        long: "call number".into(),
+                        short: "call no.".into(),
+                    },
+                ),
+                (
+                    GeneralTerm::OriginalWorkPublished,
+                    SimpleTerm {
+                        long: "original work published".into(),
+                        short: "orig. pub.".into(),
+                    },
+                ),
+                (
+                    GeneralTerm::Patent,
+                    SimpleTerm {
+                        long: "patent".into(),
+                        short: "pat.".into(),
+                    },
+                ),
+                (
+                    GeneralTerm::Scale,
+                    SimpleTerm {
+                        long: "scale".into(),
+                        short: "sc.".into(),
+                    },
+                ),
+                (
+                    GeneralTerm::Between,
+                    SimpleTerm {
+                        long: "between".into(),
+                        short: "between".into(),
+                    },
+                ),
+                (
+                    GeneralTerm::Bc,
+                    SimpleTerm {
+                        long: "BC".into(),
+                        short: "BC".into(),
+                    },
+                ),
+                (
+                    GeneralTerm::Ad,
+                    SimpleTerm {
+                        long: "AD".into(),
+                        short: "AD".into(),
+                    },
+                ),
+                (
+                    GeneralTerm::Bce,
+                    SimpleTerm {
+                        long: "BCE".into(),
+                        short: "BCE".into(),
+                    },
+                ),
+                (
+                    GeneralTerm::Ce,
+                    SimpleTerm {
+                        long: "CE".into(),
+                        short: "CE".into(),
+                    },
+                ),
+                (
+                    GeneralTerm::Floruit,
+                    SimpleTerm {
+                        long: "flourished".into(),
+                        short: "fl.".into(),
+                    },
+                ),
+                (
+                    GeneralTerm::Submitted,
+                    SimpleTerm {
+                        long: "submitted".into(),
+                        short: "submitted".into(),
+                    },
+                ),
+                (
+                    GeneralTerm::Version,
+                    SimpleTerm {
+                        long: "version".into(),
+                        short: "v.".into(),
+                    },
+                ),
+                (
+                    GeneralTerm::Hour,
+                    SimpleTerm {
+                        long: "hour".into(),
+                        short: "hr.".into(),
+                    },
+                ),
+                (
+                    GeneralTerm::Minute,
+                    SimpleTerm {
+                        long: "minute".into(),
+                        short: "min.".into(),
+                    },
+                ),
+                (
+                    GeneralTerm::Second,
+                    SimpleTerm {
+                        long: "second".into(),
+                        short: "sec.".into(),
+                    },
+                ),
+            ]),
         }
     }
 }
@@ -171,6 +324,48 @@ pub struct SingularPlural {
     pub plural: String,
 }
 
+/// Ordinal terms (e.g., "2nd", "second").
+#[derive(Debug, Default, Deserialize, Serialize, Clone)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "kebab-case")]
+pub struct OrdinalTerms {
+    /// Default ordinal suffix (e.g., "th").
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suffix: Option<String>,
+    /// Suffixes keyed by the last one or two digits they match (e.g., 1 → "st", 11 → "th").
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub suffixes: HashMap<u32, String>,
+    /// Spelled-out ordinals keyed by number (e.g., 2 → "second").
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub long: HashMap<u32, String>,
+}
+
+impl OrdinalTerms {
+    /// Create English (US) ordinal terms.
+    pub fn en_us() -> Self {
+        let suffixes = [
+            (1, "st"),
+            (2, "nd"),
+            (3, "rd"),
+            (11, "th"),
+            (12, "th"),
+            (13, "th"),
+        ];
+        let long = [
+            "first", "second", "third", "fourth", "fifth", "sixth", "seventh", "eighth", "ninth",
+            "tenth",
+        ];
+        Self {
+            suffix: Some("th".into()),
+            suffixes: suffixes
+                .into_iter()
+                .map(|(n, s)| (n, s.to_string()))
+                .collect(),
+            long: (1..).zip(long).map(|(n, s)| (n, s.to_string())).collect(),
+        }
+    }
+}
+
 /// Date-related terms.
 #[derive(Debug, Default, Deserialize, Serialize, Clone)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
@@ -187,6 +382,9 @@ pub struct DateTerms {
     /// Term for open-ended date ranges (e.g., "present").
     #[serde(skip_serializing_if = "Option::is_none")]
     pub open_ended_term: Option<String>,
+    /// Delimiter between parts of numeric dates (e.g., "/" or ".").
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub numeric_delimiter: Option<String>,
 }
 
 impl DateTerms {
@@ -202,6 +400,7 @@ impl DateTerms {
             ],
             uncertainty_term: Some("uncertain".into()),
             open_ended_term: Some("present".into()),
+            numeric_delimiter: Some("/".into()),
         }
     }
 }
@@ -52,6 +52,8 @@ macro_rules! dispatch_component {
             $crate::template::TemplateComponent::Variable($inner) => $action,
             $crate::template::TemplateComponent::List($inner) => $action,
             $crate::template::TemplateComponent::Term($inner) => $action,
+            $crate::template::TemplateComponent::Text($inner) => $action,
+            $crate::template::TemplateComponent::Conditional($inner) => $action,
         }
     };
 }
@@ -212,12 +214,16 @@ macro_rules! ref_book {
                 )),
                 editor: None,
                 translator: None,
+                composer: None,
+                illustrator: None,
                 issued: $crate::reference::EdtfString($year.to_string()),
+                submitted: None,
                 publisher: None,
                 url: None,
                 accessed: None,
                 language: None,
                 note: None,
+                abstract_text: None,
                 isbn: None,
                 doi: None,
                 edition: None,
@@ -228,6 +234,15 @@ macro_rules! ref_book {
                 keywords: None,
                 original_date: None,
                 original_title: None,
+                original_publisher: None,
+                number_of_volumes: None,
+                number_of_pages: None,
+                archive: None,
+                archive_place: None,
+                archive_location: None,
+                call_number: None,
+                scale: None,
+                dimensions: None,
             },
         ))
     };
@@ -265,12 +280,16 @@ macro_rules! ref_book_authors {
                 )),
                 editor: None,
                 translator: None,
+                composer: None,
+                illustrator: None,
                 issued: $crate::reference::EdtfString($year.to_string()),
+                submitted: None,
                 publisher: None,
                 url: None,
                 accessed: None,
                 language: None,
                 note: None,
+                abstract_text: None,
                 isbn: None,
                 doi: None,
                 edition: None,
@@ -281,6 +300,15 @@ macro_rules! ref_book_authors {
                 keywords: None,
                 original_date: None,
                 original_title: None,
+                original_publisher: None,
+                number_of_volumes: None,
+                number_of_pages: None,
+                archive: None,
+                archive_place: None,
+                archive_location: None,
+                call_number: None,
+                scale: None,
+                dimensions: None,
             },
         ))
     }};
@@ -308,7 +336,9 @@ macro_rules! ref_article {
                     },
                 )),
                 translator: None,
+                director: None,
                 issued: $crate::reference::EdtfString($year.to_string()),
+                submitted: None,
                 parent: $crate::reference::Parent::Embedded($crate::reference::Serial {
                     r#type: $crate::reference::SerialType::AcademicJournal,
                     title: $crate::reference::Title::Single(String::new()),
@@ -320,13 +350,19 @@ macro_rules! ref_article {
                 accessed: None,
                 language: None,
                 note: None,
+                abstract_text: None,
                 doi: None,
                 pages: None,
                 volume: None,
                 issue: None,
                 genre: None,
                 medium: None,
+                pmid: None,
+                pmcid: None,
+                dimensions: None,
                 keywords: None,
+                reviewed_title: None,
+                reviewed_author: None,
             },
         ))
     };
@@ -363,7 +399,9 @@ macro_rules! ref_article_authors {
                     $crate::reference::ContributorList(_authors),
                 )),
                 translator: None,
+                director: None,
                 issued: $crate::reference::EdtfString($year.to_string()),
+                submitted: None,
                 parent: $crate::reference::Parent::Embedded($crate::reference::Serial {
                     r#type: $crate::reference::SerialType::AcademicJournal,
                     title: $crate::reference::Title::Single(String::new()),
@@ -375,13 +413,19 @@ macro_rules! ref_article_authors {
                 accessed: None,
                 language: None,
                 note: None,
+                abstract_text: None,
                 doi: None,
                 pages: None,
                 volume: None,
                 issue: None,
                 genre: None,
                 medium: None,
+                pmid: None,
+                pmcid: None,
+                dimensions: None,
                 keywords: None,
+                reviewed_title: None,
+                reviewed_author: None,
             },
         ))
     }};
@@ -37,11 +37,72 @@ pub struct BibliographyConfig {
     /// Set to true to suppress the period (APA 7th, Bluebook style).
     #[serde(default, skip_serializing_if = "std::ops::Not::not")]
     pub suppress_period_after_url: bool,
+    /// Whether a final period is appended to each entry, applied after
+    /// punctuation collapsing. Defaults to `preserve`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub terminal_punctuation: Option<TerminalPunctuation>,
+    /// Render each reference's `note` as an annotation after its entry
+    /// (a separate block in HTML, a new line in plain text).
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub annotate: bool,
+    /// Render each reference's `abstract` as a trailing block after its
+    /// entry (and after any annotation).
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub show_abstract: bool,
+    /// Maximum abstract length in characters. Longer abstracts are cut at a
+    /// word boundary and end with an ellipsis.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub abstract_max_length: Option<usize>,
+    /// Container element wrapped around the whole bibliography list.
+    /// Honored by the HTML and Djot renderers; other formats ignore it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wrapper: Option<BibliographyWrapper>,
     /// Custom user-defined fields for extensions.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub custom: Option<HashMap<String, serde_json::Value>>,
 }
 
+/// Output container for a rendered bibliography.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct BibliographyWrapper {
+    /// Element name for the container (e.g., "div", "section", "ol").
+    #[serde(default = "default_wrapper_element")]
+    pub element: String,
+    /// Class attribute for the container (e.g., "references").
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub class: Option<String>,
+}
+
+fn default_wrapper_element() -> String {
+    "div".to_string()
+}
+
+impl Default for BibliographyWrapper {
+    fn default() -> Self {
+        Self {
+            element: default_wrapper_element(),
+            class: None,
+        }
+    }
+}
+
+/// Final punctuation for bibliography entries.
+#[derive(Debug, Default, PartialEq, Clone, Copy, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "kebab-case")]
+pub enum TerminalPunctuation {
+    /// End every entry with a period, unless it already ends with terminal
+    /// punctuation or a URL/DOI.
+    Period,
+    /// Never append a period; `entry-suffix` is ignored.
+    None,
+    /// Leave entries as rendered, applying only `entry-suffix`.
+    #[default]
+    Preserve,
+}
+
 /// Rules for subsequent author substitution.
 #[derive(Debug, Default, PartialEq, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
@@ -60,6 +60,10 @@ pub struct ContributorConfig {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub delimiter: Option<String>,
     /// Conjunction between last two contributors.
+    ///
+    /// Set under `citation.options` or `bibliography.options` to use a
+    /// different conjunction in each context; a contributor component's own
+    /// `and` takes precedence over both.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub and: Option<AndOptions>,
     /// When to include delimiter before the last contributor.
@@ -270,9 +274,21 @@ pub struct ShortenListOptions {
     /// How to render "and others".
     #[serde(default)]
     pub and_others: AndOtherOptions,
+    /// Explicit "and others" term, overriding the locale's "et al." or
+    /// "and others" term.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub term: Option<String>,
+    /// Thresholds for citations after a reference's first.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subsequent: Option<ShortenSubsequent>,
     /// When to use delimiter before last name.
     #[serde(default)]
     pub delimiter_precedes_last: DelimiterPrecedesLast,
+    /// Italicize the "et al." term, independently of the names' formatting.
+    /// Its terminal period stays outside the emphasis so it can merge with
+    /// following punctuation.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub et_al_emph: bool,
 }
 
 impl Default for ShortenListOptions {
@@ -282,17 +298,34 @@ impl Default for ShortenListOptions {
             use_first: 1,
             use_last: None,
             and_others: AndOtherOptions::default(),
+            term: None,
+            subsequent: None,
             delimiter_precedes_last: DelimiterPrecedesLast::default(),
+            et_al_emph: false,
         }
     }
 }
 
+/// Et al. thresholds for subsequent citations (et-al-subsequent-min and
+/// et-al-subsequent-use-first in CSL 1.0).
+#[derive(Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "kebab-case")]
+pub struct ShortenSubsequent {
+    /// Minimum number of names to trigger shortening.
+    pub min: u8,
+    /// Number of names to show when shortened.
+    pub use_first: u8,
+}
+
 /// How to render "and others" / et al.
 #[derive(Debug, Default, PartialEq, Clone, Copy, Serialize, Deserialize)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(rename_all = "kebab-case")]
 pub enum AndOtherOptions {
+    /// The locale's "et al." term (e.g., "u. a." in German).
     #[default]
     EtAl,
+    /// The locale's spelled-out "and others" term (e.g., "und andere").
     Text,
 }
@@ -51,17 +51,55 @@ pub struct DateConfig {
     /// Marker for approximate dates (e.g., "ca. " or "~"). None suppresses display.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub approximation_marker: Option<String>,
+    /// Delimiter between date parts (e.g., "/" in "5/15/2023"). None uses the
+    /// locale default for numeric dates and a space for textual ones.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delimiter: Option<String>,
     /// Delimiter for date ranges (default: en-dash "–").
     #[serde(default = "default_range_delimiter")]
     pub range_delimiter: String,
     /// Marker for open-ended ranges (e.g., "–present"). None uses locale default.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub open_range_marker: Option<String>,
+    /// How closed ranges are phrased (default: dash, "2004–2006").
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub range_form: Option<DateRangeForm>,
+    /// Era labels for years (e.g., "300 BCE"). None renders BCE years as
+    /// negative numbers.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub era: Option<EraForm>,
     /// Custom user-defined fields for extensions.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub custom: Option<HashMap<String, serde_json::Value>>,
 }
 
+/// How a closed date range is phrased.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "kebab-case")]
+pub enum DateRangeForm {
+    /// Join the endpoints with the range delimiter ("2004–2006").
+    #[default]
+    Dash,
+    /// Use the locale's "between" and "and" terms ("between 2004 and 2006").
+    Narrative,
+}
+
+/// Which locale terms label the era of a year.
+///
+/// BCE years are always labeled; CE years only before 1000, where the era is
+/// not obvious from the number alone.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "kebab-case")]
+pub enum EraForm {
+    /// The "bce" and "ce" terms ("300 BCE", "14 CE").
+    #[default]
+    Bce,
+    /// The "bc" and "ad" terms ("300 BC", "14 AD").
+    Bc,
+}
+
 fn default_range_delimiter() -> String {
     "–".to_string() // U+2013 en-dash
 }
@@ -72,8 +110,11 @@ impl Default for DateConfig {
             month: MonthFormat::Long,
             uncertainty_marker: Some("?".to_string()),
             approximation_marker: Some("ca. ".to_string()),
+            delimiter: None,
             range_delimiter: default_range_delimiter(),
             open_range_marker: None,
+            range_form: None,
+            era: None,
             custom: None,
         }
     }
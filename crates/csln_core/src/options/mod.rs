@@ -13,18 +13,20 @@ pub mod multilingual;
 pub mod processing;
 pub mod substitute;
 
-pub use bibliography::{BibliographyConfig, SubsequentAuthorSubstituteRule};
+pub use bibliography::{
+    BibliographyConfig, BibliographyWrapper, SubsequentAuthorSubstituteRule, TerminalPunctuation,
+};
 pub use contributors::{
     AndOptions, AndOtherOptions, ContributorConfig, ContributorConfigEntry, DelimiterPrecedesLast,
     DemoteNonDroppingParticle, DisplayAsSort, EditorLabelFormat, RoleOptions, RoleRendering,
-    ShortenListOptions,
+    ShortenListOptions, ShortenSubsequent,
 };
-pub use dates::{DateConfig, DateConfigEntry};
+pub use dates::{DateConfig, DateConfigEntry, DateRangeForm, EraForm};
 pub use localization::{Localize, MonthFormat, Scope};
 pub use multilingual::{MultilingualConfig, MultilingualMode, ScriptConfig};
 pub use processing::{
-    Disambiguation, Group, LabelConfig, LabelParams, LabelPreset, Processing, ProcessingCustom,
-    Sort, SortKey, SortSpec,
+    Disambiguation, DisambiguationStrategy, GivennameDisambiguationRule, Group, LabelConfig,
+    LabelParams, LabelPreset, Processing, ProcessingCustom, Sort, SortKey, SortSpec,
 };
 pub use substitute::{Substitute, SubstituteConfig, SubstituteKey};
 
@@ -87,6 +89,14 @@ pub struct Config {
     /// Hyperlink configuration.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub links: Option<LinksConfig>,
+    /// Which of a reference's DOI and URL to render when it has both.
+    /// Defaults to `both`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub link_precedence: Option<LinkPrecedence>,
+    /// Omit the URL and accessed date of references that have a DOI, as
+    /// APA 7 does. Defaults to false.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suppress_access_when_doi: Option<bool>,
     /// Whether to place periods/commas inside quotation marks.
     /// true = American style ("text."), false = British style ("text".)
     /// Defaults to false; en-US locale typically sets this to true.
@@ -104,6 +114,9 @@ pub struct Config {
     /// Strip trailing periods from terms, labels, and abbreviated dates.
     #[serde(skip_serializing_if = "Option::is_none", rename = "strip-periods")]
     pub strip_periods: Option<bool>,
+    /// The marker sequence for automatically generated notes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub note_marker: Option<NoteMarker>,
     /// Custom user-defined fields for extensions.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub custom: Option<HashMap<String, serde_json::Value>>,
@@ -128,6 +141,34 @@ pub enum PageRangeFormat {
     Chicago16,
 }
 
+/// Precedence between a reference's DOI and URL.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "kebab-case")]
+pub enum LinkPrecedence {
+    /// Render the DOI, falling back to the URL when there is no DOI.
+    DoiThenUrl,
+    /// Render the URL, falling back to the DOI when there is no URL.
+    UrlThenDoi,
+    /// Render both.
+    #[default]
+    Both,
+}
+
+/// Note marker sequences.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "kebab-case")]
+pub enum NoteMarker {
+    /// Arabic numerals: 1, 2, 3, ...
+    #[default]
+    Number,
+    /// Symbols: *, †, ‡, §, then doubled (**, ††, ...).
+    Symbol,
+    /// Lowercase letters: a, b, ..., z, aa, ab, ...
+    Letter,
+}
+
 pub mod titles;
 
 pub use titles::{TitleRendering, TitlesConfig, TitlesConfigEntry};
@@ -198,9 +239,12 @@ impl Config {
             page_range_format,
             bibliography,
             links,
+            link_precedence,
+            suppress_access_when_doi,
             volume_pages_delimiter,
             semantic_classes,
             strip_periods,
+            note_marker,
             custom,
         );
 
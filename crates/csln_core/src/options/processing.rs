@@ -141,6 +141,8 @@ impl Processing {
                     names: true,
                     add_givenname: true,
                     year_suffix: true,
+                    strategy: None,
+                    givenname_rule: None,
                 }),
             },
             Processing::Numeric => ProcessingCustom {
@@ -155,6 +157,8 @@ impl Processing {
                     names: true,
                     add_givenname: false,
                     year_suffix: false,
+                    strategy: None,
+                    givenname_rule: None,
                 }),
             },
             Processing::Label(_) => ProcessingCustom {
@@ -164,6 +168,8 @@ impl Processing {
                     names: false,
                     add_givenname: false,
                     year_suffix: true,
+                    strategy: None,
+                    givenname_rule: None,
                 }),
             },
             Processing::Custom(custom) => custom.clone(),
@@ -288,6 +294,15 @@ pub struct Disambiguation {
     #[serde(default)]
     pub add_givenname: bool,
     pub year_suffix: bool,
+    /// The disambiguation steps to try, in order, stopping once citations
+    /// are unique. When set, this replaces the boolean flags above, so
+    /// omitting a step (e.g., `add-givenname`) disables it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub strategy: Option<Vec<DisambiguationStrategy>>,
+    /// Which names gain given names when disambiguating, and whether full
+    /// given names may follow initials (`givenname-disambiguation-rule`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub givenname_rule: Option<GivennameDisambiguationRule>,
 }
 
 impl Default for Disambiguation {
@@ -296,10 +311,62 @@ impl Default for Disambiguation {
             names: true,
             add_givenname: false,
             year_suffix: false,
+            strategy: None,
+            givenname_rule: None,
         }
     }
 }
 
+/// A single step in the disambiguation escalation.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "kebab-case")]
+pub enum DisambiguationStrategy {
+    /// Show more names before "et al." (`disambiguate-add-names`).
+    AddNames,
+    /// Show given names or initials (`disambiguate-add-givenname`).
+    AddGivenname,
+    /// Add the short title after the author when same-author, same-year
+    /// works have distinct titles, e.g. "(Kuhn, Structure, 1962)".
+    AddTitle,
+    /// Append a letter to the year (`disambiguate-add-year-suffix`).
+    YearSuffix,
+}
+
+/// How given names are added to resolve ambiguous citations.
+///
+/// Expansion only applies to citations that collide. Initials are tried
+/// first; the `by-cite`, `all-names` and `primary-name` rules may then show
+/// full given names.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "kebab-case")]
+pub enum GivennameDisambiguationRule {
+    /// Like `all-names`, but only for names in ambiguous cites.
+    #[default]
+    ByCite,
+    /// Expand every displayed name, with full given names if needed.
+    AllNames,
+    /// Expand every displayed name, using initials only.
+    AllNamesWithInitials,
+    /// Expand only the first name, with full given names if needed.
+    PrimaryName,
+    /// Expand only the first name, using initials only.
+    PrimaryNameWithInitials,
+}
+
+impl GivennameDisambiguationRule {
+    /// Whether only the first name is expanded.
+    pub fn primary_only(self) -> bool {
+        matches!(self, Self::PrimaryName | Self::PrimaryNameWithInitials)
+    }
+
+    /// Whether full given names may be shown once initials are not enough.
+    pub fn allows_full_names(self) -> bool {
+        matches!(self, Self::ByCite | Self::AllNames | Self::PrimaryName)
+    }
+}
+
 /// Sorting configuration.
 #[derive(Debug, Default, Deserialize, Serialize, Clone, PartialEq)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
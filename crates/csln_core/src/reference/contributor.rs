@@ -33,9 +33,13 @@ pub struct MultilingualName {
 }
 
 /// A simple name is just a string, with an optional location.
+///
+/// Simple names are literal: they are rendered and sorted as a whole, and are
+/// never inverted or initialized (e.g., "World Health Organization").
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
 pub struct SimpleName {
+    #[serde(alias = "literal")]
     pub name: MultilingualString,
     pub location: Option<String>,
 }
@@ -123,6 +127,31 @@ impl FlatName {
             ""
         }
     }
+
+    /// The family part of the name as used for sorting.
+    ///
+    /// A non-dropping particle leads the key unless it is demoted, so
+    /// "van Gogh" files under "G" when demoted and under "V" otherwise.
+    pub fn family_sort_key(&self, demote_particle: bool) -> String {
+        match (&self.non_dropping_particle, &self.family) {
+            (Some(particle), Some(family)) if !demote_particle && !particle.is_empty() => {
+                join_particle(particle, family)
+            }
+            _ => self.family_or_literal().to_string(),
+        }
+    }
+}
+
+/// Join a particle to the name part that follows it.
+///
+/// Particles ending in an apostrophe or hyphen attach without a space
+/// ("d'Alembert", "al-Farabi"); others are separated by one ("van der Berg").
+pub fn join_particle(particle: &str, rest: &str) -> String {
+    if particle.ends_with(['\'', '\u{2019}', '-']) {
+        format!("{}{}", particle, rest)
+    } else {
+        format!("{} {}", particle, rest)
+    }
 }
 
 impl fmt::Display for Contributor {
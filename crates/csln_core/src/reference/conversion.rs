@@ -36,6 +36,17 @@ fn format_interviewer_note(names: &[csl_legacy::csl_json::Name]) -> Option<Strin
     }
 }
 
+/// Split a delimited keyword string (e.g., "history, science; art").
+fn split_keywords(value: &str) -> Option<Vec<String>> {
+    let keywords: Vec<String> = value
+        .split([',', ';'])
+        .map(str::trim)
+        .filter(|k| !k.is_empty())
+        .map(str::to_string)
+        .collect();
+    (!keywords.is_empty()).then_some(keywords)
+}
+
 impl From<csl_legacy::csl_json::Reference> for InputReference {
     fn from(legacy: csl_legacy::csl_json::Reference) -> Self {
         let id = Some(legacy.id);
@@ -50,10 +61,48 @@ impl From<csl_legacy::csl_json::Reference> for InputReference {
             .unwrap_or(EdtfString(String::new()));
         let url = legacy.url.and_then(|u| Url::parse(&u).ok());
         let accessed = legacy.accessed.map(EdtfString::from);
+        let original_date = legacy
+            .extra
+            .get("original-date")
+            .and_then(|v| {
+                serde_json::from_value::<csl_legacy::csl_json::DateVariable>(v.clone()).ok()
+            })
+            .map(EdtfString::from);
+        let submitted = legacy
+            .extra
+            .get("submitted")
+            .and_then(|v| {
+                serde_json::from_value::<csl_legacy::csl_json::DateVariable>(v.clone()).ok()
+            })
+            .map(EdtfString::from);
         let mut note = legacy.note;
+        let abstract_text = legacy.abstract_text;
         let doi = legacy.doi;
         let isbn = legacy.isbn;
         let edition = legacy.edition.map(|e| e.to_string());
+        let extra_str = |keys: &[&str]| {
+            keys.iter()
+                .find_map(|k| legacy.extra.get(*k).and_then(|v| v.as_str()))
+                .map(str::to_string)
+        };
+        let archive = extra_str(&["archive"]);
+        let archive_place = extra_str(&["archive-place", "archive_place"]);
+        let archive_location = extra_str(&["archive_location", "archive-location"]);
+        let call_number = extra_str(&["call-number", "call_number"]);
+        let scale = extra_str(&["scale"]);
+        let dimensions = extra_str(&["dimensions"]);
+        let original_publisher = extra_str(&["original-publisher"]).map(|name| {
+            Contributor::SimpleName(SimpleName {
+                name: name.into(),
+                location: extra_str(&["original-publisher-place"]),
+            })
+        });
+        let keywords = extra_str(&["keyword"]).and_then(|k| split_keywords(&k));
+        let version = legacy.extra.get("version").and_then(|v| match v {
+            serde_json::Value::String(s) => Some(s.clone()),
+            serde_json::Value::Number(n) => Some(n.to_string()),
+            _ => None,
+        });
 
         match legacy.ref_type.as_str() {
             "book"
@@ -62,8 +111,12 @@ impl From<csl_legacy::csl_json::Reference> for InputReference {
             | "webpage"
             | "post"
             | "post-weblog"
-            | "software"
+            | "song"
             | "interview"
+            | "manuscript"
+            | "map"
+            | "figure"
+            | "graphic"
             | "personal_communication"
             | "personal-communication" => {
                 if (legacy.ref_type == "personal_communication"
@@ -86,6 +139,16 @@ impl From<csl_legacy::csl_json::Reference> for InputReference {
                     MonographType::Webpage
                 } else if legacy.ref_type.contains("post") {
                     MonographType::Post
+                } else if legacy.ref_type == "song" {
+                    MonographType::Recording
+                } else if legacy.ref_type == "manuscript" {
+                    MonographType::Manuscript
+                } else if legacy.ref_type == "map" {
+                    MonographType::Map
+                } else if legacy.ref_type == "figure" {
+                    MonographType::Figure
+                } else if legacy.ref_type == "graphic" {
+                    MonographType::Graphic
                 } else if legacy.ref_type == "personal_communication"
                     || legacy.ref_type == "personal-communication"
                 {
@@ -100,7 +163,10 @@ impl From<csl_legacy::csl_json::Reference> for InputReference {
                     author: legacy.author.map(Contributor::from),
                     editor: legacy.editor.map(Contributor::from),
                     translator: legacy.translator.map(Contributor::from),
+                    composer: legacy.composer.map(Contributor::from),
+                    illustrator: legacy.illustrator.map(Contributor::from),
                     issued,
+                    submitted,
                     publisher: legacy.publisher.map(|n| {
                         Contributor::SimpleName(SimpleName {
                             name: n.into(),
@@ -111,6 +177,7 @@ impl From<csl_legacy::csl_json::Reference> for InputReference {
                     accessed,
                     language,
                     note: note.clone(),
+                    abstract_text,
                     isbn,
                     doi,
                     edition,
@@ -118,9 +185,18 @@ impl From<csl_legacy::csl_json::Reference> for InputReference {
                     collection_number: legacy.collection_number.map(|v| v.to_string()),
                     genre: legacy.genre,
                     medium: legacy.medium,
-                    keywords: None,
-                    original_date: None,
+                    keywords,
+                    original_date,
                     original_title: None,
+                    original_publisher,
+                    number_of_volumes: legacy.number_of_volumes.map(|v| v.to_string()),
+                    number_of_pages: legacy.number_of_pages.map(|v| v.to_string()),
+                    archive,
+                    archive_place,
+                    archive_location,
+                    call_number,
+                    scale,
+                    dimensions,
                 }))
             }
             "chapter" | "paper-conference" | "entry-dictionary" => {
@@ -170,14 +246,16 @@ impl From<csl_legacy::csl_json::Reference> for InputReference {
                     accessed,
                     language,
                     note: note.clone(),
+                    abstract_text,
                     doi,
                     genre: legacy.genre,
                     medium: legacy.medium,
-                    keywords: None,
+                    keywords,
                 }))
             }
             "article-journal" | "article" | "article-magazine" | "article-newspaper"
-            | "broadcast" | "motion_picture" | "entry-encyclopedia" => {
+            | "broadcast" | "motion_picture" | "motion-picture" | "entry-encyclopedia"
+            | "review" | "review-book" => {
                 let mut genre = legacy.genre;
                 if legacy.ref_type == "entry-encyclopedia" && genre.is_none() {
                     // Preserve original entry type so style type-templates can target it.
@@ -187,7 +265,9 @@ impl From<csl_legacy::csl_json::Reference> for InputReference {
                     "article-journal" => SerialType::AcademicJournal,
                     "article-magazine" => SerialType::Magazine,
                     "article-newspaper" => SerialType::Newspaper,
-                    "broadcast" | "motion_picture" => SerialType::BroadcastProgram,
+                    "broadcast" | "motion_picture" | "motion-picture" => {
+                        SerialType::BroadcastProgram
+                    }
                     _ => SerialType::AcademicJournal,
                 };
                 let parent_title = legacy
@@ -196,11 +276,17 @@ impl From<csl_legacy::csl_json::Reference> for InputReference {
                     .unwrap_or(Title::Single(String::new()));
                 InputReference::SerialComponent(Box::new(SerialComponent {
                     id,
-                    r#type: SerialComponentType::Article,
+                    r#type: match legacy.ref_type.as_str() {
+                        "review" => SerialComponentType::Review,
+                        "review-book" => SerialComponentType::ReviewBook,
+                        _ => SerialComponentType::Article,
+                    },
                     title: Some(title),
                     author: legacy.author.map(Contributor::from),
                     translator: legacy.translator.map(Contributor::from),
+                    director: legacy.director.map(Contributor::from),
                     issued,
+                    submitted,
                     parent: Parent::Embedded(Serial {
                         r#type: serial_type,
                         title: parent_title,
@@ -217,6 +303,7 @@ impl From<csl_legacy::csl_json::Reference> for InputReference {
                     accessed,
                     language,
                     note: note.clone(),
+                    abstract_text,
                     doi,
                     pages: legacy.page,
                     volume: legacy.volume.map(|v| match v {
@@ -226,8 +313,10 @@ impl From<csl_legacy::csl_json::Reference> for InputReference {
                     issue: legacy
                         .issue
                         .or_else(|| {
-                            if legacy.ref_type == "broadcast" || legacy.ref_type == "motion_picture"
-                            {
+                            if matches!(
+                                legacy.ref_type.as_str(),
+                                "broadcast" | "motion_picture" | "motion-picture"
+                            ) {
                                 legacy.number.as_ref().map(|n| {
                                     csl_legacy::csl_json::StringOrNumber::String(n.clone())
                                 })
@@ -241,7 +330,12 @@ impl From<csl_legacy::csl_json::Reference> for InputReference {
                         }),
                     genre,
                     medium: legacy.medium,
-                    keywords: None,
+                    pmid: extra_str(&["PMID", "pmid"]),
+                    pmcid: extra_str(&["PMCID", "pmcid"]),
+                    dimensions,
+                    keywords,
+                    reviewed_title: legacy.reviewed_title.map(Title::Single),
+                    reviewed_author: legacy.reviewed_author.map(Contributor::from),
                 }))
             }
             "legal-case" | "legal_case" => InputReference::LegalCase(Box::new(LegalCase {
@@ -257,9 +351,9 @@ impl From<csl_legacy::csl_json::Reference> for InputReference {
                 language,
                 note: note.clone(),
                 doi,
-                keywords: None,
+                keywords,
             })),
-            "statute" | "legislation" => InputReference::Statute(Box::new(Statute {
+            "statute" | "legislation" | "bill" => InputReference::Statute(Box::new(Statute {
                 id,
                 title,
                 authority: legacy.authority,
@@ -271,12 +365,13 @@ impl From<csl_legacy::csl_json::Reference> for InputReference {
                 accessed,
                 language,
                 note: note.clone(),
-                keywords: None,
+                keywords,
             })),
             "treaty" => InputReference::Treaty(Box::new(Treaty {
                 id,
                 title,
                 author: legacy.author.map(Contributor::from),
+                authority: legacy.authority,
                 volume: legacy.volume.map(|v| v.to_string()),
                 reporter: legacy.container_title,
                 page: legacy.page,
@@ -285,7 +380,7 @@ impl From<csl_legacy::csl_json::Reference> for InputReference {
                 accessed,
                 language,
                 note: note.clone(),
-                keywords: None,
+                keywords,
             })),
             "standard" => InputReference::Standard(Box::new(Standard {
                 id,
@@ -304,7 +399,7 @@ impl From<csl_legacy::csl_json::Reference> for InputReference {
                 accessed,
                 language,
                 note: note.clone(),
-                keywords: None,
+                keywords,
             })),
             "patent" => InputReference::Patent(Box::new(Patent {
                 id,
@@ -317,11 +412,12 @@ impl From<csl_legacy::csl_json::Reference> for InputReference {
                 issued,
                 jurisdiction: None,
                 authority: legacy.authority,
+                status: extra_str(&["status"]),
                 url,
                 accessed,
                 language,
                 note: note.clone(),
-                keywords: None,
+                keywords,
             })),
             "dataset" => InputReference::Dataset(Box::new(Dataset {
                 id,
@@ -334,16 +430,38 @@ impl From<csl_legacy::csl_json::Reference> for InputReference {
                         location: legacy.publisher_place,
                     })
                 }),
-                version: None,
+                version,
                 format: None,
                 size: None,
-                repository: None,
+                repository: archive.or_else(|| extra_str(&["source"])),
                 doi,
                 url,
                 accessed,
                 language,
                 note: note.clone(),
-                keywords: None,
+                keywords,
+            })),
+            "software" => InputReference::Software(Box::new(Software {
+                id,
+                title,
+                author: legacy.author.map(Contributor::from),
+                issued,
+                publisher: legacy.publisher.map(|n| {
+                    Contributor::SimpleName(SimpleName {
+                        name: n.into(),
+                        location: legacy.publisher_place,
+                    })
+                }),
+                version,
+                repository: extra_str(&["repository"]).or(archive),
+                license: extra_str(&["license"]),
+                platform: extra_str(&["platform"]),
+                doi,
+                url,
+                accessed,
+                language,
+                note: note.clone(),
+                keywords,
             })),
             _ => InputReference::Monograph(Box::new(Monograph {
                 id,
@@ -352,7 +470,10 @@ impl From<csl_legacy::csl_json::Reference> for InputReference {
                 author: legacy.author.map(Contributor::from),
                 editor: legacy.editor.map(Contributor::from),
                 translator: legacy.translator.map(Contributor::from),
+                composer: legacy.composer.map(Contributor::from),
+                illustrator: legacy.illustrator.map(Contributor::from),
                 issued,
+                submitted,
                 publisher: legacy.publisher.map(|n| {
                     Contributor::SimpleName(SimpleName {
                         name: n.into(),
@@ -363,6 +484,7 @@ impl From<csl_legacy::csl_json::Reference> for InputReference {
                 accessed,
                 language,
                 note,
+                abstract_text,
                 isbn,
                 doi,
                 edition,
@@ -370,9 +492,18 @@ impl From<csl_legacy::csl_json::Reference> for InputReference {
                 collection_number: legacy.collection_number.map(|v| v.to_string()),
                 genre: legacy.genre,
                 medium: legacy.medium,
-                keywords: None,
-                original_date: None,
+                keywords,
+                original_date,
                 original_title: None,
+                original_publisher,
+                number_of_volumes: legacy.number_of_volumes.map(|v| v.to_string()),
+                number_of_pages: legacy.number_of_pages.map(|v| v.to_string()),
+                archive,
+                archive_place,
+                archive_location,
+                call_number,
+                scale,
+                dimensions,
             })),
         }
     }
@@ -388,7 +519,11 @@ impl From<csl_legacy::csl_json::DateVariable> for EdtfString {
         {
             let year = first
                 .first()
-                .map(|y| format!("{:04}", y))
+                .map(|y| match *y {
+                    // EDTF pads the digits, not the sign ("-0300").
+                    y if y < 0 => format!("-{:04}", -y),
+                    y => format!("{:04}", y),
+                })
                 .unwrap_or_default();
             let month = first
                 .get(1)
@@ -429,6 +564,181 @@ impl From<Vec<csl_legacy::csl_json::Name>> for Contributor {
     }
 }
 
+impl From<&EdtfString> for csl_legacy::csl_json::DateVariable {
+    fn from(date: &EdtfString) -> Self {
+        use csln_edtf::{Day, Edtf, MonthOrSeason, UnspecifiedYear, YearPrecision};
+
+        // Only fully specified year-month-day dates map to date-parts.
+        let parts = |date: &csln_edtf::Date| -> Option<Vec<i32>> {
+            if date.year.unspecified != UnspecifiedYear::None
+                || date.year.precision != YearPrecision::Year
+            {
+                return None;
+            }
+            let mut parts = vec![i32::try_from(date.year.value).ok()?];
+            match date.month_or_season {
+                Some(MonthOrSeason::Month(month)) => parts.push(month as i32),
+                None => return Some(parts),
+                Some(_) => return None,
+            }
+            match date.day {
+                Some(Day::Day(day)) => parts.push(day as i32),
+                None => {}
+                Some(Day::Unspecified) => return None,
+            }
+            Some(parts)
+        };
+        let date_parts = match date.parse() {
+            RefDate::Edtf(Edtf::Date(d)) => parts(&d).map(|p| vec![p]),
+            RefDate::Edtf(Edtf::Interval(i)) => {
+                parts(&i.start).zip(parts(&i.end)).map(|(s, e)| vec![s, e])
+            }
+            _ => None,
+        };
+        match date_parts {
+            Some(date_parts) => Self {
+                date_parts: Some(date_parts),
+                circa: date.is_approximate().then_some(true),
+                ..Default::default()
+            },
+            None => Self {
+                literal: Some(date.0.clone()),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// Map an internal reference type to the name CSL-JSON uses for it.
+///
+/// Most internal names already match; the rest are spelled with
+/// underscores in CSL, or have no CSL type of their own.
+fn csl_json_type(ref_type: &str) -> &str {
+    match ref_type {
+        "legal-case" => "legal_case",
+        "motion-picture" => "motion_picture",
+        "personal-communication" => "personal_communication",
+        "statute" => "legislation",
+        "brief" => "document",
+        other => other,
+    }
+}
+
+impl From<&InputReference> for csl_legacy::csl_json::Reference {
+    fn from(reference: &InputReference) -> Self {
+        use csl_legacy::csl_json::{DateVariable, Name, StringOrNumber};
+
+        let names = |contributor: Option<Contributor>| {
+            contributor
+                .map(|c| {
+                    c.to_names_vec()
+                        .into_iter()
+                        .map(|n| Name {
+                            family: n.family,
+                            given: n.given,
+                            literal: n.literal,
+                            suffix: n.suffix,
+                            dropping_particle: n.dropping_particle,
+                            non_dropping_particle: n.non_dropping_particle,
+                        })
+                        .collect::<Vec<_>>()
+                })
+                .filter(|names| !names.is_empty())
+        };
+        let date = |date: Option<EdtfString>| {
+            date.filter(|d| !d.0.is_empty())
+                .map(|d| DateVariable::from(&d))
+        };
+        let number = |value: NumOrStr| match value {
+            NumOrStr::Number(n) => StringOrNumber::Number(n),
+            NumOrStr::Str(s) => StringOrNumber::String(s),
+        };
+        let number_str = |value: String| match value.parse() {
+            Ok(n) => StringOrNumber::Number(n),
+            Err(_) => StringOrNumber::String(value),
+        };
+
+        // Variables without a dedicated CSL-JSON field round-trip through
+        // the keys the importer reads them from.
+        let mut extra = std::collections::HashMap::new();
+        for (key, value) in [
+            ("version", reference.version()),
+            ("archive", reference.archive()),
+            ("archive-place", reference.archive_place()),
+            ("archive_location", reference.archive_location()),
+            ("call-number", reference.call_number()),
+            ("scale", reference.scale()),
+            ("dimensions", reference.dimensions()),
+            ("PMID", reference.pmid()),
+            ("PMCID", reference.pmcid()),
+            ("keyword", reference.keywords().map(|k| k.join(", "))),
+            ("original-publisher", reference.original_publisher()),
+            (
+                "original-publisher-place",
+                reference.original_publisher_place(),
+            ),
+        ] {
+            if let Some(value) = value {
+                extra.insert(key.to_string(), serde_json::Value::String(value));
+            }
+        }
+        for (key, value) in [
+            ("original-date", date(reference.original_date())),
+            ("submitted", date(reference.submitted())),
+        ] {
+            if let Some(value) = value.and_then(|v| serde_json::to_value(v).ok()) {
+                extra.insert(key.to_string(), value);
+            }
+        }
+
+        Self {
+            id: reference.id().unwrap_or_default(),
+            ref_type: csl_json_type(&reference.ref_type()).to_string(),
+            author: names(reference.author()),
+            editor: names(reference.editor()),
+            translator: names(reference.translator()),
+            director: names(reference.director()),
+            composer: names(reference.composer()),
+            illustrator: names(reference.illustrator()),
+            reviewed_author: names(reference.reviewed_author()),
+            title: reference
+                .title()
+                .map(|t| t.to_string())
+                .filter(|t| !t.is_empty()),
+            reviewed_title: reference.reviewed_title().map(|t| t.to_string()),
+            container_title: reference
+                .container_title()
+                .map(|t| t.to_string())
+                .filter(|t| !t.is_empty()),
+            collection_number: reference.collection_number().map(number_str),
+            issued: date(reference.issued()),
+            accessed: date(reference.accessed()),
+            volume: reference.volume().map(number),
+            issue: reference.issue().map(number),
+            page: reference.pages().map(|p| p.to_string()),
+            edition: reference.edition().map(number_str),
+            doi: reference.doi(),
+            url: reference.url().map(|u| u.to_string()),
+            isbn: reference.isbn(),
+            issn: reference.issn(),
+            publisher: reference.publisher_str(),
+            publisher_place: reference.publisher_place(),
+            authority: reference.authority(),
+            section: reference.section(),
+            medium: reference.medium(),
+            number: reference.number(),
+            genre: reference.genre(),
+            language: reference.language(),
+            abstract_text: reference.abstract_text(),
+            note: reference.note(),
+            number_of_pages: reference.number_of_pages().map(number_str),
+            number_of_volumes: reference.number_of_volumes().map(number_str),
+            extra,
+            ..Default::default()
+        }
+    }
+}
+
 impl InputReference {
     pub fn from_biblatex(entry: &Entry) -> Self {
         let id = Some(entry.key.clone());
@@ -443,16 +753,35 @@ impl InputReference {
             })
         };
 
+        let keywords = field_str("keywords").and_then(|k| split_keywords(&k));
+        let abstract_text = field_str("abstract");
         let title = field_str("title")
             .map(Title::Single)
             .unwrap_or(Title::Single(String::new()));
+        // BibTeX entries carry `year`/`month` rather than a biblatex `date`.
         let issued = field_str("date")
+            .or_else(|| {
+                let year = field_str("year")?;
+                Some(
+                    match field_str("month").and_then(|m| m.parse::<u8>().ok()) {
+                        Some(month) => format!("{}-{:02}", year, month),
+                        None => year,
+                    },
+                )
+            })
             .map(EdtfString)
             .unwrap_or(EdtfString(String::new()));
+        let location = || field_str("location").or_else(|| field_str("address"));
         let publisher = field_str("publisher").map(|p| {
             Contributor::SimpleName(SimpleName {
                 name: p.into(),
-                location: field_str("location"),
+                location: location(),
+            })
+        });
+        let original_publisher = field_str("origpublisher").map(|p| {
+            Contributor::SimpleName(SimpleName {
+                name: p.into(),
+                location: field_str("origlocation"),
             })
         });
 
@@ -476,12 +805,16 @@ impl InputReference {
                     author,
                     editor,
                     translator: None,
+                    composer: None,
+                    illustrator: None,
                     issued,
+                    submitted: None,
                     publisher,
                     url: field_str("url").and_then(|u| Url::parse(&u).ok()),
                     accessed: None,
                     language,
                     note: field_str("note"),
+                    abstract_text: abstract_text.clone(),
                     isbn: field_str("isbn"),
                     doi: field_str("doi"),
                     edition: field_str("edition"),
@@ -503,9 +836,18 @@ impl InputReference {
                     },
                     genre: field_str("type"),
                     medium: None,
-                    keywords: None,
+                    keywords,
                     original_date: None,
                     original_title: None,
+                    original_publisher,
+                    number_of_volumes: field_str("volumes"),
+                    number_of_pages: field_str("pagetotal"),
+                    archive: None,
+                    archive_place: None,
+                    archive_location: None,
+                    call_number: None,
+                    scale: None,
+                    dimensions: None,
                 }))
             }
             "inbook" | "incollection" | "inproceedings" => {
@@ -540,10 +882,11 @@ impl InputReference {
                     accessed: field_str("urldate").map(EdtfString),
                     language,
                     note: field_str("note"),
+                    abstract_text,
                     doi: field_str("doi"),
                     genre: field_str("type"),
                     medium: None,
-                    keywords: None,
+                    keywords,
                 }))
             }
             "article" => {
@@ -557,7 +900,9 @@ impl InputReference {
                     title: Some(title),
                     author,
                     translator: None,
+                    director: None,
                     issued,
+                    submitted: None,
                     parent: Parent::Embedded(Serial {
                         r#type: SerialType::AcademicJournal,
                         title: parent_title,
@@ -569,13 +914,19 @@ impl InputReference {
                     accessed: field_str("urldate").map(EdtfString),
                     language,
                     note: field_str("note"),
+                    abstract_text: abstract_text.clone(),
                     doi: field_str("doi"),
                     pages: field_str("pages"),
                     volume: field_str("volume").map(NumOrStr::Str),
                     issue: field_str("number").map(NumOrStr::Str),
                     genre: field_str("type"),
                     medium: None,
-                    keywords: None,
+                    pmid: None,
+                    pmcid: None,
+                    dimensions: None,
+                    keywords,
+                    reviewed_title: None,
+                    reviewed_author: None,
                 }))
             }
             _ => InputReference::Monograph(Box::new(Monograph {
@@ -585,12 +936,16 @@ impl InputReference {
                 author,
                 editor,
                 translator: None,
+                composer: None,
+                illustrator: None,
                 issued,
+                submitted: None,
                 publisher,
                 url: field_str("url").and_then(|u| Url::parse(&u).ok()),
                 accessed: field_str("urldate").map(EdtfString),
                 language,
                 note: field_str("note"),
+                abstract_text: abstract_text.clone(),
                 isbn: field_str("isbn"),
                 doi: field_str("doi"),
                 edition: field_str("edition"),
@@ -612,19 +967,194 @@ impl InputReference {
                 },
                 genre: field_str("type"),
                 medium: None,
-                keywords: None,
+                keywords,
                 original_date: None,
                 original_title: None,
+                original_publisher,
+                number_of_volumes: field_str("volumes"),
+                number_of_pages: field_str("pagetotal"),
+                archive: None,
+                archive_place: None,
+                archive_location: None,
+                call_number: None,
+                scale: None,
+                dimensions: None,
             })),
         }
     }
 }
 
+/// Escape characters that are special in BibTeX field values.
+fn escape_bibtex(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' | '%' | '$' | '#' | '_' | '{' | '}' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            '~' => escaped.push_str("\\textasciitilde{}"),
+            '^' => escaped.push_str("\\textasciicircum{}"),
+            '\\' => escaped.push_str("\\textbackslash{}"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Format contributors as a BibTeX name list ("von Last, Jr, First and ...").
+///
+/// Literal names are braced so that they read back as institutional names.
+fn bibtex_names(contributor: Contributor) -> Option<String> {
+    let names: Vec<String> = contributor
+        .to_names_vec()
+        .into_iter()
+        .filter_map(|n| {
+            if let Some(literal) = n.literal {
+                return Some(format!("{{{}}}", escape_bibtex(&literal)));
+            }
+            let family = [n.dropping_particle, n.non_dropping_particle, n.family]
+                .into_iter()
+                .flatten()
+                .collect::<Vec<_>>()
+                .join(" ");
+            if family.is_empty() {
+                return None;
+            }
+            let mut parts = vec![escape_bibtex(&family)];
+            match (n.suffix, n.given) {
+                (Some(suffix), given) => {
+                    parts.push(escape_bibtex(&suffix));
+                    parts.push(escape_bibtex(&given.unwrap_or_default()));
+                }
+                (None, Some(given)) => parts.push(escape_bibtex(&given)),
+                (None, None) => {}
+            }
+            Some(parts.join(", "))
+        })
+        .collect();
+    (!names.is_empty()).then(|| names.join(" and "))
+}
+
+impl InputReference {
+    /// Serialize the reference as a BibTeX entry.
+    ///
+    /// This is the inverse of [`InputReference::from_biblatex`]: the CSL item
+    /// type selects the entry type, and dates are written as `year` and
+    /// `month` fields.
+    pub fn to_bibtex(&self) -> String {
+        use csln_edtf::{Edtf, MonthOrSeason};
+
+        let ref_type = self.ref_type();
+        let entry_type = match ref_type.as_str() {
+            "article" | "article-journal" | "article-magazine" | "article-newspaper" => "article",
+            "book" => "book",
+            "chapter" => "incollection",
+            "paper-conference" => "inproceedings",
+            "report" => "techreport",
+            "thesis" => "phdthesis",
+            _ => "misc",
+        };
+        let container_field = match entry_type {
+            "article" => Some("journal"),
+            "incollection" | "inproceedings" => Some("booktitle"),
+            _ => None,
+        };
+        let publisher_field = match entry_type {
+            "techreport" => "institution",
+            "phdthesis" => "school",
+            _ => "publisher",
+        };
+        let number = match entry_type {
+            "article" => self.issue().map(|n| n.to_string()),
+            "techreport" => self.number(),
+            _ => self.collection_number(),
+        };
+        let issued = self.issued().filter(|d| !d.0.is_empty());
+        let month = issued.as_ref().and_then(|d| match d.parse() {
+            RefDate::Edtf(Edtf::Date(date)) => match date.month_or_season {
+                Some(MonthOrSeason::Month(month)) => Some(month.to_string()),
+                _ => None,
+            },
+            _ => None,
+        });
+
+        let mut fields: Vec<(&str, String)> = Vec::new();
+        let mut push = |key: &'static str, value: Option<String>| {
+            if let Some(value) = value.filter(|v| !v.is_empty()) {
+                fields.push((key, value));
+            }
+        };
+        push("author", self.author().and_then(bibtex_names));
+        push("editor", self.editor().and_then(bibtex_names));
+        push("title", self.title().map(|t| escape_bibtex(&t.to_string())));
+        if let Some(key) = container_field {
+            push(
+                key,
+                self.container_title()
+                    .map(|t| escape_bibtex(&t.to_string())),
+            );
+        }
+        push("year", issued.as_ref().map(|d| d.year()));
+        push("month", month);
+        push(
+            "volume",
+            self.volume().map(|v| escape_bibtex(&v.to_string())),
+        );
+        push("number", number.map(|n| escape_bibtex(&n)));
+        push("pages", self.pages().map(|p| escape_bibtex(&p.to_string())));
+        push("edition", self.edition().map(|e| escape_bibtex(&e)));
+        push(
+            publisher_field,
+            self.publisher_str().map(|p| escape_bibtex(&p)),
+        );
+        push("address", self.publisher_place().map(|p| escape_bibtex(&p)));
+        push("isbn", self.isbn());
+        push("issn", self.issn());
+        push("doi", self.doi());
+        push("url", self.url().map(|u| u.to_string()));
+        push(
+            "urldate",
+            self.accessed().map(|d| d.0).filter(|d| !d.is_empty()),
+        );
+        push("language", self.language());
+        push(
+            "keywords",
+            self.keywords().map(|k| escape_bibtex(&k.join(", "))),
+        );
+        push("abstract", self.abstract_text().map(|a| escape_bibtex(&a)));
+        push("note", self.note().map(|n| escape_bibtex(&n)));
+
+        let mut entry = format!(
+            "@{}{{{}",
+            entry_type,
+            self.id().unwrap_or_else(|| "ref".to_string())
+        );
+        for (key, value) in fields {
+            entry.push_str(&format!(",\n  {} = {{{}}}", key, value));
+        }
+        entry.push_str("\n}\n");
+        entry
+    }
+}
+
 impl Contributor {
     fn from_biblatex_persons(persons: &[Person]) -> Self {
         let contributors: Vec<Contributor> = persons
             .iter()
             .map(|p| {
+                // A braced multi-word name with no other parts, such as
+                // `{World Health Organization}`, is an institutional name.
+                if p.given_name.is_empty()
+                    && p.prefix.is_empty()
+                    && p.suffix.is_empty()
+                    && p.name.contains(' ')
+                {
+                    return Contributor::SimpleName(SimpleName {
+                        name: p.name.clone().into(),
+                        location: None,
+                    });
+                }
                 Contributor::StructuredName(StructuredName {
                     given: p.given_name.clone().into(),
                     family: p.name.clone().into(),
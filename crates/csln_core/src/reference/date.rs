@@ -28,13 +28,28 @@ impl EdtfString {
             RefDate::Edtf(edtf) => match edtf {
                 Edtf::Date(date) => date.year.value.to_string(),
                 Edtf::Interval(interval) => interval.start.year.value.to_string(),
-                Edtf::IntervalFrom(date) => date.year.value.to_string(),
-                Edtf::IntervalTo(date) => date.year.value.to_string(),
+                Edtf::IntervalFrom { start: date, .. } => date.year.value.to_string(),
+                Edtf::IntervalTo { end: date, .. } => date.year.value.to_string(),
+                Edtf::OneOfSet(members) | Edtf::AllOfSet(members) => members
+                    .first()
+                    .map(|member| member.date().year.value.to_string())
+                    .unwrap_or_default(),
             },
             RefDate::Literal(_) => String::new(),
         }
     }
 
+    /// A key for chronological sorting, or `None` for a literal date.
+    ///
+    /// See [`Edtf::sort_key`] for how intervals, sets, and partial dates
+    /// are ordered.
+    pub fn sort_key(&self) -> Option<(i64, u8, u8)> {
+        match self.parse() {
+            RefDate::Edtf(edtf) => Some(edtf.sort_key()),
+            RefDate::Literal(_) => None,
+        }
+    }
+
     fn month_to_string(month: u32, months: &[String]) -> String {
         if month > 0 {
             let index = month - 1;
@@ -56,8 +71,11 @@ impl EdtfString {
                 let m_opt = match edtf {
                     Edtf::Date(date) => date.month_or_season,
                     Edtf::Interval(interval) => interval.start.month_or_season,
-                    Edtf::IntervalFrom(date) => date.month_or_season,
-                    Edtf::IntervalTo(date) => date.month_or_season,
+                    Edtf::IntervalFrom { start: date, .. } => date.month_or_season,
+                    Edtf::IntervalTo { end: date, .. } => date.month_or_season,
+                    Edtf::OneOfSet(members) | Edtf::AllOfSet(members) => members
+                        .first()
+                        .and_then(|member| member.date().month_or_season),
                 };
                 match m_opt {
                     Some(MonthOrSeason::Month(m)) => Some(m),
@@ -91,8 +109,11 @@ impl EdtfString {
                 let d_opt = match edtf {
                     Edtf::Date(date) => date.day,
                     Edtf::Interval(interval) => interval.start.day,
-                    Edtf::IntervalFrom(date) => date.day,
-                    Edtf::IntervalTo(date) => date.day,
+                    Edtf::IntervalFrom { start: date, .. } => date.day,
+                    Edtf::IntervalTo { end: date, .. } => date.day,
+                    Edtf::OneOfSet(members) | Edtf::AllOfSet(members) => {
+                        members.first().and_then(|member| member.date().day)
+                    }
                 };
                 match d_opt {
                     Some(Day::Day(d)) => Some(d),
@@ -128,7 +149,7 @@ impl EdtfString {
     pub fn is_range(&self) -> bool {
         matches!(
             self.parse(),
-            RefDate::Edtf(Edtf::Interval(_) | Edtf::IntervalFrom(_) | Edtf::IntervalTo(_))
+            RefDate::Edtf(Edtf::Interval(_) | Edtf::IntervalFrom { .. } | Edtf::IntervalTo { .. })
         )
     }
 
@@ -160,8 +181,8 @@ impl EdtfString {
                         _ => Some(year),
                     }
                 }
-                Edtf::IntervalFrom(_date) => None, // Open-ended
-                Edtf::IntervalTo(date) => {
+                Edtf::IntervalFrom { .. } => None, // Open-ended
+                Edtf::IntervalTo { end: date, .. } => {
                     let year = date.year.value.to_string();
                     Some(year)
                 }
@@ -171,9 +192,20 @@ impl EdtfString {
         }
     }
 
-    /// Check if the range is open-ended (ends with "..").
+    /// The end of a range as a date of its own, so that it can be formatted
+    /// like the start (e.g., "2020-06-01" for "2020-05-15/2020-06-01").
+    pub fn range_end_date(&self) -> Option<EdtfString> {
+        match self.parse() {
+            RefDate::Edtf(Edtf::Interval(interval)) => Some(EdtfString(interval.end.to_string())),
+            RefDate::Edtf(Edtf::IntervalTo { end, .. }) => Some(EdtfString(end.to_string())),
+            _ => None,
+        }
+    }
+
+    /// Check if the range has no end date, whether open ("2004/..") or
+    /// unknown ("2004/").
     pub fn is_open_range(&self) -> bool {
-        matches!(self.parse(), RefDate::Edtf(Edtf::IntervalFrom(_)))
+        matches!(self.parse(), RefDate::Edtf(Edtf::IntervalFrom { .. }))
     }
 }
 
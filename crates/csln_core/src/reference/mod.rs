@@ -9,6 +9,8 @@ SPDX-FileCopyrightText: © 2023-2026 Bruce D'Arcus
 pub mod contributor;
 pub mod conversion;
 pub mod date;
+pub mod normalize;
+pub mod similarity;
 pub mod types;
 
 #[cfg(test)]
@@ -22,8 +24,11 @@ use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use url::Url;
 
-pub use self::contributor::{Contributor, ContributorList, FlatName, SimpleName, StructuredName};
+pub use self::contributor::{
+    Contributor, ContributorList, FlatName, SimpleName, StructuredName, join_particle,
+};
 pub use self::date::EdtfString;
+pub use self::normalize::NormalizeOptions;
 pub use self::types::*;
 
 /// The Reference model.
@@ -128,6 +133,30 @@ impl InputReference {
         }
     }
 
+    /// Return the director.
+    pub fn director(&self) -> Option<Contributor> {
+        match self {
+            InputReference::SerialComponent(r) => r.director.clone(),
+            _ => None,
+        }
+    }
+
+    /// Return the composer.
+    pub fn composer(&self) -> Option<Contributor> {
+        match self {
+            InputReference::Monograph(r) => r.composer.clone(),
+            _ => None,
+        }
+    }
+
+    /// Return the illustrator.
+    pub fn illustrator(&self) -> Option<Contributor> {
+        match self {
+            InputReference::Monograph(r) => r.illustrator.clone(),
+            _ => None,
+        }
+    }
+
     /// Return the publisher.
     pub fn publisher(&self) -> Option<Contributor> {
         match self {
@@ -210,6 +239,38 @@ impl InputReference {
         }
     }
 
+    /// Return the PubMed identifier.
+    pub fn pmid(&self) -> Option<String> {
+        match self {
+            InputReference::SerialComponent(r) => r.pmid.clone(),
+            _ => None,
+        }
+    }
+
+    /// Return the title of the work under review.
+    pub fn reviewed_title(&self) -> Option<Title> {
+        match self {
+            InputReference::SerialComponent(r) => r.reviewed_title.clone(),
+            _ => None,
+        }
+    }
+
+    /// Return the author of the work under review.
+    pub fn reviewed_author(&self) -> Option<Contributor> {
+        match self {
+            InputReference::SerialComponent(r) => r.reviewed_author.clone(),
+            _ => None,
+        }
+    }
+
+    /// Return the PubMed Central identifier.
+    pub fn pmcid(&self) -> Option<String> {
+        match self {
+            InputReference::SerialComponent(r) => r.pmcid.clone(),
+            _ => None,
+        }
+    }
+
     /// Return the note.
     pub fn note(&self) -> Option<String> {
         match self {
@@ -303,6 +364,7 @@ impl InputReference {
             InputReference::Monograph(r) => r.medium.clone(),
             InputReference::CollectionComponent(r) => r.medium.clone(),
             InputReference::SerialComponent(r) => r.medium.clone(),
+            InputReference::Dataset(r) => r.format.clone(),
             _ => None,
         }
     }
@@ -318,7 +380,12 @@ impl InputReference {
 
     /// Return the abstract.
     pub fn abstract_text(&self) -> Option<String> {
-        None
+        match self {
+            InputReference::Monograph(r) => r.abstract_text.clone(),
+            InputReference::CollectionComponent(r) => r.abstract_text.clone(),
+            InputReference::SerialComponent(r) => r.abstract_text.clone(),
+            _ => None,
+        }
     }
 
     pub fn container_title(&self) -> Option<Title> {
@@ -395,6 +462,17 @@ impl InputReference {
             InputReference::Regulation(r) => r.authority.clone(),
             InputReference::Brief(r) => r.authority.clone(),
             InputReference::Standard(r) => r.authority.clone(),
+            InputReference::Treaty(r) => r.authority.clone(),
+            InputReference::Patent(r) => r.authority.clone(),
+            _ => None,
+        }
+    }
+
+    /// Return the publication or legal status (e.g., "draft", "pending").
+    pub fn status(&self) -> Option<String> {
+        match self {
+            InputReference::Standard(r) => r.status.clone(),
+            InputReference::Patent(r) => r.status.clone(),
             _ => None,
         }
     }
@@ -447,6 +525,86 @@ impl InputReference {
         }
     }
 
+    /// Return the total number of volumes.
+    pub fn number_of_volumes(&self) -> Option<String> {
+        match self {
+            InputReference::Monograph(r) => r.number_of_volumes.clone(),
+            _ => None,
+        }
+    }
+
+    /// Return the total number of pages.
+    pub fn number_of_pages(&self) -> Option<String> {
+        match self {
+            InputReference::Monograph(r) => r.number_of_pages.clone(),
+            _ => None,
+        }
+    }
+
+    /// Return the archive holding the item.
+    pub fn archive(&self) -> Option<String> {
+        match self {
+            InputReference::Monograph(r) => r.archive.clone(),
+            InputReference::Dataset(r) => r.repository.clone(),
+            _ => None,
+        }
+    }
+
+    /// Return the repository or database provider (e.g., "Zenodo", "GitHub").
+    ///
+    /// Datasets prefer their repository over the publisher.
+    pub fn source(&self) -> Option<String> {
+        match self {
+            InputReference::Dataset(r) => r
+                .repository
+                .clone()
+                .or_else(|| r.publisher.as_ref().and_then(|c| c.name())),
+            InputReference::Software(r) => r.publisher.as_ref().and_then(|c| c.name()),
+            _ => None,
+        }
+    }
+
+    /// Return the location of the archive.
+    pub fn archive_place(&self) -> Option<String> {
+        match self {
+            InputReference::Monograph(r) => r.archive_place.clone(),
+            _ => None,
+        }
+    }
+
+    /// Return the location of the item within the archive.
+    pub fn archive_location(&self) -> Option<String> {
+        match self {
+            InputReference::Monograph(r) => r.archive_location.clone(),
+            _ => None,
+        }
+    }
+
+    /// Return the call number of the item.
+    pub fn call_number(&self) -> Option<String> {
+        match self {
+            InputReference::Monograph(r) => r.call_number.clone(),
+            _ => None,
+        }
+    }
+
+    /// Return the scale of a map.
+    pub fn scale(&self) -> Option<String> {
+        match self {
+            InputReference::Monograph(r) => r.scale.clone(),
+            _ => None,
+        }
+    }
+
+    /// Return the physical dimensions or running time of the item.
+    pub fn dimensions(&self) -> Option<String> {
+        match self {
+            InputReference::Monograph(r) => r.dimensions.clone(),
+            InputReference::SerialComponent(r) => r.dimensions.clone(),
+            _ => None,
+        }
+    }
+
     /// Return the accessed date.
     pub fn accessed(&self) -> Option<EdtfString> {
         match self {
@@ -476,6 +634,33 @@ impl InputReference {
         }
     }
 
+    /// Return the original publisher as a string.
+    pub fn original_publisher(&self) -> Option<String> {
+        match self {
+            InputReference::Monograph(r) => r.original_publisher.as_ref().and_then(|c| c.name()),
+            _ => None,
+        }
+    }
+
+    /// Return the place of the original publisher.
+    pub fn original_publisher_place(&self) -> Option<String> {
+        match self {
+            InputReference::Monograph(r) => {
+                r.original_publisher.as_ref().and_then(|c| c.location())
+            }
+            _ => None,
+        }
+    }
+
+    /// Return the submission date.
+    pub fn submitted(&self) -> Option<EdtfString> {
+        match self {
+            InputReference::Monograph(r) => r.submitted.clone(),
+            InputReference::SerialComponent(r) => r.submitted.clone(),
+            _ => None,
+        }
+    }
+
     /// Return the ISBN.
     pub fn isbn(&self) -> Option<String> {
         match self {
@@ -577,6 +762,11 @@ impl InputReference {
                 MonographType::Webpage => "webpage".to_string(),
                 MonographType::Post => "post".to_string(),
                 MonographType::PersonalCommunication => "personal-communication".to_string(),
+                MonographType::Manuscript => "manuscript".to_string(),
+                MonographType::Map => "map".to_string(),
+                MonographType::Figure => "figure".to_string(),
+                MonographType::Graphic => "graphic".to_string(),
+                MonographType::Recording => "song".to_string(),
                 MonographType::Document => {
                     if r.medium
                         .as_deref()
@@ -592,6 +782,12 @@ impl InputReference {
                 MonographComponentType::Chapter => "chapter".to_string(),
                 MonographComponentType::Document => "paper-conference".to_string(),
             },
+            InputReference::SerialComponent(r) if r.r#type == SerialComponentType::Review => {
+                "review".to_string()
+            }
+            InputReference::SerialComponent(r) if r.r#type == SerialComponentType::ReviewBook => {
+                "review-book".to_string()
+            }
             InputReference::SerialComponent(r) => match r.parent {
                 Parent::Embedded(ref s) => match s.r#type {
                     SerialType::AcademicJournal => {
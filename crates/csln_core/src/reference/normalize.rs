@@ -0,0 +1,162 @@
+/*
+SPDX-License-Identifier: MPL-2.0
+SPDX-FileCopyrightText: © 2023-2026 Bruce D'Arcus
+*/
+
+//! Cleanup of imported reference data.
+//!
+//! Normalization walks the serialized form of a reference, so it applies to
+//! every string field without per-type code. Name particles, IDs, and URLs
+//! are never touched; notes are trimmed but keep their line breaks.
+
+use super::InputReference;
+use serde_json::Value;
+
+/// Options for [`InputReference::normalize_with`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct NormalizeOptions {
+    /// Replace curly quotation marks in titles with straight ones.
+    pub quotes: bool,
+}
+
+/// Keys whose values are left exactly as imported.
+const PRESERVED_KEYS: &[&str] = &["id", "url", "dropping-particle", "non-dropping-particle"];
+
+/// Keys whose values are trimmed but keep internal whitespace.
+const TRIM_ONLY_KEYS: &[&str] = &["note"];
+
+/// Keys whose subtrees hold titles.
+const TITLE_KEYS: &[&str] = &["title", "original-title"];
+
+impl InputReference {
+    /// Trim fields, collapse internal whitespace, and lowercase DOIs.
+    pub fn normalize(&mut self) {
+        self.normalize_with(&NormalizeOptions::default());
+    }
+
+    /// Normalize with explicit options.
+    ///
+    /// The reference is left unchanged if the normalized data no longer
+    /// describes a valid reference.
+    pub fn normalize_with(&mut self, options: &NormalizeOptions) {
+        let Ok(mut value) = serde_json::to_value(&*self) else {
+            return;
+        };
+        normalize_value(&mut value, None, false, options);
+        if let Ok(normalized) = serde_json::from_value(value) {
+            *self = normalized;
+        }
+    }
+}
+
+fn normalize_value(
+    value: &mut Value,
+    key: Option<&str>,
+    in_title: bool,
+    options: &NormalizeOptions,
+) {
+    match value {
+        Value::String(s) => *s = normalize_string(s, key, in_title, options),
+        Value::Array(items) => {
+            for item in items {
+                normalize_value(item, key, in_title, options);
+            }
+        }
+        Value::Object(map) => {
+            for (k, v) in map.iter_mut() {
+                if PRESERVED_KEYS.contains(&k.as_str()) {
+                    continue;
+                }
+                let in_title = in_title || TITLE_KEYS.contains(&k.as_str());
+                normalize_value(v, Some(k), in_title, options);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn normalize_string(
+    s: &str,
+    key: Option<&str>,
+    in_title: bool,
+    options: &NormalizeOptions,
+) -> String {
+    let mut out = if key.is_some_and(|k| TRIM_ONLY_KEYS.contains(&k)) {
+        s.trim().to_string()
+    } else {
+        s.split_whitespace().collect::<Vec<_>>().join(" ")
+    };
+    if key == Some("doi") {
+        out = out.to_lowercase();
+    }
+    if in_title && options.quotes {
+        out = out
+            .replace(['\u{2018}', '\u{2019}'], "'")
+            .replace(['\u{201C}', '\u{201D}'], "\"");
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reference::{Contributor, MultilingualString, StructuredName, Title};
+
+    #[test]
+    fn test_normalize_messy_reference() {
+        let mut reference: InputReference = serde_yaml::from_str(
+            r#"
+id: " vaneyck "
+type: book
+title: "  The   “Arnolfini”   Portrait "
+author:
+  - family: " Eyck "
+    given: "Jan"
+    non-dropping-particle: "van "
+issued: " 1434 "
+doi: " 10.1000/ABC.Def "
+note: "  First line.\nSecond line. "
+"#,
+        )
+        .unwrap();
+
+        reference.normalize_with(&NormalizeOptions { quotes: true });
+
+        assert_eq!(reference.id().as_deref(), Some(" vaneyck "));
+        assert_eq!(
+            reference.title(),
+            Some(Title::Single("The \"Arnolfini\" Portrait".to_string()))
+        );
+        assert_eq!(reference.doi().as_deref(), Some("10.1000/abc.def"));
+        assert_eq!(reference.issued().map(|d| d.0), Some("1434".to_string()));
+        assert_eq!(
+            reference.note().as_deref(),
+            Some("First line.\nSecond line.")
+        );
+        let Some(Contributor::ContributorList(list)) = reference.author() else {
+            panic!("expected an author list");
+        };
+        assert_eq!(
+            list.0[0],
+            Contributor::StructuredName(StructuredName {
+                given: MultilingualString::Simple("Jan".to_string()),
+                family: MultilingualString::Simple("Eyck".to_string()),
+                non_dropping_particle: Some("van ".to_string()),
+                ..Default::default()
+            })
+        );
+    }
+
+    #[test]
+    fn test_normalize_keeps_quotes_by_default() {
+        let mut reference: InputReference = serde_yaml::from_str(
+            "id: q\ntype: book\ntitle: \"\u{201C}Quoted\u{201D}\"\nissued: \"2000\"\n",
+        )
+        .unwrap();
+        reference.normalize();
+        assert_eq!(
+            reference.title(),
+            Some(Title::Single("\u{201C}Quoted\u{201D}".to_string()))
+        );
+    }
+}
@@ -0,0 +1,131 @@
+/*
+SPDX-License-Identifier: MPL-2.0
+SPDX-FileCopyrightText: © 2023-2026 Bruce D'Arcus
+*/
+
+//! Fuzzy matching of references, for finding likely duplicates.
+//!
+//! Titles and authors are compared as word and name sets; issued dates are
+//! compared as EDTF, so `2004` and `2004-06` count as the same date at
+//! different precisions.
+
+use super::{EdtfString, InputReference, RefDate};
+use csln_edtf::Edtf;
+use std::collections::HashSet;
+
+/// Relative weights of the compared fields.
+const TITLE_WEIGHT: f32 = 0.5;
+const AUTHOR_WEIGHT: f32 = 0.3;
+const DATE_WEIGHT: f32 = 0.2;
+
+impl InputReference {
+    /// Score how likely two references describe the same work, from 0.0
+    /// (unrelated) to 1.0 (identical titles, authors, and dates).
+    ///
+    /// Fields missing from either reference are left out of the score
+    /// rather than counted as mismatches. References with no comparable
+    /// fields score 0.0.
+    pub fn similarity(&self, other: &InputReference) -> f32 {
+        let title = |r: &InputReference| r.title().map(|t| words(&t.to_string()));
+        let authors = |r: &InputReference| {
+            r.author().map(|a| {
+                a.to_names_vec()
+                    .iter()
+                    .map(|n| n.family_or_literal().to_lowercase())
+                    .collect::<HashSet<_>>()
+            })
+        };
+
+        let scores = [
+            (
+                TITLE_WEIGHT,
+                title(self).zip(title(other)).map(|(a, b)| jaccard(&a, &b)),
+            ),
+            (
+                AUTHOR_WEIGHT,
+                authors(self)
+                    .zip(authors(other))
+                    .map(|(a, b)| jaccard(&a, &b)),
+            ),
+            (
+                DATE_WEIGHT,
+                self.issued()
+                    .zip(other.issued())
+                    .and_then(|(a, b)| date_similarity(&a, &b)),
+            ),
+        ];
+
+        let (total, weight) = scores
+            .iter()
+            .filter_map(|(weight, score)| score.map(|s| (s * weight, *weight)))
+            .fold((0.0, 0.0), |(total, weights), (s, w)| {
+                (total + s, weights + w)
+            });
+        if weight > 0.0 { total / weight } else { 0.0 }
+    }
+}
+
+/// Lowercased alphanumeric words.
+fn words(s: &str) -> HashSet<String> {
+    s.split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
+
+fn jaccard(a: &HashSet<String>, b: &HashSet<String>) -> f32 {
+    let union = a.union(b).count();
+    if union == 0 {
+        return 0.0;
+    }
+    a.intersection(b).count() as f32 / union as f32
+}
+
+/// Compare two dates, treating a less precise date as matching any more
+/// precise date it contains. Returns `None` if either is empty.
+///
+/// Same-year dates that disagree on month or day score 0.5.
+fn date_similarity(a: &EdtfString, b: &EdtfString) -> Option<f32> {
+    let (year_a, year_b) = (a.year(), b.year());
+    if year_a.is_empty() || year_b.is_empty() {
+        return None;
+    }
+    if year_a != year_b {
+        return Some(0.0);
+    }
+    let compatible = match (a.parse(), b.parse()) {
+        (RefDate::Edtf(Edtf::Date(_)), RefDate::Edtf(Edtf::Date(_))) => {
+            a.0.trim()
+                .split('-')
+                .zip(b.0.trim().split('-'))
+                .all(|(x, y)| x == y)
+        }
+        _ => true,
+    };
+    Some(if compatible { 1.0 } else { 0.5 })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn book(title: &str, issued: &str) -> InputReference {
+        serde_yaml::from_str(&format!(
+            "id: x\ntype: book\ntitle: \"{title}\"\nauthor:\n  - family: Kuhn\n    given: Thomas\nissued: \"{issued}\"\n"
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_similarity_with_date_precision() {
+        let precise = book("The Structure of Scientific Revolutions", "1962-06");
+        let imprecise = book("The structure of scientific revolutions", "1962");
+        assert_eq!(precise.similarity(&imprecise), 1.0);
+
+        let other_month = book("The Structure of Scientific Revolutions", "1962-09");
+        assert!(precise.similarity(&other_month) > 0.85);
+
+        let different = book("The Copernican Revolution", "1957");
+        assert!(precise.similarity(&different) < 0.5);
+    }
+}
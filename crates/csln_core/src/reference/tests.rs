@@ -23,3 +23,70 @@ fn test_parse_csl_json() {
         assert_eq!(name.family, MultilingualString::Simple("Kuhn".to_string()));
     }
 }
+
+#[test]
+fn test_biblatex_corporate_author_is_literal() {
+    let bib = biblatex::Bibliography::parse(
+        r#"@report{who2020,
+            author = {{World Health Organization} and Smith, John},
+            title = {Global Report},
+            date = {2020},
+        }"#,
+    )
+    .unwrap();
+    let reference = InputReference::from_biblatex(bib.get("who2020").unwrap());
+
+    let Some(Contributor::ContributorList(list)) = reference.author() else {
+        panic!("expected an author list");
+    };
+    assert_eq!(
+        list.0[0],
+        Contributor::SimpleName(SimpleName {
+            name: MultilingualString::Simple("World Health Organization".to_string()),
+            location: None,
+        })
+    );
+    assert!(matches!(list.0[1], Contributor::StructuredName(_)));
+}
+
+#[test]
+fn test_literal_name_yaml() {
+    let contributor: Contributor =
+        serde_yaml::from_str("literal: World Health Organization").unwrap();
+    assert_eq!(
+        contributor.to_names_vec()[0].literal.as_deref(),
+        Some("World Health Organization")
+    );
+}
+
+#[test]
+fn test_bibtex_article_round_trip() {
+    let bib = biblatex::Bibliography::parse(
+        r#"@article{smith2020,
+            author = {Smith, Jane and {Example Lab}},
+            title = {Costs \& Benefits of 100\% Coverage},
+            journal = {Journal of Examples},
+            year = {2020},
+            month = {5},
+            volume = {12},
+            number = {3},
+            pages = {45-67},
+            doi = {10.1234/abc_def},
+        }"#,
+    )
+    .unwrap();
+    let original = InputReference::from_biblatex(bib.get("smith2020").unwrap());
+    assert_eq!(original.ref_type(), "article-journal");
+
+    let exported = original.to_bibtex();
+    assert!(exported.starts_with("@article{smith2020,"), "{exported}");
+    assert!(
+        exported.contains("title = {Costs \\& Benefits of 100\\% Coverage}"),
+        "{exported}"
+    );
+    assert!(exported.contains("year = {2020}"), "{exported}");
+
+    let reparsed = biblatex::Bibliography::parse(&exported).unwrap();
+    let reimported = InputReference::from_biblatex(reparsed.get("smith2020").unwrap());
+    assert_eq!(reimported, original);
+}
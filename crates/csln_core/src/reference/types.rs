@@ -98,13 +98,25 @@ pub struct Monograph {
     pub author: Option<Contributor>,
     pub editor: Option<Contributor>,
     pub translator: Option<Contributor>,
+    /// The composer of a musical work or recording.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub composer: Option<Contributor>,
+    /// The illustrator of an illustrated book.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub illustrator: Option<Contributor>,
     pub issued: EdtfString,
+    /// The date the work was submitted, e.g., for a preprint or manuscript.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub submitted: Option<EdtfString>,
     pub publisher: Option<Contributor>,
     #[serde(alias = "URL")]
     pub url: Option<Url>,
     pub accessed: Option<EdtfString>,
     pub language: Option<LangID>,
     pub note: Option<String>,
+    /// A summary of the work's content.
+    #[serde(rename = "abstract", skip_serializing_if = "Option::is_none")]
+    pub abstract_text: Option<String>,
     #[serde(alias = "ISBN")]
     pub isbn: Option<String>,
     #[serde(alias = "DOI")]
@@ -117,6 +129,25 @@ pub struct Monograph {
     pub keywords: Option<Vec<String>>,
     pub original_date: Option<EdtfString>,
     pub original_title: Option<Title>,
+    /// The publisher of the original edition of a reprint or translation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub original_publisher: Option<Contributor>,
+    /// Total number of volumes in a multi-volume work.
+    pub number_of_volumes: Option<String>,
+    /// Total number of pages.
+    pub number_of_pages: Option<String>,
+    /// The archive or collection holding the item.
+    pub archive: Option<String>,
+    /// The location of the archive (e.g., "London").
+    pub archive_place: Option<String>,
+    /// The location within the archive (e.g., box and folder).
+    pub archive_location: Option<String>,
+    /// The shelfmark or call number of the item.
+    pub call_number: Option<String>,
+    /// The scale of a map (e.g., "1:50,000").
+    pub scale: Option<String>,
+    /// The physical dimensions of a figure or artwork (e.g., "30 x 40 cm").
+    pub dimensions: Option<String>,
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
@@ -131,6 +162,16 @@ pub enum MonographType {
     Post,
     PersonalCommunication,
     Document,
+    /// An audio recording, such as a song or album.
+    Recording,
+    /// An unpublished or archival manuscript.
+    Manuscript,
+    /// A map.
+    Map,
+    /// A figure, such as a chart or diagram.
+    Figure,
+    /// A graphic work, such as a photograph or illustration.
+    Graphic,
 }
 
 /// A collection of works, such as an anthology or proceedings.
@@ -188,6 +229,9 @@ pub struct CollectionComponent {
     pub accessed: Option<EdtfString>,
     pub language: Option<LangID>,
     pub note: Option<String>,
+    /// A summary of the work's content.
+    #[serde(rename = "abstract", skip_serializing_if = "Option::is_none")]
+    pub abstract_text: Option<String>,
     #[serde(alias = "DOI")]
     pub doi: Option<String>,
     pub genre: Option<String>,
@@ -217,7 +261,13 @@ pub struct SerialComponent {
     pub title: Option<Title>,
     pub author: Option<Contributor>,
     pub translator: Option<Contributor>,
+    /// The director of a film or broadcast.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub director: Option<Contributor>,
     pub issued: EdtfString,
+    /// The date the work was submitted, e.g., for a preprint or manuscript.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub submitted: Option<EdtfString>,
     /// The parent work, such as a magazine or journal.
     pub parent: Parent<Serial>,
     #[serde(alias = "URL")]
@@ -225,6 +275,9 @@ pub struct SerialComponent {
     pub accessed: Option<EdtfString>,
     pub language: Option<LangID>,
     pub note: Option<String>,
+    /// A summary of the work's content.
+    #[serde(rename = "abstract", skip_serializing_if = "Option::is_none")]
+    pub abstract_text: Option<String>,
     #[serde(alias = "DOI")]
     pub doi: Option<String>,
     pub pages: Option<String>,
@@ -232,7 +285,22 @@ pub struct SerialComponent {
     pub issue: Option<NumOrStr>,
     pub genre: Option<String>,
     pub medium: Option<String>,
+    /// PubMed identifier.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pmid: Option<String>,
+    /// PubMed Central identifier (e.g., "PMC1234567").
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pmcid: Option<String>,
+    /// The running time of a broadcast or film (e.g., "58 min." or "PT58M").
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dimensions: Option<String>,
     pub keywords: Option<Vec<String>>,
+    /// The title of the work under review, for reviews.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reviewed_title: Option<Title>,
+    /// The author of the work under review, for reviews.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reviewed_author: Option<Contributor>,
 }
 
 /// Types of serial components.
@@ -243,6 +311,8 @@ pub enum SerialComponentType {
     Article,
     Post,
     Review,
+    /// A review of a book.
+    ReviewBook,
 }
 
 /// A serial publication (journal, magazine, etc.).
@@ -423,6 +493,8 @@ pub struct Treaty {
     pub title: Title,
     /// Parties to the treaty
     pub author: Option<Contributor>,
+    /// Depositary or registering body (e.g., "United Nations")
+    pub authority: Option<String>,
     /// Treaty series volume
     pub volume: Option<String>,
     /// Treaty series abbreviation (e.g., "U.N.T.S.")
@@ -568,6 +640,8 @@ pub struct Patent {
     pub jurisdiction: Option<String>,
     /// Patent office (e.g., "U.S. Patent and Trademark Office")
     pub authority: Option<String>,
+    /// Legal status (e.g., "pending", "granted")
+    pub status: Option<String>,
     #[serde(alias = "URL")]
     pub url: Option<Url>,
     pub accessed: Option<EdtfString>,
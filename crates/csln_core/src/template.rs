@@ -34,6 +34,7 @@ SPDX-FileCopyrightText: © 2023-2026 Bruce D'Arcus
 //! This keeps all conditional logic in the style, making it testable and portable.
 
 use crate::locale::{GeneralTerm, TermForm};
+use indexmap::IndexMap;
 #[cfg(feature = "schema")]
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -89,6 +90,11 @@ pub struct Rendering {
     /// Strip trailing periods from rendered value.
     #[serde(skip_serializing_if = "Option::is_none", rename = "strip-periods")]
     pub strip_periods: Option<bool>,
+    /// Move the component to the start or end of the rendered template.
+    /// Usually set per reference type through `overrides`, e.g., to place
+    /// the date at the end of book entries only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position: Option<ComponentPosition>,
 }
 
 impl Rendering {
@@ -109,6 +115,7 @@ impl Rendering {
             suppress,
             initialize_with,
             strip_periods,
+            position,
         );
     }
 }
@@ -125,6 +132,32 @@ pub enum WrapPunctuation {
     None,
 }
 
+/// Where a component is placed relative to the rest of its template.
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "kebab-case")]
+pub enum ComponentPosition {
+    /// Render before all other components.
+    Start,
+    /// Render after all other components.
+    End,
+}
+
+/// How numeric citation labels are set off from the text.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "kebab-case")]
+pub enum CitationNumberForm {
+    /// Square brackets, e.g., "[1]".
+    Bracket,
+    /// Parentheses, e.g., "(1)".
+    Parenthesis,
+    /// Superscript, e.g., "¹".
+    Superscript,
+    /// No punctuation, e.g., "1".
+    Plain,
+}
+
 /// Type-specific rendering overrides for components.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
@@ -139,6 +172,7 @@ pub enum ComponentOverride {
 /// Selector for reference types in overrides.
 /// Can be a single type string or a list of types.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(untagged)]
 pub enum TypeSelector {
     Single(String),
@@ -174,6 +208,8 @@ pub enum TemplateComponent {
     Variable(TemplateVariable),
     List(TemplateList),
     Term(TemplateTerm),
+    Text(TemplateText),
+    Conditional(TemplateConditional),
 }
 
 impl Default for TemplateComponent {
@@ -207,6 +243,10 @@ pub struct RoleLabel {
     /// Where to place the label relative to names.
     #[serde(default)]
     pub placement: LabelPlacement,
+    /// Case of the label text, e.g., "Edited by" to open an entry and
+    /// "edited by" mid-entry. Defaults to the locale term as written.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text_case: Option<TextCase>,
 }
 
 /// Term form for role labels.
@@ -217,6 +257,10 @@ pub enum RoleLabelForm {
     #[default]
     Short,
     Long,
+    /// Verb phrase (e.g., "edited by").
+    Verb,
+    /// Short verb phrase (e.g., "ed.").
+    VerbShort,
 }
 
 /// Label placement relative to contributor names.
@@ -294,6 +338,9 @@ pub enum ContributorForm {
     FamilyOnly,
     Verb,
     VerbShort,
+    /// The number of contributors (e.g., "3"), counting every name even when
+    /// the list would be shortened with et al.
+    Count,
 }
 
 crate::str_enum! {
@@ -329,6 +376,19 @@ crate::str_enum! {
 pub struct TemplateDate {
     pub date: DateVariable,
     pub form: DateForm,
+    /// For `date: original-published`, prefixes the localized "original work
+    /// published" term (e.g., "original work published 1890"); for
+    /// `date: submitted`, the "submitted" term (e.g., "submitted June 2020").
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show_label: Option<bool>,
+    /// Case applied to the label (e.g., "Original work published 1890").
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label_text_case: Option<TextCase>,
+    /// Introduce the date with the locale's short "floruit" term (e.g., "fl.
+    /// 300 BCE"), for dates recording when an author was active. Replaces the
+    /// approximation marker.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub floruit: Option<bool>,
     /// Fallback components if the primary date is missing.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub fallback: Option<Vec<TemplateComponent>>,
@@ -363,6 +423,7 @@ pub enum DateVariable {
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(rename_all = "kebab-case")]
 pub enum DateForm {
+    /// The year alone, even for month or day precision dates.
     #[default]
     Year,
     YearMonth,
@@ -386,6 +447,18 @@ pub struct TemplateTitle {
     /// appears in citations only to resolve same-author ambiguity.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub disambiguate_only: Option<bool>,
+    /// With `form: short`, shorten a plain title to the text before its
+    /// first colon ("Alpha" for "Alpha: A Study"). Structured titles and
+    /// shorthands are shortened without it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub split_subtitle: Option<bool>,
+    /// Case applied to the "review of" phrase of a reviewed title (e.g.,
+    /// "Review of Walden").
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label_text_case: Option<TextCase>,
+    /// Case transform applied to the title text.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text_case: Option<TextCase>,
     #[serde(flatten, default)]
     pub rendering: Rendering,
     /// Structured link options (DOI, URL).
@@ -399,6 +472,23 @@ pub struct TemplateTitle {
     pub custom: Option<HashMap<String, serde_json::Value>>,
 }
 
+/// Case transform applied to a title, a label, or a localized term.
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "kebab-case")]
+pub enum TextCase {
+    /// Lowercase the text ("edited by", "eds.").
+    Lowercase,
+    /// Uppercase the first character only ("Edited by", "Eds.").
+    CapitalizeFirst,
+    /// Capitalize the first word of the title and of each subtitle, and
+    /// lowercase the rest, keeping acronyms and mixed-case words
+    /// ("Main title: A subtitle").
+    Sentence,
+    /// Capitalize only the first word of the title ("Main title: a subtitle").
+    SentenceFirstWord,
+}
+
 /// Types of titles.
 #[derive(Debug, Default, Deserialize, Serialize, Clone, PartialEq)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
@@ -412,6 +502,9 @@ pub enum TitleType {
     ParentMonograph,
     /// Title of a periodical/serial containing the cited work.
     ParentSerial,
+    /// Title of the work under review, introduced by a localized
+    /// "review of" phrase for review types.
+    Reviewed,
 }
 
 /// Title rendering forms.
@@ -434,6 +527,18 @@ pub struct TemplateNumber {
     pub form: Option<NumberForm>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub label_form: Option<LabelForm>,
+    /// Type-specific label forms, taking precedence over `label-form`
+    /// (e.g., the "§" symbol for legislation sections). When several
+    /// selectors match a type, the first one listed wins.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label_forms: Option<IndexMap<TypeSelector, LabelForm>>,
+    /// Case applied to the label (e.g., "No." rather than "no.").
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label_text_case: Option<TextCase>,
+    /// How to render a numeric edition, followed by the localized edition
+    /// term (e.g., "2nd ed."). Only applies to the `edition` variable.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub edition_form: Option<EditionForm>,
     #[serde(flatten)]
     pub rendering: Rendering,
     /// Structured link options (DOI, URL).
@@ -447,6 +552,20 @@ pub struct TemplateNumber {
     pub custom: Option<HashMap<String, serde_json::Value>>,
 }
 
+/// Edition rendering forms.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "kebab-case")]
+pub enum EditionForm {
+    /// The number with the short edition term (e.g., "2 ed.").
+    Numeric,
+    /// A locale ordinal with the short edition term (e.g., "2nd ed.").
+    Ordinal,
+    /// A capitalized, spelled-out ordinal with the long edition term
+    /// (e.g., "Second edition").
+    LongOrdinal,
+}
+
 /// Number variables.
 #[derive(Debug, Default, Deserialize, Serialize, Clone, PartialEq)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
@@ -469,6 +588,8 @@ pub enum NumberVariable {
     PatentNumber,
     StandardNumber,
     ReportNumber,
+    /// A section of a statute, regulation, or classic work.
+    Section,
 }
 
 /// Number rendering forms.
@@ -501,12 +622,49 @@ pub struct TemplateVariable {
     pub variable: SimpleVariable,
     /// Whether locator labels (e.g., "p.", "sec.") should be rendered when
     /// `variable: locator` is used. If omitted, processor defaults apply.
+    /// For `variable: call-number`, prefixes the localized "call no." label;
+    /// for `variable: patent-number` and `variable: scale`, the "patent" and
+    /// "scale" terms; for `variable: version`, the "version" term.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub show_label: Option<bool>,
+    /// Form of the label added by `show-label` (e.g., "v. 4.1.0" or
+    /// "version 4.1.0"). Only applies to `variable: version`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label_form: Option<LabelForm>,
     /// Strip trailing periods from locator labels (e.g., "p." -> "p").
     /// Only applies to `variable: locator`.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub strip_label_periods: Option<bool>,
+    /// Separator between the parts of a composite locator (e.g., ", " in
+    /// "bk. 2, ch. 3"). Defaults to ", ". Only applies to `variable: locator`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub locator_delimiter: Option<String>,
+    /// Fall back to the localized item-type name when the variable is empty
+    /// (e.g., "Report" for a report without a genre).
+    /// Only applies to `variable: genre`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_fallback: Option<bool>,
+    /// Normalize the identifier's hyphenation. Invalid identifiers render
+    /// unchanged. Only applies to `variable: isbn` and `variable: issn`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub isbn_form: Option<IsbnForm>,
+    /// Introduce the value with a localized phrase (e.g., "retrieved from
+    /// Zenodo"); use `text-case` to capitalize it. Only applies to
+    /// `variable: source`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_phrase: Option<SourcePhrase>,
+    /// Enclose the visible URL text (e.g., "<https://example.com>"); links
+    /// still target the bare URL. Only applies to `variable: url`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url_wrap: Option<UrlWrap>,
+    /// Show only the domain followed by an ellipsis (e.g., "example.com…");
+    /// links still target the full URL. Only applies to `variable: url`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url_shorten: Option<bool>,
+    /// Case transform applied to the value (e.g., "Report" for the
+    /// localized "report" genre fallback).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text_case: Option<TextCase>,
     #[serde(flatten)]
     pub rendering: Rendering,
     /// Structured link options (DOI, URL).
@@ -520,6 +678,42 @@ pub struct TemplateVariable {
     pub custom: Option<HashMap<String, serde_json::Value>>,
 }
 
+/// Localized phrases introducing a repository or database provider.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "kebab-case")]
+pub enum SourcePhrase {
+    /// The "retrieved" and "from" terms, capitalized to open a sentence
+    /// (e.g., "Retrieved from Zenodo").
+    RetrievedFrom,
+    /// The "available at" term (e.g., "available at GitHub").
+    AvailableAt,
+}
+
+/// ISBN and ISSN rendering forms.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "kebab-case")]
+pub enum IsbnForm {
+    /// Standard hyphenation (e.g., "978-0-306-40615-7", "0378-5955").
+    Hyphenated,
+    /// Digits only, with separators removed (e.g., "9780306406157").
+    Compact,
+}
+
+/// Delimiters around a rendered URL.
+#[derive(Debug, Default, Deserialize, Serialize, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "kebab-case")]
+pub enum UrlWrap {
+    #[default]
+    None,
+    /// Angle brackets, e.g., "<https://example.com>".
+    Angle,
+    /// Parentheses, e.g., "(https://example.com)".
+    Paren,
+}
+
 /// Simple string variables.
 #[derive(Debug, Default, Deserialize, Serialize, Clone, PartialEq)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
@@ -543,8 +737,12 @@ pub enum SimpleVariable {
     Status,
     Archive,
     ArchiveLocation,
+    ArchivePlace,
+    CallNumber,
     Publisher,
     PublisherPlace,
+    OriginalPublisher,
+    OriginalPublisherPlace,
     EventPlace,
     Dimensions,
     Scale,
@@ -581,6 +779,90 @@ pub struct TemplateTerm {
     pub custom: Option<HashMap<String, serde_json::Value>>,
 }
 
+/// A literal text component (e.g., "In"), rendered with the standard
+/// formatting options.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct TemplateText {
+    /// The text to render verbatim.
+    pub text: String,
+    #[serde(flatten, default)]
+    pub rendering: Rendering,
+    /// Type-specific rendering overrides.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub overrides: Option<HashMap<TypeSelector, ComponentOverride>>,
+    /// Custom user-defined fields for extensions.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom: Option<HashMap<String, serde_json::Value>>,
+}
+
+/// A conditional component that renders one of two component lists.
+///
+/// Tests are limited to variable presence and item type, keeping templates
+/// declarative:
+///
+/// ```yaml
+/// - if:
+///     present: [doi]
+///   then:
+///     - variable: doi
+///   else:
+///     - variable: url
+/// ```
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct TemplateConditional {
+    /// The test that selects the branch.
+    #[serde(rename = "if")]
+    pub condition: Condition,
+    /// Components rendered when the test holds.
+    pub then: Vec<TemplateComponent>,
+    /// Components rendered when the test fails.
+    #[serde(rename = "else", default, skip_serializing_if = "Vec::is_empty")]
+    pub otherwise: Vec<TemplateComponent>,
+    /// Delimiter between the rendered components of a branch.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delimiter: Option<DelimiterPunctuation>,
+    #[serde(flatten, default)]
+    pub rendering: Rendering,
+    /// Type-specific rendering overrides.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub overrides: Option<HashMap<TypeSelector, ComponentOverride>>,
+    /// Custom user-defined fields for extensions.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom: Option<HashMap<String, serde_json::Value>>,
+}
+
+/// A test on a reference; every specified part must hold.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct Condition {
+    /// Variables that must all have a value.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub present: Vec<ConditionVariable>,
+    /// Variables that must all be empty.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub absent: Vec<ConditionVariable>,
+    /// Reference types, one of which must match.
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub item_type: Option<TypeSelector>,
+}
+
+/// A variable that can be tested for presence in a condition.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(untagged)]
+pub enum ConditionVariable {
+    Simple(SimpleVariable),
+    Number(NumberVariable),
+    Date(DateVariable),
+    Title(TitleType),
+    Contributor(ContributorRole),
+}
+
 /// A list component for grouping multiple items with a delimiter.
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Default)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
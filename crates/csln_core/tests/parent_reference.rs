@@ -12,19 +12,27 @@ fn test_serial_component_with_parent_id() {
         title: Some(Title::Single("My Article".to_string())),
         author: None,
         translator: None,
+        director: None,
         issued: EdtfString("2023".to_string()),
+        submitted: None,
         parent: Parent::Id(parent_id.clone()),
         url: None,
         accessed: None,
         language: None,
         note: None,
+        abstract_text: None,
         doi: None,
         pages: None,
         volume: None,
         issue: None,
         genre: None,
         medium: None,
+        pmid: None,
+        pmcid: None,
+        dimensions: None,
         keywords: None,
+        reviewed_title: None,
+        reviewed_author: None,
     };
 
     match component.parent {
@@ -49,6 +57,7 @@ fn test_collection_component_with_parent_id() {
         accessed: None,
         language: None,
         note: None,
+        abstract_text: None,
         doi: None,
         genre: None,
         medium: None,
@@ -1,16 +1,22 @@
 //! csln_edtf - A modern EDTF (Extended Date/Time Format) parser
 //!
-//! This crate implements ISO 8601-2:2019 (EDTF) Level 0 and Level 1.
+//! This crate implements ISO 8601-2:2019 (EDTF) Level 0 and Level 1, plus
+//! the Level 2 set notations ("one of" and "all of").
 
 use winnow::ascii::dec_int;
-use winnow::combinator::{alt, opt, preceded};
-use winnow::error::{ContextError, ErrMode};
+use winnow::combinator::{alt, cut_err, delimited, opt, preceded, separated};
+use winnow::error::{ContextError, ErrMode, StrContext};
 use winnow::prelude::*;
 use winnow::token::take;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "chrono")]
+mod naive_date;
+#[cfg(feature = "chrono")]
+pub use naive_date::NaiveDateError;
+
 /// Represents the top-level EDTF value.
 #[derive(Debug, PartialEq, Eq, Clone)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -19,10 +25,62 @@ pub enum Edtf {
     Date(Date),
     /// A date interval.
     Interval(Interval),
-    /// An open-ended interval starting at a specific date.
-    IntervalFrom(Date),
-    /// An open-ended interval ending at a specific date.
-    IntervalTo(Date),
+    /// An interval starting at a specific date with an open ("2004/..") or
+    /// unknown ("2004/") end.
+    IntervalFrom { start: Date, end_kind: EndpointKind },
+    /// An interval ending at a specific date with an open ("../2004") or
+    /// unknown ("/2004") start.
+    IntervalTo { end: Date, start_kind: EndpointKind },
+    /// One member of a set, e.g. `[1667,1668,1670..1672]` (Level 2).
+    OneOfSet(Vec<SetMember>),
+    /// All members of a set, e.g. `{1960,1961,1962}` (Level 2).
+    AllOfSet(Vec<SetMember>),
+}
+
+/// The missing endpoint of a half-bounded interval.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum EndpointKind {
+    /// The interval has no bound in that direction (e.g., ongoing), written `..`.
+    Open,
+    /// The endpoint exists but is not known, written as an empty string.
+    Unknown,
+}
+
+/// A member of an EDTF Level 2 set.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum SetMember {
+    /// A single date.
+    Date(Date),
+    /// An inclusive range of dates (e.g., `1670..1672`).
+    Range(Interval),
+    /// The date or any earlier one (e.g., `..1760-12-03`).
+    Earlier(Date),
+    /// The date or any later one (e.g., `1760-12..`).
+    Later(Date),
+}
+
+impl SetMember {
+    /// The date the member is written with; the start of a range.
+    pub fn date(&self) -> &Date {
+        match self {
+            SetMember::Date(date) | SetMember::Earlier(date) | SetMember::Later(date) => date,
+            SetMember::Range(interval) => &interval.start,
+        }
+    }
+
+    /// The earliest and latest instants covered, with `None` on an open side.
+    fn bounds(&self) -> (Option<Instant>, Option<Instant>) {
+        match self {
+            SetMember::Date(date) => (Some(date.earliest()), Some(date.latest())),
+            SetMember::Range(interval) => {
+                (Some(interval.start.earliest()), Some(interval.end.latest()))
+            }
+            SetMember::Earlier(date) => (None, Some(date.latest())),
+            SetMember::Later(date) => (Some(date.earliest()), None),
+        }
+    }
 }
 
 /// A date interval.
@@ -53,12 +111,31 @@ pub struct Quality {
     pub approximate: bool, // '~'
 }
 
-/// A year in an EDTF date, which may contain unspecified digits.
+/// A year in an EDTF date, which may contain unspecified digits or name a
+/// whole decade or century.
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Year {
+    /// The year, with unspecified or masked digits as zero (1960 for "196X").
     pub value: i64,
     pub unspecified: UnspecifiedYear,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub precision: YearPrecision,
+}
+
+/// The precision of a year (EDTF Level 2).
+///
+/// A decade ("196X") or century ("19XX") is a single span of time, unlike a
+/// year with unspecified digits ("196u"), which is one unknown year.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum YearPrecision {
+    #[default]
+    Year,
+    /// A decade (e.g., 196X)
+    Decade,
+    /// A century (e.g., 19XX)
+    Century,
 }
 
 /// Unspecified digits in a year (EDTF Level 1).
@@ -103,8 +180,296 @@ pub struct Date {
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Time {
     pub hour: u32,
-    pub minute: u32,
-    pub second: u32,
+    /// The minute, absent for hour-only times ("T10").
+    pub minute: Option<u32>,
+    /// The second, absent for reduced-precision times ("T10:30").
+    pub second: Option<u32>,
+    /// The time zone, if given ("Z" or "±HH:MM").
+    pub tz: Option<TzOffset>,
+}
+
+impl Time {
+    /// Seconds since midnight, filling missing components with `fill`
+    /// (0 for the earliest instant, 59 for the latest).
+    fn seconds_since_midnight(&self, fill: u32) -> u32 {
+        self.hour * 3600 + self.minute.unwrap_or(fill) * 60 + self.second.unwrap_or(fill)
+    }
+}
+
+/// A time zone designator.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum TzOffset {
+    /// Coordinated Universal Time ("Z").
+    Utc,
+    /// An offset from UTC in signed minutes (e.g., -300 for "-05:00",
+    /// -30 for "-00:30").
+    Offset { minutes: i16 },
+}
+
+/// A comparable point in time: year, month, day, and seconds since midnight.
+type Instant = (i64, u32, u32, u32);
+
+impl Quality {
+    /// Combine two qualities; a marker in either applies to the result.
+    fn merge(self, other: Quality) -> Quality {
+        Quality {
+            uncertain: self.uncertain || other.uncertain,
+            approximate: self.approximate || other.approximate,
+        }
+    }
+}
+
+impl Date {
+    /// The quality of the date as a whole, if every component present shares
+    /// it.
+    ///
+    /// "2004-06-11~" and "2004" give `Some`, so a style can render "c. 2004"
+    /// or a plain year; "2004-06-?11", where only the day is uncertain, gives
+    /// `None`.
+    pub fn overall_quality(&self) -> Option<Quality> {
+        let mut qualities = vec![self.year_quality];
+        if self.month_or_season.is_some() {
+            qualities.push(self.month_quality);
+        }
+        if self.day.is_some() {
+            qualities.push(self.day_quality);
+        }
+        qualities
+            .iter()
+            .all(|quality| *quality == self.year_quality)
+            .then_some(self.year_quality)
+    }
+
+    /// A key for chronological sorting: year, month, and day.
+    ///
+    /// Unspecified year digits count as zero ("199u" sorts as 1990). A
+    /// missing or unspecified month or day sorts as 0, before any specified
+    /// value, so "2004" precedes "2004-01" and "2004-uu" precedes "2004-03".
+    /// Seasons sort by their first month (spring as March, winter as
+    /// December). Times and the uncertain/approximate qualities are ignored,
+    /// so "2004?" and "2004~" sort with "2004"; use a stable sort to keep
+    /// such ties in input order.
+    pub fn sort_key(&self) -> (i64, u8, u8) {
+        let month = match self.month_or_season {
+            Some(MonthOrSeason::Month(month)) => month as u8,
+            Some(MonthOrSeason::Spring) => 3,
+            Some(MonthOrSeason::Summer) => 6,
+            Some(MonthOrSeason::Autumn) => 9,
+            Some(MonthOrSeason::Winter) => 12,
+            Some(MonthOrSeason::Unspecified) | None => 0,
+        };
+        let day = match self.day {
+            Some(Day::Day(day)) => day as u8,
+            Some(Day::Unspecified) | None => 0,
+        };
+        (self.year.value, month, day)
+    }
+
+    /// The range of years covered, widened for unspecified digits
+    /// (e.g., 1990 to 1999 for "199u") and for decades and centuries.
+    fn year_span(&self) -> (i64, i64) {
+        let width = match (self.year.precision, self.year.unspecified) {
+            (YearPrecision::Decade, _) => 9,
+            (YearPrecision::Century, _) => 99,
+            (YearPrecision::Year, UnspecifiedYear::None) => 0,
+            (YearPrecision::Year, UnspecifiedYear::One) => 9,
+            (YearPrecision::Year, UnspecifiedYear::Two) => 99,
+            (YearPrecision::Year, UnspecifiedYear::Three) => 999,
+            (YearPrecision::Year, UnspecifiedYear::Four) => 9999,
+        };
+        (self.year.value, self.year.value + width)
+    }
+
+    /// The calendar months the date covers, in order.
+    ///
+    /// A month yields itself, a season its three months (winter runs from
+    /// December into the following year), and a bare or unspecified month
+    /// all twelve, repeated for each year of a decade or century. A year
+    /// with unspecified digits ("199u") or an extended year ("Y17000000002")
+    /// is not a concrete span and yields no months.
+    pub fn covered_months(&self) -> Vec<(i64, u32)> {
+        if self.concrete_years().is_none() {
+            return Vec::new();
+        }
+        // Months as offsets from the date's year.
+        let months: Vec<(i64, u32)> = match self.month_or_season {
+            Some(MonthOrSeason::Month(month)) => vec![(0, month)],
+            Some(MonthOrSeason::Spring) => vec![(0, 3), (0, 4), (0, 5)],
+            Some(MonthOrSeason::Summer) => vec![(0, 6), (0, 7), (0, 8)],
+            Some(MonthOrSeason::Autumn) => vec![(0, 9), (0, 10), (0, 11)],
+            Some(MonthOrSeason::Winter) => vec![(0, 12), (1, 1), (1, 2)],
+            Some(MonthOrSeason::Unspecified) | None => (1..=12).map(|m| (0, m)).collect(),
+        };
+        let (first, last) = self.year_span();
+        (first..=last)
+            .flat_map(|year| months.iter().map(move |(offset, m)| (year + offset, *m)))
+            .collect()
+    }
+
+    /// The years from the earliest to the latest instant of the date, or
+    /// `None` if the year has unspecified digits or is an extended year.
+    fn concrete_years(&self) -> Option<std::ops::RangeInclusive<i64>> {
+        let concrete = self.year.unspecified == UnspecifiedYear::None
+            && (-9999..=9999).contains(&self.year.value);
+        concrete.then(|| self.earliest().0..=self.latest().0)
+    }
+
+    /// The earliest instant the date can refer to (e.g., the start of
+    /// January 1 for "2023").
+    fn earliest(&self) -> Instant {
+        let (year, _) = self.year_span();
+        let month = match self.month_or_season {
+            Some(MonthOrSeason::Month(month)) => month,
+            Some(MonthOrSeason::Spring) => 3,
+            Some(MonthOrSeason::Summer) => 6,
+            Some(MonthOrSeason::Autumn) => 9,
+            Some(MonthOrSeason::Winter) => 12,
+            Some(MonthOrSeason::Unspecified) | None => 1,
+        };
+        let day = match self.day {
+            Some(Day::Day(day)) => day,
+            _ => 1,
+        };
+        let time = self.time.map_or(0, |t| t.seconds_since_midnight(0));
+        (year, month, day, time)
+    }
+
+    /// The latest instant the date can refer to (e.g., the end of
+    /// December 31 for "2023"). Winter runs into the following year.
+    fn latest(&self) -> Instant {
+        let (_, year) = self.year_span();
+        let (year, month) = match self.month_or_season {
+            Some(MonthOrSeason::Month(month)) => (year, month),
+            Some(MonthOrSeason::Spring) => (year, 5),
+            Some(MonthOrSeason::Summer) => (year, 8),
+            Some(MonthOrSeason::Autumn) => (year, 11),
+            Some(MonthOrSeason::Winter) => (year + 1, 2),
+            Some(MonthOrSeason::Unspecified) | None => (year, 12),
+        };
+        let day = match self.day {
+            Some(Day::Day(day)) => day,
+            _ => 31,
+        };
+        let time = self.time.map_or(86_399, |t| t.seconds_since_midnight(59));
+        (year, month, day, time)
+    }
+}
+
+impl Interval {
+    /// Whether the whole of `date` falls within the interval.
+    ///
+    /// Reduced-precision dates cover their full span, so "2020/2021"
+    /// contains "2021-12" but "2020-06/2021" does not contain "2020".
+    pub fn contains(&self, date: &Date) -> bool {
+        Edtf::Interval(self.clone()).contains(date)
+    }
+
+    /// Whether the interval shares any instant with `other`.
+    pub fn overlaps(&self, other: &Interval) -> bool {
+        Edtf::Interval(self.clone()).overlaps(&Edtf::Interval(other.clone()))
+    }
+}
+
+/// The years from `start` through `end`, or `None` if either is not a
+/// concrete year.
+fn years_between(start: &Date, end: &Date) -> Option<std::ops::RangeInclusive<i64>> {
+    Some(*start.concrete_years()?.start()..=*end.concrete_years()?.end())
+}
+
+impl Edtf {
+    /// The calendar years the value covers, in ascending order without
+    /// duplicates.
+    ///
+    /// A date yields its year, or every year of a decade or century; an
+    /// interval yields each year from its start through its end. A
+    /// half-bounded interval yields only the years of its known endpoint,
+    /// and a set the years of all its members. Dates whose year has
+    /// unspecified digits ("199u") or is an extended year ("Y17000000002")
+    /// contribute no years, so such values yield an empty iterator.
+    pub fn iter_years(&self) -> impl Iterator<Item = i64> {
+        let years: std::collections::BTreeSet<i64> = match self {
+            Edtf::Date(date)
+            | Edtf::IntervalFrom { start: date, .. }
+            | Edtf::IntervalTo { end: date, .. } => {
+                date.concrete_years().into_iter().flatten().collect()
+            }
+            Edtf::Interval(interval) => years_between(&interval.start, &interval.end)
+                .into_iter()
+                .flatten()
+                .collect(),
+            Edtf::OneOfSet(members) | Edtf::AllOfSet(members) => members
+                .iter()
+                .flat_map(|member| match member {
+                    SetMember::Date(date) | SetMember::Earlier(date) | SetMember::Later(date) => {
+                        date.concrete_years()
+                    }
+                    SetMember::Range(interval) => years_between(&interval.start, &interval.end),
+                })
+                .flatten()
+                .collect(),
+        };
+        years.into_iter()
+    }
+
+    /// A key for chronological sorting, as for [`Date::sort_key`].
+    ///
+    /// Intervals sort by their start date, and intervals with an open
+    /// start ("../2020") by their end date. Sets sort by their earliest
+    /// member as written, and an empty set sorts first.
+    pub fn sort_key(&self) -> (i64, u8, u8) {
+        match self {
+            Edtf::Date(date)
+            | Edtf::IntervalFrom { start: date, .. }
+            | Edtf::IntervalTo { end: date, .. } => date.sort_key(),
+            Edtf::Interval(interval) => interval.start.sort_key(),
+            Edtf::OneOfSet(members) | Edtf::AllOfSet(members) => members
+                .iter()
+                .map(|member| member.date().sort_key())
+                .min()
+                .unwrap_or((i64::MIN, 0, 0)),
+        }
+    }
+
+    /// The earliest and latest instants of each span covered, with `None` on
+    /// an open side. A set has one span per member, so the gaps between
+    /// members are not covered.
+    fn spans(&self) -> Vec<(Option<Instant>, Option<Instant>)> {
+        match self {
+            Edtf::Date(date) => vec![(Some(date.earliest()), Some(date.latest()))],
+            Edtf::Interval(interval) => {
+                vec![(Some(interval.start.earliest()), Some(interval.end.latest()))]
+            }
+            Edtf::IntervalFrom { start, .. } => vec![(Some(start.earliest()), None)],
+            Edtf::IntervalTo { end, .. } => vec![(None, Some(end.latest()))],
+            Edtf::OneOfSet(members) | Edtf::AllOfSet(members) => {
+                members.iter().map(SetMember::bounds).collect()
+            }
+        }
+    }
+
+    /// Whether the whole of `date` falls within this value. Open intervals
+    /// are unbounded on their open side, and a set contains the date if any
+    /// one member does.
+    pub fn contains(&self, date: &Date) -> bool {
+        self.spans().into_iter().any(|(start, end)| {
+            start.is_none_or(|start| start <= date.earliest())
+                && end.is_none_or(|end| date.latest() <= end)
+        })
+    }
+
+    /// Whether this value shares any instant with `other`. Open intervals
+    /// are unbounded on their open side, and a set overlaps if any one
+    /// member does.
+    pub fn overlaps(&self, other: &Edtf) -> bool {
+        let other_spans = other.spans();
+        self.spans().into_iter().any(|(start, end)| {
+            other_spans.iter().any(|&(other_start, other_end)| {
+                start.zip(other_end).is_none_or(|(start, end)| start <= end)
+                    && other_start.zip(end).is_none_or(|(start, end)| start <= end)
+            })
+        })
+    }
 }
 
 use std::fmt;
@@ -114,19 +479,56 @@ impl fmt::Display for Edtf {
         match self {
             Edtf::Date(d) => write!(f, "{}", d),
             Edtf::Interval(i) => write!(f, "{}/{}", i.start, i.end),
-            Edtf::IntervalFrom(d) => write!(f, "{}/..", d),
-            Edtf::IntervalTo(d) => write!(f, "../{}", d),
+            Edtf::IntervalFrom { start, end_kind } => write!(f, "{}/{}", start, end_kind),
+            Edtf::IntervalTo { end, start_kind } => write!(f, "{}/{}", start_kind, end),
+            Edtf::OneOfSet(members) => write_set(f, members, '[', ']'),
+            Edtf::AllOfSet(members) => write_set(f, members, '{', '}'),
+        }
+    }
+}
+
+fn write_set(
+    f: &mut fmt::Formatter<'_>,
+    members: &[SetMember],
+    open: char,
+    close: char,
+) -> fmt::Result {
+    write!(f, "{}", open)?;
+    for (i, member) in members.iter().enumerate() {
+        if i > 0 {
+            write!(f, ",")?;
+        }
+        write!(f, "{}", member)?;
+    }
+    write!(f, "{}", close)
+}
+
+impl fmt::Display for SetMember {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SetMember::Date(d) => write!(f, "{}", d),
+            SetMember::Range(i) => write!(f, "{}..{}", i.start, i.end),
+            SetMember::Earlier(d) => write!(f, "..{}", d),
+            SetMember::Later(d) => write!(f, "{}..", d),
         }
     }
 }
 
 impl fmt::Display for Date {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}{}", self.year, self.year_quality)?;
+        let mut qualities = vec![self.year_quality];
+        if self.month_or_season.is_some() {
+            qualities.push(self.month_quality);
+            if self.day.is_some() {
+                qualities.push(self.day_quality);
+            }
+        }
+        let (left, right) = qualifier_positions(&qualities);
+        write!(f, "{}{}{}", left[0], self.year, right[0])?;
         if let Some(m) = self.month_or_season {
-            write!(f, "-{}{}", m, self.month_quality)?;
+            write!(f, "-{}{}{}", left[1], m, right[1])?;
             if let Some(d) = self.day {
-                write!(f, "-{}{}", d, self.day_quality)?;
+                write!(f, "-{}{}{}", left[2], d, right[2])?;
             }
         }
         if let Some(t) = self.time {
@@ -136,54 +538,74 @@ impl fmt::Display for Date {
     }
 }
 
+/// Place qualifiers for the given component qualities, year first.
+///
+/// Each marker is written once to the right of the last component of the
+/// leading run that shares it, and to the left of any later component that
+/// has it on its own, so that parsing the result gives back the same
+/// qualities.
+fn qualifier_positions(qualities: &[Quality]) -> (Vec<Quality>, Vec<Quality>) {
+    let (uncertain_left, uncertain_right) =
+        marker_positions(qualities.iter().map(|quality| quality.uncertain));
+    let (approximate_left, approximate_right) =
+        marker_positions(qualities.iter().map(|quality| quality.approximate));
+    let combine = |uncertain: Vec<bool>, approximate: Vec<bool>| {
+        uncertain
+            .into_iter()
+            .zip(approximate)
+            .map(|(uncertain, approximate)| Quality {
+                uncertain,
+                approximate,
+            })
+            .collect()
+    };
+    (
+        combine(uncertain_left, approximate_left),
+        combine(uncertain_right, approximate_right),
+    )
+}
+
+/// Left and right positions for one qualifier marker; see
+/// [`qualifier_positions`].
+fn marker_positions(marked: impl Iterator<Item = bool>) -> (Vec<bool>, Vec<bool>) {
+    let marked: Vec<bool> = marked.collect();
+    let run = marked.iter().take_while(|marked| **marked).count();
+    let left = marked
+        .iter()
+        .enumerate()
+        .map(|(index, marked)| *marked && index >= run)
+        .collect();
+    let right = (0..marked.len()).map(|index| index + 1 == run).collect();
+    (left, right)
+}
+
+impl fmt::Display for EndpointKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EndpointKind::Open => write!(f, ".."),
+            EndpointKind::Unknown => Ok(()),
+        }
+    }
+}
+
 impl fmt::Display for Year {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         if self.value > 9999 || self.value < -9999 {
-            write!(f, "Y{}", self.value)
-        } else if self.value < 0 {
-            let abs_val = self.value.abs();
-            let mut s = format!("{:04}", abs_val);
-            match self.unspecified {
-                UnspecifiedYear::None => write!(f, "-{}", s),
-                UnspecifiedYear::One => {
-                    s.replace_range(3..4, "u");
-                    write!(f, "-{}", s)
-                }
-                UnspecifiedYear::Two => {
-                    s.replace_range(2..4, "uu");
-                    write!(f, "-{}", s)
-                }
-                UnspecifiedYear::Three => {
-                    s.replace_range(1..4, "uuu");
-                    write!(f, "-{}", s)
-                }
-                UnspecifiedYear::Four => {
-                    s.replace_range(0..4, "uuuu");
-                    write!(f, "-{}", s)
-                }
-            }
-        } else {
-            let mut s = format!("{:04}", self.value);
-            match self.unspecified {
-                UnspecifiedYear::None => write!(f, "{}", s),
-                UnspecifiedYear::One => {
-                    s.replace_range(3..4, "u");
-                    write!(f, "{}", s)
-                }
-                UnspecifiedYear::Two => {
-                    s.replace_range(2..4, "uu");
-                    write!(f, "{}", s)
-                }
-                UnspecifiedYear::Three => {
-                    s.replace_range(1..4, "uuu");
-                    write!(f, "{}", s)
-                }
-                UnspecifiedYear::Four => {
-                    s.replace_range(0..4, "uuuu");
-                    write!(f, "{}", s)
-                }
-            }
+            return write!(f, "Y{}", self.value);
         }
+        let sign = if self.value < 0 { "-" } else { "" };
+        let digits = format!("{:04}", self.value.abs());
+        let (masked, mask) = match (self.precision, self.unspecified) {
+            (YearPrecision::Decade, _) => (1, 'X'),
+            (YearPrecision::Century, _) => (2, 'X'),
+            (YearPrecision::Year, UnspecifiedYear::None) => (0, 'u'),
+            (YearPrecision::Year, UnspecifiedYear::One) => (1, 'u'),
+            (YearPrecision::Year, UnspecifiedYear::Two) => (2, 'u'),
+            (YearPrecision::Year, UnspecifiedYear::Three) => (3, 'u'),
+            (YearPrecision::Year, UnspecifiedYear::Four) => (4, 'u'),
+        };
+        let mask: String = std::iter::repeat_n(mask, masked).collect();
+        write!(f, "{}{}{}", sign, &digits[..4 - masked], mask)
     }
 }
 
@@ -211,7 +633,27 @@ impl fmt::Display for Day {
 
 impl fmt::Display for Time {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{:02}:{:02}:{:02}", self.hour, self.minute, self.second)
+        write!(f, "{:02}", self.hour)?;
+        for component in [self.minute, self.second].into_iter().flatten() {
+            write!(f, ":{:02}", component)?;
+        }
+        if let Some(tz) = self.tz {
+            write!(f, "{}", tz)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for TzOffset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TzOffset::Utc => write!(f, "Z"),
+            TzOffset::Offset { minutes } => {
+                let sign = if *minutes < 0 { '-' } else { '+' };
+                let minutes = minutes.unsigned_abs();
+                write!(f, "{}{:02}:{:02}", sign, minutes / 60, minutes % 60)
+            }
+        }
     }
 }
 
@@ -252,11 +694,21 @@ fn parse_year(input: &mut &str) -> Result<Year, ErrMode<ContextError>> {
         return Ok(Year {
             value,
             unspecified: UnspecifiedYear::None,
+            precision: YearPrecision::Year,
         });
     }
 
     let sign = opt(alt(('-', '+'))).parse_next(input)?;
-    let s = take(4_usize).parse_next(input)?;
+    let s: &str = take(4_usize).parse_next(input)?;
+
+    // A trailing "X" or "XX" after specified digits names a decade or
+    // century; any other masking is a year with unspecified digits.
+    let masked = s.chars().rev().take_while(|&c| c == 'X').count();
+    let precision = match masked {
+        1 if s[..3].chars().all(|c| c.is_ascii_digit()) => YearPrecision::Decade,
+        2 if s[..2].chars().all(|c| c.is_ascii_digit()) => YearPrecision::Century,
+        _ => YearPrecision::Year,
+    };
 
     let mut value_str = String::with_capacity(4);
     let mut unspecified_count = 0;
@@ -281,6 +733,7 @@ fn parse_year(input: &mut &str) -> Result<Year, ErrMode<ContextError>> {
     }
 
     let unspecified = match unspecified_count {
+        _ if precision != YearPrecision::Year => UnspecifiedYear::None,
         0 => UnspecifiedYear::None,
         1 => UnspecifiedYear::One,
         2 => UnspecifiedYear::Two,
@@ -289,7 +742,11 @@ fn parse_year(input: &mut &str) -> Result<Year, ErrMode<ContextError>> {
         _ => return Err(ErrMode::Backtrack(ContextError::default())),
     };
 
-    Ok(Year { value, unspecified })
+    Ok(Year {
+        value,
+        unspecified,
+        precision,
+    })
 }
 
 fn parse_month_or_season(input: &mut &str) -> Result<MonthOrSeason, ErrMode<ContextError>> {
@@ -318,62 +775,123 @@ fn parse_day(input: &mut &str) -> Result<Day, ErrMode<ContextError>> {
         return Ok(Day::Unspecified);
     }
 
-    let val: u32 = s
-        .parse()
-        .map_err(|_| ErrMode::Backtrack(ContextError::default()))?;
-    Ok(Day::Day(val))
+    match s.parse() {
+        Ok(val @ 1..=31) => Ok(Day::Day(val)),
+        _ => Err(ErrMode::Backtrack(ContextError::default())),
+    }
 }
 
 fn parse_time(input: &mut &str) -> Result<Time, ErrMode<ContextError>> {
-    let hour = take(2_usize)
-        .try_map(|s: &str| s.parse::<u32>())
-        .parse_next(input)?;
-    let _ = ':'.parse_next(input)?;
-    let minute = take(2_usize)
-        .try_map(|s: &str| s.parse::<u32>())
-        .parse_next(input)?;
-    let _ = ':'.parse_next(input)?;
-    let second = take(2_usize)
-        .try_map(|s: &str| s.parse::<u32>())
-        .parse_next(input)?;
+    let hour = u32::from(two_digits.verify(|h| *h < 24).parse_next(input)?);
+    // Minutes and seconds may be omitted for reduced precision ("T10").
+    let minute = opt(preceded(':', cut_err(two_digits.verify(|m| *m < 60))))
+        .parse_next(input)?
+        .map(u32::from);
+    let second = if minute.is_some() {
+        opt(preceded(':', cut_err(two_digits.verify(|s| *s < 60))))
+            .parse_next(input)?
+            .map(u32::from)
+    } else {
+        None
+    };
+    let tz = opt(parse_tz).parse_next(input)?;
 
     Ok(Time {
         hour,
         minute,
         second,
+        tz,
     })
 }
 
+/// Parses a time zone designator: "Z" or "±HH:MM".
+fn parse_tz(input: &mut &str) -> Result<TzOffset, ErrMode<ContextError>> {
+    if opt('Z').parse_next(input)?.is_some() {
+        return Ok(TzOffset::Utc);
+    }
+    let sign = alt(('+', '-')).parse_next(input)?;
+    let hours = cut_err(two_digits.verify(|h| *h <= 14)).parse_next(input)?;
+    let _ = cut_err(':').parse_next(input)?;
+    let minutes = cut_err(two_digits.verify(|m| *m < 60)).parse_next(input)?;
+    let offset = i16::from(hours) * 60 + i16::from(minutes);
+
+    Ok(TzOffset::Offset {
+        minutes: if sign == '-' { -offset } else { offset },
+    })
+}
+
+/// Parses exactly two ASCII digits.
+fn two_digits(input: &mut &str) -> Result<u8, ErrMode<ContextError>> {
+    take(2_usize)
+        .verify(|s: &str| s.bytes().all(|b| b.is_ascii_digit()))
+        .try_map(str::parse)
+        .parse_next(input)
+}
+
+/// Wrap a component parser so that failures are labeled with `label` and
+/// leave the input at the start of the component.
+fn component<'i, O>(
+    label: &'static str,
+    parser: impl Parser<&'i str, O, ErrMode<ContextError>>,
+) -> impl Parser<&'i str, O, ErrMode<ContextError>> {
+    let mut parser = parser.context(StrContext::Label(label));
+    move |input: &mut &'i str| {
+        let start = *input;
+        parser.parse_next(input).inspect_err(|_| *input = start)
+    }
+}
+
 /// Parses a single date component.
-pub fn parse_date(input: &mut &str) -> Result<Date, ErrMode<ContextError>> {
-    let year = parse_year.parse_next(input)?;
-    let year_quality = parse_quality.parse_next(input)?;
+///
+/// Following ISO 8601-2, a qualifier to the left of a component applies to
+/// that component only ("2004-?06-11"), while one to the right applies to
+/// the component and every component before it, so in "2004-06?-11" the
+/// year and month are uncertain and in "2004-06-11?" the whole date is.
+fn parse_date(input: &mut &str) -> Result<Date, ErrMode<ContextError>> {
+    let year_left = parse_quality.parse_next(input)?;
+    let year = component("year", parse_year).parse_next(input)?;
+    let year_right = parse_quality.parse_next(input)?;
+    let mut year_quality = year_left.merge(year_right);
 
-    let month_or_season = opt(preceded('-', parse_month_or_season)).parse_next(input)?;
-    let month_quality = if month_or_season.is_some() {
-        parse_quality.parse_next(input)?
-    } else {
-        Quality::default()
+    // A '-' after the year always introduces a month, so a bad month is an
+    // error rather than trailing input.
+    let month = opt(preceded(
+        '-',
+        cut_err((
+            parse_quality,
+            component("month", parse_month_or_season),
+            parse_quality,
+        )),
+    ))
+    .parse_next(input)?;
+    let (month_or_season, mut month_quality) = match month {
+        Some((left, month, right)) => {
+            year_quality = year_quality.merge(right);
+            (Some(month), left.merge(right))
+        }
+        None => (None, Quality::default()),
     };
 
     let day =
         if let Some(MonthOrSeason::Month(_)) | Some(MonthOrSeason::Unspecified) = month_or_season {
-            opt(preceded('-', parse_day)).parse_next(input)?
+            opt(preceded(
+                '-',
+                cut_err((parse_quality, component("day", parse_day), parse_quality)),
+            ))
+            .parse_next(input)?
         } else {
             None
         };
-    let day_quality = if day.is_some() {
-        parse_quality.parse_next(input)?
-    } else {
-        Quality::default()
+    let (day, day_quality) = match day {
+        Some((left, day, right)) => {
+            year_quality = year_quality.merge(right);
+            month_quality = month_quality.merge(right);
+            (Some(day), left.merge(right))
+        }
+        None => (None, Quality::default()),
     };
 
-    let time = opt(preceded('T', parse_time)).parse_next(input)?;
-
-    // Final check: if the last component parsed didn't have a quality marker,
-    // but there is one at the end of the string, it applies to the whole thing?
-    // Actually, ISO 8601-2 says it applies to what's on the left.
-    // If we have "2004-06-11?", it applies to "11".
+    let time = opt(preceded('T', cut_err(component("time", parse_time)))).parse_next(input)?;
 
     Ok(Date {
         year,
@@ -386,12 +904,70 @@ pub fn parse_date(input: &mut &str) -> Result<Date, ErrMode<ContextError>> {
     })
 }
 
-/// Main entry point for parsing an EDTF Level 1 string.
-pub fn parse(input: &mut &str) -> Result<Edtf, ErrMode<ContextError>> {
-    if input.starts_with("../") {
-        let _ = "../".parse_next(input)?;
-        let date = parse_date.parse_next(input)?;
-        return Ok(Edtf::IntervalTo(date));
+/// Parses a set member: a date, a `..` range, or an open-ended date.
+fn parse_set_member(input: &mut &str) -> Result<SetMember, ErrMode<ContextError>> {
+    if opt("..").parse_next(input)?.is_some() {
+        return Ok(SetMember::Earlier(parse_date.parse_next(input)?));
+    }
+    let start = parse_date.parse_next(input)?;
+    if opt("..").parse_next(input)?.is_none() {
+        return Ok(SetMember::Date(start));
+    }
+    Ok(match opt(parse_date).parse_next(input)? {
+        Some(end) => SetMember::Range(Interval { start, end }),
+        None => SetMember::Later(start),
+    })
+}
+
+/// Parses comma-separated set members between `open` and `close`.
+fn parse_set(
+    input: &mut &str,
+    open: char,
+    close: char,
+) -> Result<Vec<SetMember>, ErrMode<ContextError>> {
+    delimited(
+        open,
+        separated(1.., parse_set_member, ','),
+        component("set", close),
+    )
+    .parse_next(input)
+}
+
+/// Parse an EDTF value from the start of `input`, advancing past it.
+///
+/// Unlike [`Edtf::from_str`](std::str::FromStr::from_str), trailing input is
+/// left in place rather than rejected. Error positions are byte offsets
+/// into the original `input`.
+pub fn parse(input: &mut &str) -> Result<Edtf, EdtfError> {
+    if input.is_empty() {
+        return Err(EdtfError::EmptyInput);
+    }
+    let original = *input;
+    parse_edtf
+        .parse_next(input)
+        .map_err(|err| EdtfError::from_winnow(&err, original.len() - input.len()))
+}
+
+fn parse_edtf(input: &mut &str) -> Result<Edtf, ErrMode<ContextError>> {
+    if input.starts_with('[') {
+        return Ok(Edtf::OneOfSet(parse_set(input, '[', ']')?));
+    }
+    if input.starts_with('{') {
+        return Ok(Edtf::AllOfSet(parse_set(input, '{', '}')?));
+    }
+
+    let start_kind = if input.starts_with("../") {
+        Some(EndpointKind::Open)
+    } else if input.starts_with('/') {
+        Some(EndpointKind::Unknown)
+    } else {
+        None
+    };
+    if let Some(start_kind) = start_kind {
+        let _ = opt("..").parse_next(input)?;
+        let _ = '/'.parse_next(input)?;
+        let end = parse_date.parse_next(input)?;
+        return Ok(Edtf::IntervalTo { end, start_kind });
     }
 
     let start_date = parse_date.parse_next(input)?;
@@ -399,10 +975,16 @@ pub fn parse(input: &mut &str) -> Result<Edtf, ErrMode<ContextError>> {
     if input.starts_with('/') {
         let _ = '/'.parse_next(input)?;
         if input.is_empty() || *input == ".." {
-            if *input == ".." {
+            let end_kind = if input.is_empty() {
+                EndpointKind::Unknown
+            } else {
                 let _ = "..".parse_next(input)?;
-            }
-            Ok(Edtf::IntervalFrom(start_date))
+                EndpointKind::Open
+            };
+            Ok(Edtf::IntervalFrom {
+                start: start_date,
+                end_kind,
+            })
         } else {
             let end_date = parse_date.parse_next(input)?;
             Ok(Edtf::Interval(Interval {
@@ -415,6 +997,107 @@ pub fn parse(input: &mut &str) -> Result<Edtf, ErrMode<ContextError>> {
     }
 }
 
+/// An error from parsing an EDTF string.
+///
+/// Positions are byte offsets into the input, pointing at the start of the
+/// offending component.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EdtfError {
+    /// The input was empty.
+    EmptyInput,
+    /// A year was missing or malformed (e.g., "20x4").
+    InvalidYear { position: usize },
+    /// A month or season was malformed (e.g., "2004-13").
+    InvalidMonth { position: usize },
+    /// A day was malformed (e.g., "2004-06-32").
+    InvalidDay { position: usize },
+    /// A time was malformed (e.g., "2004-06-11T25").
+    InvalidTime { position: usize },
+    /// A set was not closed or had an empty member (e.g., "[1667,").
+    InvalidSet { position: usize },
+    /// A complete value was followed by other input (e.g., "2004garbage").
+    UnexpectedTrailing { position: usize },
+}
+
+impl EdtfError {
+    /// The byte offset of the error, if it has one.
+    pub fn position(&self) -> Option<usize> {
+        match self {
+            EdtfError::EmptyInput => None,
+            EdtfError::InvalidYear { position }
+            | EdtfError::InvalidMonth { position }
+            | EdtfError::InvalidDay { position }
+            | EdtfError::InvalidTime { position }
+            | EdtfError::InvalidSet { position }
+            | EdtfError::UnexpectedTrailing { position } => Some(*position),
+        }
+    }
+
+    /// Classify a winnow error by the innermost component label.
+    fn from_winnow(err: &ErrMode<ContextError>, position: usize) -> Self {
+        let label = match err {
+            ErrMode::Backtrack(e) | ErrMode::Cut(e) => e.context().find_map(|c| match c {
+                StrContext::Label(label) => Some(*label),
+                _ => None,
+            }),
+            ErrMode::Incomplete(_) => None,
+        };
+        match label {
+            Some("month") => EdtfError::InvalidMonth { position },
+            Some("day") => EdtfError::InvalidDay { position },
+            Some("time") => EdtfError::InvalidTime { position },
+            Some("set") => EdtfError::InvalidSet { position },
+            _ => EdtfError::InvalidYear { position },
+        }
+    }
+}
+
+impl fmt::Display for EdtfError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let what = match self {
+            EdtfError::EmptyInput => return write!(f, "empty EDTF value"),
+            EdtfError::InvalidYear { .. } => "invalid year",
+            EdtfError::InvalidMonth { .. } => "invalid month",
+            EdtfError::InvalidDay { .. } => "invalid day",
+            EdtfError::InvalidTime { .. } => "invalid time",
+            EdtfError::InvalidSet { .. } => "unterminated set",
+            EdtfError::UnexpectedTrailing { .. } => "unexpected trailing input",
+        };
+        write!(
+            f,
+            "{} at byte {}",
+            what,
+            self.position().unwrap_or_default()
+        )
+    }
+}
+
+impl std::error::Error for EdtfError {}
+
+impl std::str::FromStr for Edtf {
+    type Err = EdtfError;
+
+    /// Parse a complete EDTF string, rejecting trailing input.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut input = s;
+        let edtf = parse(&mut input)?;
+        if !input.is_empty() {
+            return Err(EdtfError::UnexpectedTrailing {
+                position: s.len() - input.len(),
+            });
+        }
+        Ok(edtf)
+    }
+}
+
+impl TryFrom<&str> for Edtf {
+    type Error = EdtfError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -434,6 +1117,32 @@ mod tests {
         let res = parse_date(&mut input).unwrap();
         assert_eq!(res.year.value, 1990);
         assert_eq!(res.year.unspecified, UnspecifiedYear::One);
+        assert_eq!(res.year.precision, YearPrecision::Year);
+    }
+
+    #[test]
+    fn test_decade_and_century() {
+        let decade = parse_date(&mut "196X").unwrap();
+        assert_eq!(decade.year.value, 1960);
+        assert_eq!(decade.year.precision, YearPrecision::Decade);
+        assert_eq!(decade.year.unspecified, UnspecifiedYear::None);
+        assert_eq!(decade.to_string(), "196X");
+
+        let century = parse_date(&mut "19XX").unwrap();
+        assert_eq!(century.year.value, 1900);
+        assert_eq!(century.year.precision, YearPrecision::Century);
+        assert_eq!(century.to_string(), "19XX");
+
+        // Masks that do not end the year stay unspecified digits.
+        let unspecified = parse_date(&mut "1XXX").unwrap();
+        assert_eq!(unspecified.year.precision, YearPrecision::Year);
+        assert_eq!(unspecified.year.unspecified, UnspecifiedYear::Three);
+
+        let edtf: Edtf = "196X".parse().unwrap();
+        assert_eq!(edtf.to_string(), "196X");
+        assert!(edtf.contains(&parse_date(&mut "1969-12-31").unwrap()));
+        assert!(!edtf.contains(&parse_date(&mut "1970").unwrap()));
+        assert_eq!("199u".parse::<Edtf>().unwrap().to_string(), "199u");
     }
 
     #[test]
@@ -443,6 +1152,59 @@ mod tests {
         assert_eq!(res.year.value, 17000000002_i64);
     }
 
+    #[test]
+    fn test_overall_quality() {
+        let uncertain = Quality {
+            uncertain: true,
+            approximate: false,
+        };
+        let whole = parse_date(&mut "2004-06-11?").unwrap();
+        assert_eq!(whole.overall_quality(), Some(uncertain));
+        assert_eq!(whole.year_quality, uncertain);
+
+        let day_only = parse_date(&mut "2004-06-?11").unwrap();
+        assert_eq!(day_only.overall_quality(), None);
+        assert_eq!(day_only.year_quality, Quality::default());
+
+        let plain = parse_date(&mut "2004").unwrap();
+        assert_eq!(plain.overall_quality(), Some(Quality::default()));
+    }
+
+    #[test]
+    fn test_iter_years() {
+        let years = |input: &str| {
+            input
+                .parse::<Edtf>()
+                .unwrap()
+                .iter_years()
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(years("2004-06-11"), vec![2004]);
+        assert_eq!(years("196X"), (1960..=1969).collect::<Vec<_>>());
+        assert_eq!(years("2001-24"), vec![2001, 2002]);
+        assert_eq!(years("2001/2003-06"), vec![2001, 2002, 2003]);
+        assert_eq!(years("2001/.."), vec![2001]);
+        assert_eq!(
+            years("[1667,1668,1670..1672,1667]"),
+            vec![1667, 1668, 1670, 1671, 1672]
+        );
+        assert!(years("199u").is_empty());
+        assert!(years("Y17000000002").is_empty());
+        assert!(years("199u/2004").is_empty());
+    }
+
+    #[test]
+    fn test_covered_months() {
+        let months = |input: &str| parse_date(&mut &*input).unwrap().covered_months();
+        assert_eq!(months("2004-06-11"), vec![(2004, 6)]);
+        assert_eq!(months("2004-21"), vec![(2004, 3), (2004, 4), (2004, 5)]);
+        assert_eq!(months("2004-24"), vec![(2004, 12), (2005, 1), (2005, 2)]);
+        assert_eq!(months("2004").len(), 12);
+        assert_eq!(months("196X").len(), 120);
+        assert!(months("199u").is_empty());
+        assert!(months("Y17000000002").is_empty());
+    }
+
     #[test]
     fn test_unspecified_month_day() {
         let mut input = "2004-uu-uu";
@@ -453,17 +1215,25 @@ mod tests {
 
     #[test]
     fn test_component_quality() {
-        let mut input = "2004?-06-11";
-        let res = parse_date(&mut input).unwrap();
-        assert!(res.year_quality.uncertain);
-        assert!(!res.month_quality.uncertain);
-        assert!(!res.day_quality.uncertain);
+        let quality = |input: &str| {
+            let date = parse_date(&mut &*input).unwrap();
+            [date.year_quality, date.month_quality, date.day_quality].map(|q| q.uncertain)
+        };
+        // A right qualifier covers its component and those before it.
+        assert_eq!(quality("2004?-06-11"), [true, false, false]);
+        assert_eq!(quality("2004-06?-11"), [true, true, false]);
+        assert_eq!(quality("2004-06-11?"), [true, true, true]);
+        // A left qualifier covers its component only.
+        assert_eq!(quality("?2004-06-11"), [true, false, false]);
+        assert_eq!(quality("2004-?06-11"), [false, true, false]);
+        assert_eq!(quality("2004-06-?11"), [false, false, true]);
 
-        let mut input2 = "2004-06-11?";
-        let res2 = parse_date(&mut input2).unwrap();
-        assert!(!res2.year_quality.uncertain);
-        assert!(!res2.month_quality.uncertain);
-        assert!(res2.day_quality.uncertain);
+        let mixed = parse_date(&mut "2004?-06-~11").unwrap();
+        assert!(mixed.day_quality.approximate && !mixed.day_quality.uncertain);
+        assert_eq!(
+            parse_date(&mut "?2004-06-11").unwrap().to_string(),
+            "2004?-06-11"
+        );
     }
 
     #[test]
@@ -482,8 +1252,9 @@ mod tests {
     fn test_parse_interval_from() {
         let mut input = "2023-05/..";
         let res = parse(&mut input).unwrap();
-        if let Edtf::IntervalFrom(date) = res {
-            assert_eq!(date.year.value, 2023);
+        if let Edtf::IntervalFrom { start, end_kind } = res {
+            assert_eq!(start.year.value, 2023);
+            assert_eq!(end_kind, EndpointKind::Open);
         } else {
             panic!("Expected IntervalFrom");
         }
@@ -497,10 +1268,31 @@ mod tests {
             "2004-uu-uu",
             "2004?-06-11",
             "2004-06-11?",
+            "2004-06-11~",
+            "2004-06%",
+            "2004-?06-11",
+            "2004-06~-?11",
+            "2004?-06-%11",
             "2023-05/2024-06",
             "2023-05/..",
+            "2023-05/",
             "../2023-05",
+            "/2023-05",
             "Y17000000002",
+            "2004-06-11T10:30:00",
+            "2004-06-11T10:30",
+            "2004-06-11T10",
+            "2004-06-11T10Z",
+            "2004-06-11T10:30-05:00",
+            "2004-06-11T10:30:00Z",
+            "2004-06-11T10:30:00+04:00",
+            "2004-06-11T10:30:00-05:00",
+            "2004-06-11T10:30:00+05:30/2004-06-12",
+            "[1667,1668,1670..1672]",
+            "{1960,1961,1962}",
+            "[..1760-12-03]",
+            "[1760-12..]",
+            "{1667,1760-12..}",
         ];
         for case in cases {
             let mut input = case;
@@ -508,4 +1300,239 @@ mod tests {
             assert_eq!(res.to_string(), case);
         }
     }
+
+    #[test]
+    fn test_parse_sets() {
+        let mut input = "[1667,1668,1670..1672]";
+        let Edtf::OneOfSet(members) = parse(&mut input).unwrap() else {
+            panic!("Expected OneOfSet");
+        };
+        assert_eq!(members.len(), 3);
+        assert_eq!(members[0], SetMember::Date(date("1667")));
+        assert_eq!(
+            members[2],
+            SetMember::Range(Interval {
+                start: date("1670"),
+                end: date("1672"),
+            })
+        );
+
+        let mut input = "{1960,1961,1962}";
+        let Edtf::AllOfSet(members) = parse(&mut input).unwrap() else {
+            panic!("Expected AllOfSet");
+        };
+        assert_eq!(members.len(), 3);
+
+        let mut input = "[..1760-12-03]";
+        assert_eq!(
+            parse(&mut input).unwrap(),
+            Edtf::OneOfSet(vec![SetMember::Earlier(date("1760-12-03"))])
+        );
+
+        let mut input = "[1760-12..]";
+        assert_eq!(
+            parse(&mut input).unwrap(),
+            Edtf::OneOfSet(vec![SetMember::Later(date("1760-12"))])
+        );
+
+        assert!(parse(&mut "[]").is_err());
+        assert!(parse(&mut "[1667,1668").is_err());
+    }
+
+    #[test]
+    fn test_from_str() {
+        let parsed: Edtf = "2004-06-11".parse().unwrap();
+        assert_eq!(parsed, edtf("2004-06-11"));
+        let parsed: Edtf = "[1667,1670..1672]".try_into().unwrap();
+        assert_eq!(parsed.to_string(), "[1667,1670..1672]");
+
+        assert_eq!(
+            "2004-06-11garbage".parse::<Edtf>(),
+            Err(EdtfError::UnexpectedTrailing { position: 10 })
+        );
+        assert!("".parse::<Edtf>().is_err());
+        assert!(Edtf::try_from("June 2004").is_err());
+    }
+
+    #[test]
+    fn test_reduced_precision_times() {
+        let time = |input: &str| date(input).time.unwrap();
+        let full = time("2004-06-11T10:30:15");
+        assert_eq!(
+            (full.hour, full.minute, full.second),
+            (10, Some(30), Some(15))
+        );
+        let minutes = time("2004-06-11T10:30");
+        assert_eq!(
+            (minutes.hour, minutes.minute, minutes.second),
+            (10, Some(30), None)
+        );
+        let hours = time("2004-06-11T10");
+        assert_eq!((hours.hour, hours.minute, hours.second), (10, None, None));
+
+        assert!(edtf("2004-06-11T10").contains(&date("2004-06-11T10:59:59")));
+        assert!(!edtf("2004-06-11T10").contains(&date("2004-06-11T11:00")));
+    }
+
+    #[test]
+    fn test_time_zones() {
+        let tz = |input: &str| date(input).time.unwrap().tz;
+        assert_eq!(tz("2004-06-11T10:30:00"), None);
+        assert_eq!(tz("2004-06-11T10:30:00Z"), Some(TzOffset::Utc));
+        assert_eq!(
+            tz("2004-06-11T10:30:00+04:00"),
+            Some(TzOffset::Offset { minutes: 240 })
+        );
+        assert_eq!(
+            tz("2004-06-11T10:30:00-05:00"),
+            Some(TzOffset::Offset { minutes: -300 })
+        );
+        assert_eq!(
+            tz("2004-06-11T10:30:00-00:30"),
+            Some(TzOffset::Offset { minutes: -30 })
+        );
+        for input in ["2004-06-11T10:30:00-00:30", "2004-06-11T10:30:00+05:45"] {
+            assert_eq!(input.parse::<Edtf>().unwrap().to_string(), input);
+        }
+        assert_eq!(
+            "2004-06-11T10:30:00+4".parse::<Edtf>(),
+            Err(EdtfError::InvalidTime { position: 11 })
+        );
+    }
+
+    #[test]
+    fn test_error_positions() {
+        let error = |input: &str| input.parse::<Edtf>().unwrap_err();
+        assert_eq!(error(""), EdtfError::EmptyInput);
+        assert_eq!(error("20x4"), EdtfError::InvalidYear { position: 0 });
+        assert_eq!(error("2004-13"), EdtfError::InvalidMonth { position: 5 });
+        assert_eq!(error("2004-06-32"), EdtfError::InvalidDay { position: 8 });
+        assert_eq!(
+            error("2004-06-11T25:00"),
+            EdtfError::InvalidTime { position: 11 }
+        );
+        assert_eq!(error("2004/20x5"), EdtfError::InvalidYear { position: 5 });
+        assert_eq!(
+            error("[1667,1670-13]"),
+            EdtfError::InvalidMonth { position: 11 }
+        );
+        assert_eq!(error("[1667,1668"), EdtfError::InvalidSet { position: 10 });
+        assert_eq!(error("2004-06-32").to_string(), "invalid day at byte 8");
+    }
+
+    #[test]
+    fn test_chronological_sort() {
+        let mut values: Vec<Edtf> = [
+            "2004-06-11",
+            "2004-23",
+            "2004-uu",
+            "2004",
+            "199u",
+            "2004-03",
+            "[2001,1999..2000]",
+            "2003/2005",
+            "../2002",
+            "-0300",
+        ]
+        .iter()
+        .map(|s| edtf(s))
+        .collect();
+        values.sort_by_key(Edtf::sort_key);
+        let sorted: Vec<String> = values.iter().map(ToString::to_string).collect();
+        assert_eq!(
+            sorted,
+            [
+                "-0300",
+                "199u",
+                "[2001,1999..2000]",
+                "../2002",
+                "2003/2005",
+                "2004-uu",
+                "2004",
+                "2004-03",
+                "2004-06-11",
+                "2004-23",
+            ]
+        );
+        assert_eq!(date("2004-23").sort_key(), (2004, 9, 0));
+        assert_eq!(date("2004~").sort_key(), date("2004").sort_key());
+    }
+
+    #[test]
+    fn test_set_queries() {
+        let set = edtf("[1667,1670..1672]");
+        assert!(!set.contains(&date("1668")));
+        assert!(set.contains(&date("1671")));
+        assert!(!set.contains(&date("1673")));
+        assert!(set.overlaps(&edtf("1672-06")));
+        assert!(!set.overlaps(&edtf("1668/1669")));
+        assert!(edtf("[..1760-12-03]").contains(&date("1066")));
+        assert!(!edtf("[1760-12..]").overlaps(&edtf("../1760-11")));
+    }
+
+    fn date(input: &str) -> Date {
+        parse_date(&mut &*input).unwrap()
+    }
+
+    fn edtf(input: &str) -> Edtf {
+        parse(&mut &*input).unwrap()
+    }
+
+    #[test]
+    fn test_interval_contains() {
+        let interval = Interval {
+            start: date("2020"),
+            end: date("2021"),
+        };
+        assert!(interval.contains(&date("2020-01-01")));
+        assert!(interval.contains(&date("2021-12")));
+        assert!(interval.contains(&date("2021")));
+        assert!(!interval.contains(&date("2019-12-31")));
+        assert!(!interval.contains(&date("2022")));
+
+        let interval = Interval {
+            start: date("2020-06"),
+            end: date("2021"),
+        };
+        assert!(!interval.contains(&date("2020")));
+        assert!(interval.contains(&date("2020-22")));
+        assert!(!interval.contains(&date("2020-21")));
+        assert!(!interval.contains(&date("2021-24")));
+    }
+
+    #[test]
+    fn test_interval_overlaps() {
+        let interval = Interval {
+            start: date("2020-03"),
+            end: date("2020-09"),
+        };
+        let overlapping = Interval {
+            start: date("2020-09-30"),
+            end: date("2021"),
+        };
+        let disjoint = Interval {
+            start: date("2020-10"),
+            end: date("2021"),
+        };
+        assert!(interval.overlaps(&overlapping));
+        assert!(overlapping.overlaps(&interval));
+        assert!(!interval.overlaps(&disjoint));
+        assert!(!disjoint.overlaps(&interval));
+    }
+
+    #[test]
+    fn test_open_interval_queries() {
+        let from = edtf("2020/..");
+        let to = edtf("../2020");
+        assert!(from.contains(&date("9999")));
+        assert!(!from.contains(&date("2019")));
+        assert!(to.contains(&date("1066")));
+        assert!(!to.contains(&date("2021")));
+
+        assert!(from.overlaps(&to));
+        assert!(from.overlaps(&edtf("2019/2020-02")));
+        assert!(!from.overlaps(&edtf("../2019")));
+        assert!(edtf("2020/..").overlaps(&edtf("2030/..")));
+        assert!(edtf("199u").overlaps(&edtf("1995-05")));
+    }
 }
@@ -0,0 +1,141 @@
+//! Conversions between EDTF dates and [`chrono::NaiveDate`].
+//!
+//! Only fully specified year-month-day dates convert to a `NaiveDate`. Times
+//! and the uncertain/approximate qualities are dropped in that direction.
+
+use crate::{Date, Day, MonthOrSeason, Quality, UnspecifiedYear, Year, YearPrecision};
+use chrono::{Datelike, NaiveDate};
+use std::fmt;
+
+/// Why an EDTF date has no `NaiveDate` equivalent.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum NaiveDateError {
+    /// The month or day is missing (e.g., "2004" or "2004-06").
+    Incomplete,
+    /// The date names a season rather than a month (e.g., "2004-21").
+    Season,
+    /// A component is unspecified ("199u", "2004-uu-01") or the year is a
+    /// decade or century ("196X").
+    Unspecified,
+    /// The year is outside the range `NaiveDate` supports.
+    YearOutOfRange,
+    /// The day does not exist in the month (e.g., "2023-02-30").
+    InvalidDate,
+}
+
+impl fmt::Display for NaiveDateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self {
+            NaiveDateError::Incomplete => "date has no month or day",
+            NaiveDateError::Season => "date names a season",
+            NaiveDateError::Unspecified => "date has unspecified components",
+            NaiveDateError::YearOutOfRange => "year is out of range",
+            NaiveDateError::InvalidDate => "day does not exist in the month",
+        };
+        write!(f, "{}", message)
+    }
+}
+
+impl std::error::Error for NaiveDateError {}
+
+impl TryFrom<&Date> for NaiveDate {
+    type Error = NaiveDateError;
+
+    /// Convert a fully specified year-month-day date, dropping any time.
+    fn try_from(date: &Date) -> Result<Self, Self::Error> {
+        if date.year.unspecified != UnspecifiedYear::None
+            || date.year.precision != YearPrecision::Year
+        {
+            return Err(NaiveDateError::Unspecified);
+        }
+        let month = match date.month_or_season {
+            Some(MonthOrSeason::Month(month)) => month,
+            Some(MonthOrSeason::Unspecified) => return Err(NaiveDateError::Unspecified),
+            Some(_) => return Err(NaiveDateError::Season),
+            None => return Err(NaiveDateError::Incomplete),
+        };
+        let day = match date.day {
+            Some(Day::Day(day)) => day,
+            Some(Day::Unspecified) => return Err(NaiveDateError::Unspecified),
+            None => return Err(NaiveDateError::Incomplete),
+        };
+        let year = i32::try_from(date.year.value)
+            .ok()
+            .filter(|year| (NaiveDate::MIN.year()..=NaiveDate::MAX.year()).contains(year))
+            .ok_or(NaiveDateError::YearOutOfRange)?;
+        NaiveDate::from_ymd_opt(year, month, day).ok_or(NaiveDateError::InvalidDate)
+    }
+}
+
+impl From<NaiveDate> for Date {
+    fn from(date: NaiveDate) -> Self {
+        Date {
+            year: Year {
+                value: i64::from(date.year()),
+                unspecified: UnspecifiedYear::None,
+                precision: YearPrecision::Year,
+            },
+            year_quality: Quality::default(),
+            month_or_season: Some(MonthOrSeason::Month(date.month())),
+            month_quality: Quality::default(),
+            day: Some(Day::Day(date.day())),
+            day_quality: Quality::default(),
+            time: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Edtf;
+
+    fn date(input: &str) -> Date {
+        match input.parse::<Edtf>().unwrap() {
+            Edtf::Date(date) => date,
+            other => panic!("expected a date, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_to_naive_date() {
+        assert_eq!(
+            NaiveDate::try_from(&date("2004-06-11T10:30:00Z")),
+            Ok(NaiveDate::from_ymd_opt(2004, 6, 11).unwrap())
+        );
+        assert_eq!(
+            NaiveDate::try_from(&date("2004-06")),
+            Err(NaiveDateError::Incomplete)
+        );
+        assert_eq!(
+            NaiveDate::try_from(&date("2004-21")),
+            Err(NaiveDateError::Season)
+        );
+        assert_eq!(
+            NaiveDate::try_from(&date("199u-06-11")),
+            Err(NaiveDateError::Unspecified)
+        );
+        assert_eq!(
+            NaiveDate::try_from(&date("2004-06-uu")),
+            Err(NaiveDateError::Unspecified)
+        );
+        let mut distant = date("2004-06-11");
+        distant.year.value = 17_000_000_002;
+        assert_eq!(
+            NaiveDate::try_from(&distant),
+            Err(NaiveDateError::YearOutOfRange)
+        );
+        assert_eq!(
+            NaiveDate::try_from(&date("2023-02-30")),
+            Err(NaiveDateError::InvalidDate)
+        );
+    }
+
+    #[test]
+    fn test_from_naive_date() {
+        let naive = NaiveDate::from_ymd_opt(-44, 3, 15).unwrap();
+        let edtf = Date::from(naive);
+        assert_eq!(edtf.to_string(), "-0044-03-15");
+        assert_eq!(NaiveDate::try_from(&edtf), Ok(naive));
+    }
+}
@@ -1067,15 +1067,11 @@ fn move_group_wrap_to_citation_items(
 
 fn apply_wrap_to_component(component: &mut TemplateComponent, wrap: WrapPunctuation) {
     match component {
-        TemplateComponent::Number(n) => {
-            if n.rendering.wrap.is_none() {
-                n.rendering.wrap = Some(wrap);
-            }
+        TemplateComponent::Number(n) if n.rendering.wrap.is_none() => {
+            n.rendering.wrap = Some(wrap);
         }
-        TemplateComponent::List(list) => {
-            if list.rendering.wrap.is_none() {
-                list.rendering.wrap = Some(wrap);
-            }
+        TemplateComponent::List(list) if list.rendering.wrap.is_none() => {
+            list.rendering.wrap = Some(wrap);
         }
         _ => {}
     }
@@ -1238,16 +1234,14 @@ fn normalize_contributor_form_to_short(template: &mut [TemplateComponent]) -> bo
     let mut changed = false;
     for component in template {
         match component {
-            TemplateComponent::Contributor(c) => {
-                if c.form == csln_core::template::ContributorForm::Long {
-                    c.form = csln_core::template::ContributorForm::Short;
-                    changed = true;
-                }
+            TemplateComponent::Contributor(c)
+                if c.form == csln_core::template::ContributorForm::Long =>
+            {
+                c.form = csln_core::template::ContributorForm::Short;
+                changed = true;
             }
             TemplateComponent::List(list) => {
-                if normalize_contributor_form_to_short(&mut list.items) {
-                    changed = true;
-                }
+                changed |= normalize_contributor_form_to_short(&mut list.items);
             }
             _ => {}
         }
@@ -1,6 +1,7 @@
 use csl_legacy::model::{CslNode, Style};
 use csln_core::options::{
-    Disambiguation, Group, Processing, ProcessingCustom, Sort, SortKey, SortSpec,
+    Disambiguation, GivennameDisambiguationRule, Group, Processing, ProcessingCustom, Sort,
+    SortKey, SortSpec,
 };
 use std::collections::HashSet;
 
@@ -51,6 +52,19 @@ pub fn detect_processing_mode(style: &Style) -> Option<Processing> {
             // Author-date styles commonly rely on year suffixes; keep this true
             // unless legacy style explicitly disables it.
             year_suffix: style.citation.disambiguate_add_year_suffix.unwrap_or(true),
+            strategy: None,
+            givenname_rule: match style.citation.givenname_disambiguation_rule.as_deref() {
+                Some("all-names") => Some(GivennameDisambiguationRule::AllNames),
+                Some("all-names-with-initials") => {
+                    Some(GivennameDisambiguationRule::AllNamesWithInitials)
+                }
+                Some("primary-name") => Some(GivennameDisambiguationRule::PrimaryName),
+                Some("primary-name-with-initials") => {
+                    Some(GivennameDisambiguationRule::PrimaryNameWithInitials)
+                }
+                // "by-cite" is the default.
+                _ => None,
+            },
         };
 
         let sort = style.citation.sort.as_ref().and_then(extract_sort);
@@ -163,10 +177,8 @@ fn extract_group_from_sort(sort: &Sort) -> Option<Group> {
 
     for spec in &sort.template {
         match spec.key {
-            SortKey::Author | SortKey::Year | SortKey::Title => {
-                if !keys.contains(&spec.key) {
-                    keys.push(spec.key.clone());
-                }
+            SortKey::Author | SortKey::Year | SortKey::Title if !keys.contains(&spec.key) => {
+                keys.push(spec.key.clone());
             }
             SortKey::CitationNumber => {}
             _ => {}
@@ -83,7 +83,7 @@ fn test_extract_substitute_pattern() {
 #[test]
 fn test_extract_processing_sort_and_disambiguation() {
     let xml = r#"<style class="in-text">
-        <citation disambiguate-add-year-suffix="false" disambiguate-add-names="true" disambiguate-add-givenname="true">
+        <citation disambiguate-add-year-suffix="false" disambiguate-add-names="true" disambiguate-add-givenname="true" givenname-disambiguation-rule="primary-name">
             <sort>
                 <key macro="author"/>
                 <key variable="issued"/>
@@ -104,6 +104,10 @@ fn test_extract_processing_sort_and_disambiguation() {
     assert!(!disamb.year_suffix);
     assert!(disamb.names);
     assert!(disamb.add_givenname);
+    assert_eq!(
+        disamb.givenname_rule,
+        Some(csln_core::options::GivennameDisambiguationRule::PrimaryName)
+    );
 
     let sort = custom.sort.unwrap();
     assert_eq!(sort.template.len(), 3);
@@ -141,6 +145,7 @@ fn test_extract_processing_disambiguation_defaults() {
     assert!(!disamb.names);
     assert!(!disamb.add_givenname);
     assert!(disamb.year_suffix);
+    assert_eq!(disamb.givenname_rule, None);
 }
 
 #[test]
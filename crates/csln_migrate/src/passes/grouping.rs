@@ -184,10 +184,8 @@ pub fn find_issue_in_components(components: &[TemplateComponent]) -> bool {
             TemplateComponent::Number(n) if n.number == NumberVariable::Issue => {
                 return true;
             }
-            TemplateComponent::List(list) => {
-                if find_issue_in_components(&list.items) {
-                    return true;
-                }
+            TemplateComponent::List(list) if find_issue_in_components(&list.items) => {
+                return true;
             }
             _ => {}
         }
@@ -243,10 +241,8 @@ pub fn find_volume_in_list(list: &TemplateList) -> Option<()> {
             TemplateComponent::Number(n) if n.number == NumberVariable::Volume => {
                 return Some(());
             }
-            TemplateComponent::List(inner_list) => {
-                if find_volume_in_list(inner_list).is_some() {
-                    return Some(());
-                }
+            TemplateComponent::List(inner_list) if find_volume_in_list(inner_list).is_some() => {
+                return Some(());
             }
             _ => {}
         }
@@ -1437,7 +1437,7 @@ impl TemplateCompiler {
 
         let form = match names.options.mode {
             Some(csln_core::NameMode::Short) => ContributorForm::Short,
-            Some(csln_core::NameMode::Count) => ContributorForm::Short, // Map count to short
+            Some(csln_core::NameMode::Count) => ContributorForm::Count,
             _ => ContributorForm::Long,
         };
 
@@ -1451,7 +1451,18 @@ impl TemplateCompiler {
                 min: et.min,
                 use_first: et.use_first,
                 use_last: None, // Legacy CSL 1.0 et-al doesn't have use_last
-                and_others: csln_core::options::AndOtherOptions::EtAl,
+                // The term itself comes from the locale at render time.
+                and_others: match et.term.as_deref() {
+                    Some("and others") => csln_core::options::AndOtherOptions::Text,
+                    _ => csln_core::options::AndOtherOptions::EtAl,
+                },
+                term: None,
+                subsequent: et.subsequent.as_ref().map(|subsequent| {
+                    csln_core::options::ShortenSubsequent {
+                        min: subsequent.min,
+                        use_first: subsequent.use_first,
+                    }
+                }),
                 delimiter_precedes_last: match names.options.delimiter_precedes_last {
                     Some(csln_core::DelimiterPrecedes::Always) => {
                         csln_core::options::DelimiterPrecedesLast::Always
@@ -1464,6 +1475,7 @@ impl TemplateCompiler {
                     }
                     _ => csln_core::options::DelimiterPrecedesLast::Contextual,
                 },
+                et_al_emph: false,
             }
         });
 
@@ -1659,6 +1671,7 @@ impl TemplateCompiler {
             Variable::Title => Some(TitleType::Primary),
             Variable::ContainerTitle => Some(TitleType::ParentSerial),
             Variable::CollectionTitle => Some(TitleType::ParentMonograph),
+            Variable::ReviewedTitle => Some(TitleType::Reviewed),
             _ => None,
         }
     }
@@ -1672,7 +1685,9 @@ impl TemplateCompiler {
             Variable::Edition => Some(NumberVariable::Edition),
             Variable::ChapterNumber => Some(NumberVariable::ChapterNumber),
             Variable::NumberOfVolumes => Some(NumberVariable::NumberOfVolumes),
+            Variable::NumberOfPages => Some(NumberVariable::NumberOfPages),
             Variable::CitationNumber => Some(NumberVariable::CitationNumber),
+            Variable::Section => Some(NumberVariable::Section),
             _ => None,
         }
     }
@@ -1684,9 +1699,22 @@ impl TemplateCompiler {
             Variable::ISBN => Some(SimpleVariable::Isbn),
             Variable::ISSN => Some(SimpleVariable::Issn),
             Variable::URL => Some(SimpleVariable::Url),
+            Variable::PMID => Some(SimpleVariable::Pmid),
+            Variable::PMCID => Some(SimpleVariable::Pmcid),
             Variable::Publisher => Some(SimpleVariable::Publisher),
             Variable::PublisherPlace => Some(SimpleVariable::PublisherPlace),
+            Variable::OriginalPublisher => Some(SimpleVariable::OriginalPublisher),
+            Variable::OriginalPublisherPlace => Some(SimpleVariable::OriginalPublisherPlace),
             Variable::Genre => Some(SimpleVariable::Genre),
+            Variable::Archive => Some(SimpleVariable::Archive),
+            Variable::ArchiveLocation => Some(SimpleVariable::ArchiveLocation),
+            Variable::ArchivePlace => Some(SimpleVariable::ArchivePlace),
+            Variable::CallNumber => Some(SimpleVariable::CallNumber),
+            Variable::Scale => Some(SimpleVariable::Scale),
+            Variable::Dimensions => Some(SimpleVariable::Dimensions),
+            Variable::Source => Some(SimpleVariable::Source),
+            Variable::Authority => Some(SimpleVariable::Authority),
+            Variable::Status => Some(SimpleVariable::Status),
             _ => None,
         }
     }
@@ -1745,6 +1773,7 @@ impl TemplateCompiler {
             suppress: None,
             initialize_with: None,
             strip_periods: fmt.strip_periods,
+            position: None,
         }
     }
 
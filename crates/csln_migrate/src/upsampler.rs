@@ -155,7 +155,7 @@ impl Upsampler {
                 None
             };
 
-        let mut et_al_term = "et al.".to_string();
+        let mut et_al_term = None;
         let et_al_formatting = FormattingOptions::default();
 
         for child in &n.children {
@@ -211,9 +211,7 @@ impl Upsampler {
                     });
                 }
                 LNode::EtAl(et_al) => {
-                    if let Some(term) = &et_al.term {
-                        et_al_term = term.clone();
-                    }
+                    et_al_term = et_al.term.clone();
                     // Formatting from et-al node? Legacy model needs to capture it.
                     // For now, default.
                 }
@@ -504,6 +502,8 @@ impl Upsampler {
             "original-title" => Some(Variable::OriginalTitle),
             "publisher" => Some(Variable::Publisher),
             "publisher-place" => Some(Variable::PublisherPlace),
+            "original-publisher" => Some(Variable::OriginalPublisher),
+            "original-publisher-place" => Some(Variable::OriginalPublisherPlace),
             "archive" => Some(Variable::Archive),
             "archive-place" => Some(Variable::ArchivePlace),
             "archive_location" => Some(Variable::ArchiveLocation),
@@ -514,10 +514,12 @@ impl Upsampler {
             "version" => Some(Variable::Version),
             "volume" => Some(Variable::Volume),
             "number-of-volumes" => Some(Variable::NumberOfVolumes),
+            "number-of-pages" => Some(Variable::NumberOfPages),
             "issue" => Some(Variable::Issue),
             "chapter-number" => Some(Variable::ChapterNumber),
             "medium" => Some(Variable::Medium),
             "status" => Some(Variable::Status),
+            "authority" => Some(Variable::Authority),
             "edition" => Some(Variable::Edition),
             "section" => Some(Variable::Section),
             "source" => Some(Variable::Source),
@@ -556,7 +558,7 @@ impl Upsampler {
             "issued" => Some(Variable::Issued),
             "event-date" => Some(Variable::EventDate),
             "accessed" => Some(Variable::Accessed),
-            "container" => Some(Variable::Submitted),
+            "submitted" => Some(Variable::Submitted),
             "original-date" => Some(Variable::OriginalDate),
             "available-date" => Some(Variable::AvailableDate),
             _ => None,
@@ -54,6 +54,7 @@ fn make_style_with_date(form: Option<String>) -> Style {
             disambiguate_add_year_suffix: None,
             disambiguate_add_names: None,
             disambiguate_add_givenname: None,
+            givenname_disambiguation_rule: None,
         },
         bibliography: None,
     }
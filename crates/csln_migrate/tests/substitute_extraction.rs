@@ -115,6 +115,7 @@ fn test_extract_type_conditional_substitute() {
             disambiguate_add_year_suffix: None,
             disambiguate_add_names: None,
             disambiguate_add_givenname: None,
+            givenname_disambiguation_rule: None,
         },
         bibliography: None,
     };
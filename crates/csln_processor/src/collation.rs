@@ -0,0 +1,60 @@
+/*
+SPDX-License-Identifier: MPL-2.0
+SPDX-FileCopyrightText: © 2023-2026 Bruce D'Arcus
+*/
+
+//! Locale-aware string comparison for sorting.
+//!
+//! With the `icu` feature, strings are compared with the ICU collator for
+//! the active locale (e.g., Swedish sorts "Å" after "Z"). Without it, or
+//! when the locale has no collation data, strings are compared ignoring
+//! diacritics first, so "Ångström" sorts with the other "A" names.
+
+use std::cmp::Ordering;
+use unicode_normalization::UnicodeNormalization;
+use unicode_normalization::char::is_combining_mark;
+
+/// Compares sort keys according to a locale's collation rules.
+pub(crate) struct Collator {
+    #[cfg(feature = "icu")]
+    icu: Option<icu_collator::Collator>,
+}
+
+impl Collator {
+    /// Create a collator for a BCP 47 locale tag (e.g., "sv-SE").
+    #[cfg(feature = "icu")]
+    pub(crate) fn new(locale: &str) -> Self {
+        let options = icu_collator::CollatorOptions::new();
+        let icu = locale
+            .parse::<icu_locid::Locale>()
+            .ok()
+            .and_then(|l| icu_collator::Collator::try_new(&l.into(), options).ok())
+            .or_else(|| {
+                icu_collator::Collator::try_new(&icu_locid::Locale::UND.into(), options).ok()
+            });
+        Self { icu }
+    }
+
+    /// Create a collator for a BCP 47 locale tag (e.g., "sv-SE").
+    #[cfg(not(feature = "icu"))]
+    pub(crate) fn new(_locale: &str) -> Self {
+        Self {}
+    }
+
+    /// Compare two strings in collation order.
+    pub(crate) fn compare(&self, a: &str, b: &str) -> Ordering {
+        #[cfg(feature = "icu")]
+        if let Some(icu) = &self.icu {
+            return icu.compare(a, b);
+        }
+        fold(a).cmp(&fold(b)).then_with(|| a.cmp(b))
+    }
+}
+
+/// Lowercase and strip diacritics, e.g., "Ångström" -> "angstrom".
+fn fold(s: &str) -> String {
+    s.nfd()
+        .filter(|c| !is_combining_mark(*c))
+        .flat_map(char::to_lowercase)
+        .collect()
+}
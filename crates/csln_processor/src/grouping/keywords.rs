@@ -0,0 +1,84 @@
+/*
+SPDX-License-Identifier: MPL-2.0
+SPDX-FileCopyrightText: © 2023-2026 Bruce D'Arcus
+*/
+
+//! Keyword-based bibliography sections.
+//!
+//! Derives a section label for each reference from its `keyword` field,
+//! either directly (first keyword) or through a style-defined mapping.
+
+use csln_core::grouping::KeywordGrouping;
+
+use crate::reference::Reference;
+
+/// Default heading for references without a matching keyword.
+pub const UNCATEGORIZED: &str = "Uncategorized";
+
+/// Normalize a keyword for comparison: trimmed, single-spaced, lowercase.
+pub fn normalize_keyword(keyword: &str) -> String {
+    keyword
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+/// Resolve the section label for a reference, or `None` if uncategorized.
+pub fn keyword_label(reference: &Reference, grouping: &KeywordGrouping) -> Option<String> {
+    let keywords = reference.keywords()?;
+    match &grouping.mapping {
+        Some(mapping) => keywords.iter().find_map(|keyword| {
+            let normalized = normalize_keyword(keyword);
+            mapping
+                .iter()
+                .find(|(key, _)| normalize_keyword(key) == normalized)
+                .map(|(_, label)| label.clone())
+        }),
+        None => keywords
+            .iter()
+            .map(|k| k.trim())
+            .find(|k| !k.is_empty())
+            .map(str::to_string),
+    }
+}
+
+/// Partition references into labeled sections.
+///
+/// Sections are ordered alphabetically by label (case-insensitively), with
+/// the uncategorized section last. Keywords that normalize to the same
+/// value share a section, labeled by the first spelling encountered.
+/// Reference order is preserved within each section.
+pub fn partition_by_keyword<'a>(
+    references: Vec<&'a Reference>,
+    grouping: &KeywordGrouping,
+) -> Vec<(String, Vec<&'a Reference>)> {
+    let mut sections: Vec<(String, Vec<&'a Reference>)> = Vec::new();
+    let mut uncategorized = Vec::new();
+
+    for reference in references {
+        match keyword_label(reference, grouping) {
+            Some(label) => {
+                let key = normalize_keyword(&label);
+                match sections
+                    .iter_mut()
+                    .find(|(existing, _)| normalize_keyword(existing) == key)
+                {
+                    Some((_, refs)) => refs.push(reference),
+                    None => sections.push((label, vec![reference])),
+                }
+            }
+            None => uncategorized.push(reference),
+        }
+    }
+
+    sections.sort_by_key(|(label, _)| normalize_keyword(label));
+    if !uncategorized.is_empty() {
+        let heading = grouping
+            .uncategorized
+            .clone()
+            .unwrap_or_else(|| UNCATEGORIZED.to_string());
+        sections.push((heading, uncategorized));
+    }
+    sections
+}
@@ -8,8 +8,10 @@ SPDX-FileCopyrightText: © 2023-2026 Bruce D'Arcus
 //! This module provides functionality for dividing bibliographies into
 //! labeled groups with distinct sorting rules.
 
+pub mod keywords;
 pub mod selector;
 pub mod sorting;
 
+pub use keywords::partition_by_keyword;
 pub use selector::SelectorEvaluator;
 pub use sorting::GroupSorter;
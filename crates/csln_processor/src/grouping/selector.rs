@@ -90,7 +90,9 @@ impl<'a> SelectorEvaluator<'a> {
 
     /// Match field value.
     ///
-    /// Currently supports matching against the `language` field.
+    /// Currently supports matching against the `language`, `note`, and
+    /// `keyword` fields. Keywords match if any of the reference's keywords
+    /// equals the expected value, ignoring case and extra whitespace.
     /// Future: extend to support arbitrary custom metadata fields.
     fn matches_field(
         &self,
@@ -107,7 +109,20 @@ impl<'a> SelectorEvaluator<'a> {
                 let note = reference.note().unwrap_or_default();
                 self.matches_field_value(&note, matcher)
             }
-            // Future: support for keywords, custom metadata
+            "keyword" | "keywords" => reference.keywords().is_some_and(|keywords| {
+                keywords.iter().any(|keyword| {
+                    let keyword = super::keywords::normalize_keyword(keyword);
+                    match matcher {
+                        FieldMatcher::Exact(expected) => {
+                            super::keywords::normalize_keyword(expected) == keyword
+                        }
+                        FieldMatcher::Multiple(values) => values
+                            .iter()
+                            .any(|v| super::keywords::normalize_keyword(v) == keyword),
+                    }
+                })
+            }),
+            // Future: support for custom metadata
             _ => false,
         }
     }
@@ -9,33 +9,78 @@ SPDX-FileCopyrightText: © 2023-2026 Bruce D'Arcus
 //! - Type-order sorting (explicit sequence like [legal-case, statute, treaty])
 //! - Name-order sorting (family-given vs given-family for multilingual bibliographies)
 //! - Integration with standard sort keys (author, title, issued)
+//! - Sorting by arbitrary reference variables
 
-use csln_core::grouping::{GroupSort, GroupSortKey, NameSortOrder, SortKey as GroupSortKeyType};
+use csln_core::grouping::{
+    EmptyAuthorSort, GroupSort, GroupSortKey, NameSortOrder, SortKey as GroupSortKeyType,
+};
 use csln_core::locale::Locale;
+use csln_core::options::DemoteNonDroppingParticle;
 
-use crate::reference::Reference;
+use crate::collation::Collator;
+use crate::reference::{Contributor, EdtfString, FlatName, NumOrStr, Reference};
+use std::cmp::Ordering;
 
 pub struct GroupSorter<'a> {
     locale: &'a Locale,
+    collator: Collator,
+    demote_particle: bool,
 }
 
 impl<'a> GroupSorter<'a> {
     pub fn new(locale: &'a Locale) -> Self {
-        Self { locale }
+        Self {
+            locale,
+            collator: Collator::new(&locale.locale),
+            demote_particle: true,
+        }
+    }
+
+    /// Set whether non-dropping particles are demoted in name sort keys.
+    ///
+    /// Follows the style's `demote-non-dropping-particle` option: only
+    /// `never` keeps the particle in front ("van Gogh" sorts under "V").
+    pub fn with_particle_demotion(mut self, demote: Option<DemoteNonDroppingParticle>) -> Self {
+        self.demote_particle = demote != Some(DemoteNonDroppingParticle::Never);
+        self
     }
 
     /// Sort references according to a group sort specification.
     ///
     /// Applies sort keys in order, with later keys acting as tiebreakers.
+    /// References that tie on every key are ordered by id, so the result
+    /// does not depend on input order.
     ///
     /// # Arguments
     ///
     /// * `references` - References to sort
     /// * `sort_spec` - Group sort specification
     pub fn sort_references<'b>(
+        &self,
+        references: Vec<&'b Reference>,
+        sort_spec: &GroupSort,
+    ) -> Vec<&'b Reference> {
+        self.sort_with_tiebreak(references, sort_spec, true)
+    }
+
+    /// Sort references like [`Self::sort_references`], but keep references
+    /// that tie on every key in their input order.
+    ///
+    /// Year-suffix assignment uses this so that ties follow bibliography
+    /// order.
+    pub fn sort_references_stable<'b>(
+        &self,
+        references: Vec<&'b Reference>,
+        sort_spec: &GroupSort,
+    ) -> Vec<&'b Reference> {
+        self.sort_with_tiebreak(references, sort_spec, false)
+    }
+
+    fn sort_with_tiebreak<'b>(
         &self,
         mut references: Vec<&'b Reference>,
         sort_spec: &GroupSort,
+        by_id: bool,
     ) -> Vec<&'b Reference> {
         let author_fallback_to_title = sort_spec
             .template
@@ -44,25 +89,37 @@ impl<'a> GroupSorter<'a> {
 
         references.sort_by(|a, b| {
             for sort_key in &sort_spec.template {
-                let cmp =
-                    self.compare_by_key_with_context(a, b, sort_key, author_fallback_to_title);
-                if cmp != std::cmp::Ordering::Equal {
+                let cmp = self.compare_by_key_with_context(
+                    a,
+                    b,
+                    sort_key,
+                    author_fallback_to_title,
+                    true,
+                );
+                if cmp != Ordering::Equal {
                     return cmp;
                 }
             }
-            std::cmp::Ordering::Equal
+            if by_id {
+                a.id().cmp(&b.id())
+            } else {
+                Ordering::Equal
+            }
         });
         references
     }
 
     /// Compare two references by a single sort key.
+    ///
+    /// Author keys compare only the first family name, as citation sorts
+    /// on a rendered short name do.
     pub fn compare_by_key(
         &self,
         a: &Reference,
         b: &Reference,
         sort_key: &GroupSortKey,
-    ) -> std::cmp::Ordering {
-        self.compare_by_key_with_context(a, b, sort_key, true)
+    ) -> Ordering {
+        self.compare_by_key_with_context(a, b, sort_key, true, false)
     }
 
     fn compare_by_key_with_context(
@@ -71,7 +128,8 @@ impl<'a> GroupSorter<'a> {
         b: &Reference,
         sort_key: &GroupSortKey,
         author_fallback_to_title: bool,
-    ) -> std::cmp::Ordering {
+        all_names: bool,
+    ) -> Ordering {
         let cmp = match &sort_key.key {
             GroupSortKeyType::RefType => {
                 if let Some(order) = &sort_key.order {
@@ -83,22 +141,32 @@ impl<'a> GroupSorter<'a> {
                 }
             }
             GroupSortKeyType::Author => {
-                if let Some(name_order) = &sort_key.sort_order {
-                    // Name-order sorting: culturally appropriate collation
-                    self.compare_by_author_with_order(a, b, *name_order, author_fallback_to_title)
+                let author_fallback_to_title = match sort_key.empty_author {
+                    Some(EmptyAuthorSort::Title) => true,
+                    Some(EmptyAuthorSort::Last) => false,
+                    None => author_fallback_to_title,
+                };
+                // Name-order sorting: culturally appropriate collation,
+                // defaulting to family-given (Western convention)
+                let name_order = sort_key.sort_order.unwrap_or(NameSortOrder::FamilyGiven);
+                let cmp =
+                    self.compare_by_author_with_order(a, b, name_order, author_fallback_to_title);
+                if all_names {
+                    cmp.then_with(|| {
+                        self.compare_names(&Self::sort_names(a), &Self::sort_names(b), name_order)
+                    })
                 } else {
-                    // Default: family-given (Western convention)
-                    self.compare_by_author_with_order(
-                        a,
-                        b,
-                        NameSortOrder::FamilyGiven,
-                        author_fallback_to_title,
-                    )
+                    cmp
                 }
             }
             GroupSortKeyType::Title => self.compare_by_title(a, b),
-            GroupSortKeyType::Issued => self.compare_by_issued(a, b),
-            GroupSortKeyType::Field(field_name) => self.compare_by_field(a, b, field_name),
+            // Missing values stay last in either direction.
+            GroupSortKeyType::Issued => {
+                return self.compare_by_field(a, b, "issued", sort_key.ascending);
+            }
+            GroupSortKeyType::Field(field_name) => {
+                return self.compare_by_field(a, b, field_name, sort_key.ascending);
+            }
         };
 
         if sort_key.ascending {
@@ -112,12 +180,7 @@ impl<'a> GroupSorter<'a> {
     ///
     /// Types appear in the order specified, regardless of alphabetical content.
     /// Types not in the order list sort after those in the list, alphabetically.
-    fn compare_by_type_order(
-        &self,
-        a: &Reference,
-        b: &Reference,
-        order: &[String],
-    ) -> std::cmp::Ordering {
+    fn compare_by_type_order(&self, a: &Reference, b: &Reference, order: &[String]) -> Ordering {
         let a_type = a.ref_type();
         let b_type = b.ref_type();
 
@@ -126,9 +189,9 @@ impl<'a> GroupSorter<'a> {
 
         match (a_pos, b_pos) {
             (Some(a_idx), Some(b_idx)) => a_idx.cmp(&b_idx),
-            (Some(_), None) => std::cmp::Ordering::Less, // a in order, b not
-            (None, Some(_)) => std::cmp::Ordering::Greater, // b in order, a not
-            (None, None) => a_type.cmp(&b_type),         // both not in order, alphabetical
+            (Some(_), None) => Ordering::Less, // a in order, b not
+            (None, Some(_)) => Ordering::Greater, // b in order, a not
+            (None, None) => a_type.cmp(&b_type), // both not in order, alphabetical
         }
     }
 
@@ -139,14 +202,14 @@ impl<'a> GroupSorter<'a> {
         b: &Reference,
         name_order: NameSortOrder,
         fallback_to_title: bool,
-    ) -> std::cmp::Ordering {
+    ) -> Ordering {
         let a_key = self.extract_author_sort_key_opt(a, name_order, fallback_to_title);
         let b_key = self.extract_author_sort_key_opt(b, name_order, fallback_to_title);
         match (a_key, b_key) {
-            (Some(a), Some(b)) => a.cmp(&b),
-            (Some(_), None) => std::cmp::Ordering::Less,
-            (None, Some(_)) => std::cmp::Ordering::Greater,
-            (None, None) => std::cmp::Ordering::Equal,
+            (Some(a), Some(b)) => self.collator.compare(&a, &b),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
         }
     }
 
@@ -167,7 +230,7 @@ impl<'a> GroupSorter<'a> {
             .map(|name| match name_order {
                 NameSortOrder::FamilyGiven => {
                     // Western: "Smith, John" → sort by "smith"
-                    name.family_or_literal().to_lowercase()
+                    name.family_sort_key(self.demote_particle).to_lowercase()
                 }
                 NameSortOrder::GivenFamily => {
                     // Vietnamese: "Nguyễn Văn A" → sort by "nguyễn"
@@ -182,7 +245,7 @@ impl<'a> GroupSorter<'a> {
                 reference
                     .editor()
                     .and_then(|c| c.to_names_vec().first().cloned())
-                    .map(|name| name.family_or_literal().to_lowercase())
+                    .map(|name| name.family_sort_key(self.demote_particle).to_lowercase())
                     .filter(|key| !key.is_empty())
             })
             .or_else(|| {
@@ -199,6 +262,38 @@ impl<'a> GroupSorter<'a> {
             .filter(|key| !key.is_empty())
     }
 
+    /// The names an author key sorts by: the authors, or else the editors.
+    fn sort_names(reference: &Reference) -> Vec<FlatName> {
+        [reference.author(), reference.editor()]
+            .into_iter()
+            .flatten()
+            .map(|c| c.to_names_vec())
+            .find(|names| !names.is_empty())
+            .unwrap_or_default()
+    }
+
+    /// Compare two name lists name by name, family name before given name.
+    ///
+    /// A list that is a prefix of the other sorts first.
+    fn compare_names(&self, a: &[FlatName], b: &[FlatName], name_order: NameSortOrder) -> Ordering {
+        let family = |name: &FlatName| match name_order {
+            NameSortOrder::FamilyGiven => name.family_sort_key(self.demote_particle),
+            NameSortOrder::GivenFamily => name.family_or_literal().to_string(),
+        };
+        a.iter()
+            .zip(b)
+            .map(|(a, b)| {
+                self.collator.compare(&family(a), &family(b)).then_with(|| {
+                    self.collator.compare(
+                        a.given.as_deref().unwrap_or_default(),
+                        b.given.as_deref().unwrap_or_default(),
+                    )
+                })
+            })
+            .find(|cmp| *cmp != Ordering::Equal)
+            .unwrap_or_else(|| a.len().cmp(&b.len()))
+    }
+
     /// Public helper retained for tests/debugging.
     pub fn extract_author_sort_key(
         &self,
@@ -210,7 +305,7 @@ impl<'a> GroupSorter<'a> {
     }
 
     /// Compare by title (with article stripping).
-    fn compare_by_title(&self, a: &Reference, b: &Reference) -> std::cmp::Ordering {
+    fn compare_by_title(&self, a: &Reference, b: &Reference) -> Ordering {
         let a_title = self
             .locale
             .strip_sort_articles(&a.title().map(|t| t.to_string()).unwrap_or_default())
@@ -219,41 +314,130 @@ impl<'a> GroupSorter<'a> {
             .locale
             .strip_sort_articles(&b.title().map(|t| t.to_string()).unwrap_or_default())
             .to_lowercase();
-        a_title.cmp(&b_title)
-    }
-
-    /// Compare by issued date.
-    fn compare_by_issued(&self, a: &Reference, b: &Reference) -> std::cmp::Ordering {
-        let a_year = a
-            .issued()
-            .and_then(|d| d.year().parse::<i32>().ok())
-            .unwrap_or(0);
-        let b_year = b
-            .issued()
-            .and_then(|d| d.year().parse::<i32>().ok())
-            .unwrap_or(0);
-        a_year.cmp(&b_year)
+        self.collator.compare(&a_title, &b_title)
     }
 
-    /// Compare by custom field.
+    /// Compare by a reference variable in the given direction.
+    ///
+    /// References missing the variable sort last, whether ascending or
+    /// descending.
     fn compare_by_field(
         &self,
         a: &Reference,
         b: &Reference,
         field_name: &str,
-    ) -> std::cmp::Ordering {
-        match field_name {
-            "language" => {
-                let a_lang = a.language().unwrap_or_default();
-                let b_lang = b.language().unwrap_or_default();
-                a_lang.cmp(&b_lang)
+        ascending: bool,
+    ) -> Ordering {
+        match (
+            self.field_sort_value(a, field_name),
+            self.field_sort_value(b, field_name),
+        ) {
+            (Some(a), Some(b)) if ascending => self.compare_field_values(&a, &b),
+            (Some(a), Some(b)) => self.compare_field_values(&b, &a),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
+        }
+    }
+
+    fn compare_field_values(&self, a: &FieldValue, b: &FieldValue) -> Ordering {
+        match (a, b) {
+            (FieldValue::Names(a), FieldValue::Names(b)) => {
+                self.compare_names(a, b, NameSortOrder::FamilyGiven)
             }
-            // Future: support for keywords, custom metadata
-            _ => std::cmp::Ordering::Equal,
+            (FieldValue::Date(a), FieldValue::Date(b)) => a.cmp(b),
+            (FieldValue::Number(a), FieldValue::Number(b)) => a.cmp(b),
+            (FieldValue::Number(_), FieldValue::Text(_)) => Ordering::Less,
+            (FieldValue::Text(_), FieldValue::Number(_)) => Ordering::Greater,
+            (FieldValue::Text(a), FieldValue::Text(b)) => self.collator.compare(a, b),
+            // A variable always yields the same kind of value.
+            _ => Ordering::Equal,
+        }
+    }
+
+    /// The sort value of a variable, normalized by kind.
+    fn field_sort_value(&self, reference: &Reference, field_name: &str) -> Option<FieldValue> {
+        let names = |contributor: Option<Contributor>| {
+            contributor
+                .map(|c| c.to_names_vec())
+                .filter(|names| !names.is_empty())
+                .map(FieldValue::Names)
+        };
+        let date = |date: Option<EdtfString>| date.and_then(|d| d.sort_key()).map(FieldValue::Date);
+        let title = |title: Option<crate::reference::Title>| {
+            title.map(|t| {
+                FieldValue::Text(self.locale.strip_sort_articles(&t.to_string()).to_string())
+            })
+        };
+        let number = |value: Option<NumOrStr>| {
+            value.map(|v| match v {
+                NumOrStr::Number(n) => FieldValue::Number(n),
+                NumOrStr::Str(s) => s
+                    .trim()
+                    .parse()
+                    .map(FieldValue::Number)
+                    .unwrap_or(FieldValue::Text(s)),
+            })
+        };
+        let text = |value: Option<String>| value.map(FieldValue::Text);
+
+        match field_name {
+            "author" => names(reference.author()),
+            "editor" => names(reference.editor()),
+            "translator" => names(reference.translator()),
+            "director" => names(reference.director()),
+            "composer" => names(reference.composer()),
+            "illustrator" => names(reference.illustrator()),
+            "reviewed-author" => names(reference.reviewed_author()),
+            "issued" => date(reference.issued()),
+            "accessed" => date(reference.accessed()),
+            "original-date" => date(reference.original_date()),
+            "submitted" => date(reference.submitted()),
+            "title" => title(reference.title()),
+            "container-title" => title(reference.container_title()),
+            "reviewed-title" => title(reference.reviewed_title()),
+            "volume" => number(reference.volume()),
+            "issue" => number(reference.issue()),
+            "page" | "pages" => number(reference.pages()),
+            "edition" => number(reference.edition().map(NumOrStr::Str)),
+            "number" => number(reference.number().map(NumOrStr::Str)),
+            "collection-number" => number(reference.collection_number().map(NumOrStr::Str)),
+            "number-of-volumes" => number(reference.number_of_volumes().map(NumOrStr::Str)),
+            "number-of-pages" => number(reference.number_of_pages().map(NumOrStr::Str)),
+            "type" => Some(FieldValue::Text(reference.ref_type())),
+            "language" => text(reference.language()),
+            "note" => text(reference.note()),
+            "publisher" => text(reference.publisher_str()),
+            "publisher-place" => text(reference.publisher_place()),
+            "genre" => text(reference.genre()),
+            "medium" => text(reference.medium()),
+            "version" => text(reference.version()),
+            "authority" => text(reference.authority()),
+            "status" => text(reference.status()),
+            "archive" => text(reference.archive()),
+            "archive-place" => text(reference.archive_place()),
+            "archive-location" => text(reference.archive_location()),
+            "call-number" => text(reference.call_number()),
+            "source" => text(reference.source()),
+            "doi" | "DOI" => text(reference.doi()),
+            "isbn" | "ISBN" => text(reference.isbn()),
+            "issn" | "ISSN" => text(reference.issn()),
+            _ => None,
         }
+        .filter(|value| !matches!(value, FieldValue::Text(s) if s.trim().is_empty()))
     }
 }
 
+/// A variable's value, normalized for comparison.
+enum FieldValue {
+    /// Names, compared in sort order.
+    Names(Vec<FlatName>),
+    /// A date's chronological key.
+    Date((i64, u8, u8)),
+    Number(i64),
+    Text(String),
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -317,6 +501,7 @@ mod tests {
                     "article-newspaper".to_string(),
                 ]),
                 sort_order: None,
+                empty_author: None,
             }],
         };
 
@@ -346,6 +531,7 @@ mod tests {
                 ascending: true,
                 order: None,
                 sort_order: Some(NameSortOrder::FamilyGiven),
+                empty_author: None,
             }],
         };
 
@@ -374,6 +560,7 @@ mod tests {
                 ascending: false, // Descending
                 order: None,
                 sort_order: None,
+                empty_author: None,
             }],
         };
 
@@ -403,12 +590,14 @@ mod tests {
                     ascending: true,
                     order: None,
                     sort_order: Some(NameSortOrder::FamilyGiven),
+                    empty_author: None,
                 },
                 GroupSortKey {
                     key: GroupSortKeyType::Issued,
                     ascending: false, // Descending within author
                     order: None,
                     sort_order: None,
+                    empty_author: None,
                 },
             ],
         };
@@ -438,6 +627,7 @@ mod tests {
                 ascending: true,
                 order: None,
                 sort_order: Some(NameSortOrder::FamilyGiven),
+                empty_author: None,
             }],
         };
 
@@ -465,12 +655,14 @@ mod tests {
                     ascending: true,
                     order: None,
                     sort_order: None,
+                    empty_author: None,
                 },
                 GroupSortKey {
                     key: GroupSortKeyType::Issued,
                     ascending: true,
                     order: None,
                     sort_order: None,
+                    empty_author: None,
                 },
             ],
         };
@@ -500,6 +692,7 @@ mod tests {
                     "treaty".to_string(),
                 ]),
                 sort_order: None,
+                empty_author: None,
             }],
         };
 
@@ -510,4 +703,138 @@ mod tests {
         assert_eq!(refs[1].id().unwrap(), "r1");
         assert_eq!(refs[2].id().unwrap(), "r3");
     }
+
+    fn make_json_reference(json: serde_json::Value) -> Reference {
+        let legacy: csl_legacy::csl_json::Reference = serde_json::from_value(json).unwrap();
+        legacy.into()
+    }
+
+    fn ascending(key: GroupSortKeyType) -> GroupSortKey {
+        GroupSortKey {
+            key,
+            ascending: true,
+            order: None,
+            sort_order: None,
+            empty_author: None,
+        }
+    }
+
+    #[test]
+    fn test_author_sort_compares_full_names() {
+        let locale = make_locale();
+        let sorter = GroupSorter::new(&locale);
+
+        let john = make_json_reference(serde_json::json!({
+            "id": "r1", "type": "book", "title": "A",
+            "author": [{"family": "Smith", "given": "John"}],
+            "issued": {"date-parts": [[2000]]},
+        }));
+        let jane = make_json_reference(serde_json::json!({
+            "id": "r2", "type": "book", "title": "B",
+            "author": [{"family": "Smith", "given": "Jane"}],
+            "issued": {"date-parts": [[2020]]},
+        }));
+        let jane_and_lee = make_json_reference(serde_json::json!({
+            "id": "r3", "type": "book", "title": "C",
+            "author": [{"family": "Smith", "given": "Jane"}, {"family": "Lee", "given": "Ann"}],
+            "issued": {"date-parts": [[2010]]},
+        }));
+
+        let sort_spec = GroupSort {
+            template: vec![
+                ascending(GroupSortKeyType::Author),
+                ascending(GroupSortKeyType::Issued),
+            ],
+        };
+        let refs = sorter.sort_references(vec![&john, &jane_and_lee, &jane], &sort_spec);
+        let ids: Vec<_> = refs.iter().filter_map(|r| r.id()).collect();
+        assert_eq!(ids, ["r2", "r3", "r1"]);
+    }
+
+    #[test]
+    fn test_issued_sorts_chronologically_within_year() {
+        let locale = make_locale();
+        let sorter = GroupSorter::new(&locale);
+
+        let may = make_json_reference(serde_json::json!({
+            "id": "r1", "type": "book", "title": "A",
+            "issued": {"date-parts": [[2020, 5, 1]]},
+        }));
+        let february = make_json_reference(serde_json::json!({
+            "id": "r2", "type": "book", "title": "B",
+            "issued": {"date-parts": [[2020, 2]]},
+        }));
+        let undated = make_json_reference(serde_json::json!({
+            "id": "r3", "type": "book", "title": "C",
+        }));
+
+        let sort_spec = GroupSort {
+            template: vec![ascending(GroupSortKeyType::Issued)],
+        };
+        let refs = sorter.sort_references(vec![&undated, &may, &february], &sort_spec);
+        let ids: Vec<_> = refs.iter().filter_map(|r| r.id()).collect();
+        assert_eq!(ids, ["r2", "r1", "r3"]);
+
+        // Descending order still puts undated references last.
+        let sort_spec = GroupSort {
+            template: vec![GroupSortKey {
+                ascending: false,
+                ..ascending(GroupSortKeyType::Issued)
+            }],
+        };
+        let refs = sorter.sort_references(vec![&undated, &february, &may], &sort_spec);
+        let ids: Vec<_> = refs.iter().filter_map(|r| r.id()).collect();
+        assert_eq!(ids, ["r1", "r2", "r3"]);
+    }
+
+    #[test]
+    fn test_field_sort_by_variable() {
+        let locale = make_locale();
+        let sorter = GroupSorter::new(&locale);
+
+        let tenth = make_json_reference(serde_json::json!({
+            "id": "r1", "type": "book", "title": "A", "edition": "10", "publisher": "Zed",
+        }));
+        let second = make_json_reference(serde_json::json!({
+            "id": "r2", "type": "book", "title": "B", "edition": "2", "publisher": "Acme",
+        }));
+        let none = make_json_reference(serde_json::json!({
+            "id": "r3", "type": "book", "title": "C",
+        }));
+
+        // Numbers compare numerically, and missing values sort last.
+        let by_edition = GroupSort {
+            template: vec![ascending(GroupSortKeyType::Field("edition".to_string()))],
+        };
+        let refs = sorter.sort_references(vec![&none, &tenth, &second], &by_edition);
+        let ids: Vec<_> = refs.iter().filter_map(|r| r.id()).collect();
+        assert_eq!(ids, ["r2", "r1", "r3"]);
+
+        let by_publisher = GroupSort {
+            template: vec![ascending(GroupSortKeyType::Field("publisher".to_string()))],
+        };
+        let refs = sorter.sort_references(vec![&none, &tenth, &second], &by_publisher);
+        let ids: Vec<_> = refs.iter().filter_map(|r| r.id()).collect();
+        assert_eq!(ids, ["r2", "r1", "r3"]);
+    }
+
+    #[test]
+    fn test_ties_fall_back_to_reference_id() {
+        let locale = make_locale();
+        let sorter = GroupSorter::new(&locale);
+
+        let b = make_reference("b", "book", "Smith", "Title", 2020);
+        let a = make_reference("a", "book", "Smith", "Title", 2020);
+
+        let sort_spec = GroupSort {
+            template: vec![ascending(GroupSortKeyType::Author)],
+        };
+        let refs = sorter.sort_references(vec![&b, &a], &sort_spec);
+        let ids: Vec<_> = refs.iter().filter_map(|r| r.id()).collect();
+        assert_eq!(ids, ["a", "b"]);
+
+        let refs = sorter.sort_references_stable(vec![&b, &a], &sort_spec);
+        let ids: Vec<_> = refs.iter().filter_map(|r| r.id()).collect();
+        assert_eq!(ids, ["b", "a"]);
+    }
 }
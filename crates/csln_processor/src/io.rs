@@ -8,7 +8,7 @@ use std::path::Path;
 
 use csl_legacy::csl_json::Reference as LegacyReference;
 use csln_core::InputBibliography;
-use csln_core::reference::InputReference;
+use csln_core::reference::{InputReference, NormalizeOptions};
 
 use crate::{Bibliography, Citation, ProcessorError, Reference};
 
@@ -195,6 +195,20 @@ pub fn load_bibliography(path: &Path) -> Result<Bibliography, ProcessorError> {
     }
 }
 
+/// Load a bibliography and normalize each reference.
+///
+/// See [`InputReference::normalize_with`] for what is cleaned up.
+pub fn load_bibliography_normalized(
+    path: &Path,
+    options: &NormalizeOptions,
+) -> Result<Bibliography, ProcessorError> {
+    let mut bib = load_bibliography(path)?;
+    for reference in bib.values_mut() {
+        reference.normalize_with(options);
+    }
+    Ok(bib)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
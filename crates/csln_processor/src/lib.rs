@@ -64,6 +64,7 @@ SPDX-FileCopyrightText: © 2023-2026 Bruce D'Arcus
 //! assert_eq!(result, "(Kuhn, 1962)");
 //! ```
 
+mod collation;
 pub mod error;
 #[cfg(feature = "ffi")]
 pub mod ffi;
@@ -76,8 +77,10 @@ pub mod values;
 
 pub use error::ProcessorError;
 pub use processor::document::DocumentFormat;
-pub use processor::{ProcessedReferences, Processor};
-pub use reference::{Bibliography, Citation, CitationItem, Reference};
+pub use processor::{PostProcessor, ProcessedReferences, Processor};
+pub use reference::{
+    Bibliography, BibliographyExt, Citation, CitationItem, MergeConflict, Reference,
+};
 pub use render::{ProcTemplate, ProcTemplateComponent, citation_to_string, refs_to_string};
 pub use values::{ComponentValues, ProcHints, ProcValues, RenderContext, RenderOptions};
 
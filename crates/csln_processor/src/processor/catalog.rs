@@ -0,0 +1,123 @@
+/*
+SPDX-License-Identifier: MPL-2.0
+SPDX-FileCopyrightText: © 2023-2026 Bruce D'Arcus
+*/
+
+//! Library catalog records.
+//!
+//! [`Processor::catalog_entry`](super::Processor::catalog_entry) renders a
+//! reference's call number, title, author, and shelf location through the
+//! regular component renderer, and [`CatalogEntry`] lays them out in a fixed
+//! catalog format independent of the style's templates.
+
+use super::rendering::Renderer;
+use crate::reference::Reference;
+use crate::render::component::render_component_with_format;
+use crate::render::plain::PlainText;
+use crate::values::RenderContext;
+use csln_core::citation::CitationMode;
+use csln_core::template::{
+    ContributorForm, ContributorRole, NameOrder, SimpleVariable, TemplateComponent,
+    TemplateContributor, TemplateTitle, TemplateVariable, TitleType,
+};
+use serde::Serialize;
+use std::fmt;
+
+/// A reference laid out as a library catalog record.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct CatalogEntry {
+    /// The reference ID.
+    pub id: String,
+    /// The shelf mark (e.g., "QA76.73.R87 K53 2019").
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub call_number: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Authors in given-first order (e.g., "Steve Klabnik, Carol Nichols").
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author: Option<String>,
+    /// The holding archive and location within it (e.g., "Main Library, Stacks").
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+}
+
+impl CatalogEntry {
+    /// Render a reference's catalog fields with the component renderer.
+    pub(crate) fn from_reference(renderer: &Renderer<'_>, id: &str, reference: &Reference) -> Self {
+        let field = |component: TemplateComponent| render_field(renderer, reference, component);
+        let variable = |variable: SimpleVariable| {
+            field(TemplateComponent::Variable(TemplateVariable {
+                variable,
+                ..Default::default()
+            }))
+        };
+
+        // Rendered alone, an empty author would be replaced by the title.
+        let author =
+            crate::values::contributor::contributor_for_role(reference, &ContributorRole::Author)
+                .and_then(|_| {
+                    field(TemplateComponent::Contributor(TemplateContributor {
+                        contributor: ContributorRole::Author,
+                        form: ContributorForm::Long,
+                        name_order: Some(NameOrder::GivenFirst),
+                        ..Default::default()
+                    }))
+                });
+        let location: Vec<String> = [SimpleVariable::Archive, SimpleVariable::ArchiveLocation]
+            .into_iter()
+            .filter_map(variable)
+            .collect();
+
+        Self {
+            id: id.to_string(),
+            call_number: variable(SimpleVariable::CallNumber),
+            title: field(TemplateComponent::Title(TemplateTitle {
+                title: TitleType::Primary,
+                ..Default::default()
+            })),
+            author,
+            location: (!location.is_empty()).then(|| location.join(", ")),
+        }
+    }
+}
+
+/// Render a single component, or `None` if it produces no output.
+fn render_field(
+    renderer: &Renderer<'_>,
+    reference: &Reference,
+    component: TemplateComponent,
+) -> Option<String> {
+    let rendered: String = renderer
+        .process_template_with_number(
+            reference,
+            &[component],
+            RenderContext::Bibliography,
+            CitationMode::NonIntegral,
+            false,
+            0,
+            None,
+            None,
+            None,
+        )?
+        .iter()
+        .map(render_component_with_format::<PlainText>)
+        .collect();
+    (!rendered.is_empty()).then_some(rendered)
+}
+
+// Call number, "Title / Author", and location, one per line; missing fields
+// are left out.
+impl fmt::Display for CatalogEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let heading = match (&self.title, &self.author) {
+            (Some(title), Some(author)) => Some(format!("{} / {}", title, author)),
+            (title, author) => title.clone().or_else(|| author.clone()),
+        };
+        let lines: Vec<&str> = [&self.call_number, &heading, &self.location]
+            .into_iter()
+            .filter_map(|line| line.as_deref())
+            .collect();
+        write!(f, "{}", lines.join("\n"))
+    }
+}
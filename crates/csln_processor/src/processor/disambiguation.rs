@@ -1,6 +1,8 @@
 use crate::reference::{Bibliography, Reference};
 use crate::values::ProcHints;
-use csln_core::options::Config;
+use csln_core::options::{
+    Config, Disambiguation, DisambiguationStrategy, GivennameDisambiguationRule,
+};
 use std::collections::{HashMap, HashSet};
 
 use crate::grouping::GroupSorter;
@@ -10,15 +12,18 @@ use csln_core::locale::Locale;
 /// Handles disambiguation logic for author-date citations.
 ///
 /// Disambiguation resolves ambiguities when multiple references produce
-/// identical rendered strings. The processor applies strategies in cascade:
+/// identical rendered strings. The processor applies strategies in cascade,
+/// in the order given by the style's `strategy` list when one is set:
 ///
 /// 1. **Name expansion** (`disambiguate-add-names`): If et-al is triggered
 ///    in the base citation, try expanding the author list to differentiate
 ///    references with same first author and year.
 ///
-/// 2. **Given name expansion** (`disambiguate-add-givenname`): Add initials
-///    or full given names to author list to resolve remaining collisions
-///    (e.g., "Smith, John" vs "Smith, Jane").
+/// 2. **Given name expansion** (`disambiguate-add-givenname`): Add initials,
+///    then full given names, to resolve remaining collisions (e.g., "J. Smith"
+///    vs "R. Smith", or "John Smith" vs "Jane Smith"). The style's
+///    `givenname-rule` selects whether all names or only the first expand,
+///    and whether full given names are allowed.
 ///
 /// 3. **Combined expansion**: Try showing both more names AND given names
 ///    to maximize differentiation before falling back to year suffix.
@@ -42,6 +47,8 @@ use csln_core::locale::Locale;
 /// - `group_key`: Author-year key used for grouping
 /// - `disamb_condition`: Whether year suffix should be applied
 /// - `expand_given_names`: Whether to show given names/initials
+/// - `full_given_names`: Whether initials were not enough
+/// - `primary_name_only`: Whether only the first name expands
 /// - `min_names_to_show`: Minimum author count for name expansion
 pub struct Disambiguator<'a> {
     bibliography: &'a Bibliography,
@@ -136,59 +143,69 @@ impl<'a> Disambiguator<'a> {
                     .config()
                     .disambiguate;
 
-                let add_names = disamb_config.as_ref().map(|d| d.names).unwrap_or(false);
-                let add_givenname = disamb_config
-                    .as_ref()
-                    .map(|d| d.add_givenname)
-                    .unwrap_or(false);
-                let year_suffix = disamb_config
-                    .as_ref()
-                    .map(|d| d.year_suffix)
-                    .unwrap_or(false);
-
                 let is_label_mode = self
                     .config
                     .processing
                     .as_ref()
                     .is_some_and(|p| matches!(p, csln_core::options::Processing::Label(_)));
 
-                let mut resolved = false;
+                let rule = self.givenname_rule();
 
                 // For label mode, skip name strategies and go straight to year-suffix
-                if is_label_mode && year_suffix {
-                    self.apply_year_suffix(
-                        &mut hints,
-                        &group,
-                        key,
-                        group_len,
-                        false,
-                        &author_group_lengths,
-                    );
-                } else {
-                    // 1. Try expanding names (et-al expansion)
-                    if add_names && let Some(n) = self.check_names_resolution(&group) {
-                        for (i, reference) in group.iter().enumerate() {
-                            let author_key = self.make_author_key(reference);
-                            let global_author_length =
-                                author_group_lengths.get(&author_key).copied().unwrap_or(1);
-                            hints.insert(
-                                reference.id().unwrap_or_default(),
-                                ProcHints {
-                                    disamb_condition: false,
-                                    group_index: i + 1,
-                                    group_length: global_author_length,
-                                    group_key: key.clone(),
-                                    expand_given_names: false,
-                                    min_names_to_show: Some(n),
-                                    ..Default::default()
-                                },
+                let steps: Vec<_> = Self::strategy_steps(disamb_config.as_ref())
+                    .into_iter()
+                    .filter(|step| !is_label_mode || *step == DisambiguationStrategy::YearSuffix)
+                    .collect();
+
+                let mut tried_names = false;
+                let mut tried_givenname = false;
+                let mut add_title = false;
+                let mut resolved = false;
+                for step in steps {
+                    // Each name step falls back to the combined expansion
+                    // (more names + given names) once both have been tried.
+                    let resolution = match step {
+                        DisambiguationStrategy::AddNames => {
+                            tried_names = true;
+                            self.check_names_resolution(&group)
+                                .map(|n| (false, false, Some(n)))
+                                .or_else(|| {
+                                    tried_givenname
+                                        .then(|| self.check_combined_resolution(&group, rule))
+                                        .flatten()
+                                })
+                        }
+                        DisambiguationStrategy::AddGivenname => {
+                            tried_givenname = true;
+                            if let Some(full) = self.check_givenname_resolution(&group, None, rule)
+                            {
+                                Some((true, full, None))
+                            } else {
+                                tried_names
+                                    .then(|| self.check_combined_resolution(&group, rule))
+                                    .flatten()
+                            }
+                        }
+                        DisambiguationStrategy::AddTitle => {
+                            add_title = self.check_title_resolution(&group);
+                            add_title.then_some((false, false, None))
+                        }
+                        DisambiguationStrategy::YearSuffix => {
+                            self.apply_year_suffix(
+                                &mut hints,
+                                &group,
+                                key.clone(),
+                                group_len,
+                                false,
+                                &author_group_lengths,
                             );
+                            resolved = true;
+                            break;
                         }
-                        resolved = true;
-                    }
-
-                    // 2. Try expanding given names for the base name list
-                    if !resolved && add_givenname && self.check_givenname_resolution(&group, None) {
+                    };
+                    if let Some((expand_given_names, full_given_names, min_names_to_show)) =
+                        resolution
+                    {
                         for (i, reference) in group.iter().enumerate() {
                             let author_key = self.make_author_key(reference);
                             let global_author_length =
@@ -200,58 +217,33 @@ impl<'a> Disambiguator<'a> {
                                     group_index: i + 1,
                                     group_length: global_author_length,
                                     group_key: key.clone(),
-                                    expand_given_names: true,
-                                    min_names_to_show: None,
+                                    expand_given_names,
+                                    full_given_names,
+                                    primary_name_only: expand_given_names && rule.primary_only(),
+                                    min_names_to_show,
+                                    add_title,
                                     ..Default::default()
                                 },
                             );
                         }
                         resolved = true;
+                        break;
                     }
+                }
 
-                    // 3. Try combined expansion: multiple names + given names
-                    if !resolved && add_names && add_givenname {
-                        // Find if there's an N such that expanding both names and given names works
-                        let max_authors = group
-                            .iter()
-                            .map(|r| r.author().map(|a| a.to_names_vec().len()).unwrap_or(0))
-                            .max()
-                            .unwrap_or(0);
-
-                        for n in 2..=max_authors {
-                            if self.check_givenname_resolution(&group, Some(n)) {
-                                for (idx, reference) in group.iter().enumerate() {
-                                    let author_key = self.make_author_key(reference);
-                                    let global_author_length =
-                                        author_group_lengths.get(&author_key).copied().unwrap_or(1);
-                                    hints.insert(
-                                        reference.id().unwrap_or_default(),
-                                        ProcHints {
-                                            disamb_condition: false,
-                                            group_index: idx + 1,
-                                            group_length: global_author_length,
-                                            group_key: key.clone(),
-                                            expand_given_names: true,
-                                            min_names_to_show: Some(n),
-                                            ..Default::default()
-                                        },
-                                    );
-                                }
-                                resolved = true;
-                                break;
-                            }
-                        }
-                    }
-
-                    // 4. Fallback to year-suffix
-                    if !resolved {
-                        self.apply_year_suffix(
-                            &mut hints,
-                            &group,
-                            key,
-                            group_len,
-                            false,
-                            &author_group_lengths,
+                // The style opted out of every step that could resolve the
+                // collision, so the citations stay ambiguous.
+                if !resolved {
+                    for reference in &group {
+                        let author_key = self.make_author_key(reference);
+                        let global_author_length =
+                            author_group_lengths.get(&author_key).copied().unwrap_or(1);
+                        hints.insert(
+                            reference.id().unwrap_or_default(),
+                            ProcHints {
+                                group_length: global_author_length,
+                                ..Default::default()
+                            },
                         );
                     }
                 }
@@ -285,7 +277,7 @@ impl<'a> Disambiguator<'a> {
         let sorted_group = if let Some(sort_spec) = self.group_sort {
             // Use GroupSorter for per-group ordering
             let sorter = GroupSorter::new(self.locale);
-            sorter.sort_references(group.to_vec(), sort_spec)
+            sorter.sort_references_stable(group.to_vec(), sort_spec)
         } else {
             // Fallback to title sorting (default behavior)
             let mut sorted: Vec<&Reference> = group.to_vec();
@@ -328,6 +320,7 @@ impl<'a> Disambiguator<'a> {
                     group_length: global_author_length,
                     group_key: key.clone(),
                     expand_given_names: expand_names,
+                    primary_name_only: expand_names && self.givenname_rule().primary_only(),
                     min_names_to_show: None,
                     ..Default::default()
                 },
@@ -335,6 +328,72 @@ impl<'a> Disambiguator<'a> {
         }
     }
 
+    /// Resolve the ordered disambiguation steps for a style.
+    ///
+    /// An explicit `strategy` is used as given. Otherwise the boolean flags
+    /// select the name steps, and year suffixes remain the final fallback.
+    fn strategy_steps(disamb: Option<&Disambiguation>) -> Vec<DisambiguationStrategy> {
+        if let Some(strategy) = disamb.and_then(|d| d.strategy.clone()) {
+            return strategy;
+        }
+        let mut steps = Vec::new();
+        if disamb.is_some_and(|d| d.names) {
+            steps.push(DisambiguationStrategy::AddNames);
+        }
+        if disamb.is_some_and(|d| d.add_givenname) {
+            steps.push(DisambiguationStrategy::AddGivenname);
+        }
+        steps.push(DisambiguationStrategy::YearSuffix);
+        steps
+    }
+
+    /// Whether every reference in the group has a distinct, non-empty
+    /// short title, compared as the citation will render it.
+    fn check_title_resolution(&self, group: &[&Reference]) -> bool {
+        use crate::values::{ComponentValues, RenderContext, RenderOptions};
+
+        let title = super::rendering::disambiguating_title();
+        let options = RenderOptions {
+            config: self.config,
+            locale: self.locale,
+            context: RenderContext::Citation,
+            mode: Default::default(),
+            suppress_author: false,
+            locator: None,
+            locator_label: None,
+            locators: None,
+            date_locale: None,
+        };
+        let mut seen = HashSet::new();
+        group.iter().all(|reference| {
+            title
+                .values::<crate::render::plain::PlainText>(
+                    reference,
+                    &ProcHints::default(),
+                    &options,
+                )
+                .map(|v| v.value.to_lowercase())
+                .is_some_and(|t| !t.is_empty() && seen.insert(t))
+        })
+    }
+
+    /// Find the smallest name count at which given names resolve the group.
+    fn check_combined_resolution(
+        &self,
+        group: &[&Reference],
+        rule: GivennameDisambiguationRule,
+    ) -> Option<(bool, bool, Option<usize>)> {
+        let max_authors = group
+            .iter()
+            .map(|r| r.author().map(|a| a.to_names_vec().len()).unwrap_or(0))
+            .max()
+            .unwrap_or(0);
+        (2..=max_authors).find_map(|n| {
+            self.check_givenname_resolution(group, Some(n), rule)
+                .map(|full| (true, full, Some(n)))
+        })
+    }
+
     /// Check if showing more names resolves ambiguity in the group.
     fn check_names_resolution(&self, group: &[&Reference]) -> Option<usize> {
         let max_authors = group
@@ -369,38 +428,106 @@ impl<'a> Disambiguator<'a> {
         None
     }
 
-    /// Check if expanding to full names resolves ambiguity in the group.
-    /// If `min_names` is Some(n), it checks resolution when showing n names.
-    fn check_givenname_resolution(&self, group: &[&Reference], min_names: Option<usize>) -> bool {
+    /// Check if adding given names resolves ambiguity in the group.
+    ///
+    /// When the style initializes given names, initials are tried before
+    /// full given names, which the rule may forbid. Returns whether full
+    /// given names are needed, or `None` if the group stays ambiguous. If
+    /// `min_names` is Some(n), it checks resolution when showing n names;
+    /// otherwise the base citation's names are used.
+    fn check_givenname_resolution(
+        &self,
+        group: &[&Reference],
+        min_names: Option<usize>,
+        rule: GivennameDisambiguationRule,
+    ) -> Option<bool> {
+        let initializes = self
+            .config
+            .contributors
+            .as_ref()
+            .is_some_and(|c| c.initialize_with.is_some());
+        let levels: &[bool] = match (initializes, rule.allows_full_names()) {
+            (false, _) => &[true],
+            (true, true) => &[false, true],
+            (true, false) => &[false],
+        };
+        levels
+            .iter()
+            .copied()
+            .find(|&full| self.givenname_keys_unique(group, min_names, rule, full))
+    }
+
+    /// Whether the group's names differ once given names are added, either
+    /// as initials or in full.
+    fn givenname_keys_unique(
+        &self,
+        group: &[&Reference],
+        min_names: Option<usize>,
+        rule: GivennameDisambiguationRule,
+        full: bool,
+    ) -> bool {
         let mut seen = HashSet::new();
-        let mut collision = false;
-        for reference in group {
-            if let Some(authors) = reference.author() {
-                let n = min_names.unwrap_or(1);
-                // Create a key for the first n authors with full names
-                let key = authors
-                    .to_names_vec()
-                    .iter()
-                    .take(n)
-                    .map(|n| {
-                        format!(
-                            "{:?}|{:?}|{:?}|{:?}",
-                            n.family, n.given, n.non_dropping_particle, n.dropping_particle
-                        )
-                    })
-                    .collect::<Vec<_>>()
-                    .join("||");
+        group.iter().all(|reference| {
+            let key = reference
+                .author()
+                .map(|authors| {
+                    let names = authors.to_names_vec();
+                    let n = min_names.unwrap_or_else(|| self.displayed_name_count(names.len()));
+                    names
+                        .iter()
+                        .take(n)
+                        .enumerate()
+                        .map(|(i, name)| {
+                            let given = name.given.as_deref().unwrap_or("");
+                            let given = if i > 0 && rule.primary_only() {
+                                String::new()
+                            } else if full {
+                                given.to_string()
+                            } else {
+                                given
+                                    .split([' ', '-'])
+                                    .filter_map(|part| part.chars().next())
+                                    .collect()
+                            };
+                            format!(
+                                "{:?}|{}|{:?}|{:?}",
+                                name.family,
+                                given,
+                                name.non_dropping_particle,
+                                name.dropping_particle
+                            )
+                        })
+                        .collect::<Vec<_>>()
+                        .join("||")
+                })
+                .unwrap_or_default();
+            seen.insert(key)
+        })
+    }
 
-                if !seen.insert(key) {
-                    collision = true;
-                    break;
-                }
-            } else if !seen.insert("".to_string()) {
-                collision = true;
-                break;
-            }
+    /// The style's given-name disambiguation rule.
+    fn givenname_rule(&self) -> GivennameDisambiguationRule {
+        self.config
+            .processing
+            .clone()
+            .unwrap_or_default()
+            .config()
+            .disambiguate
+            .and_then(|d| d.givenname_rule)
+            .unwrap_or_default()
+    }
+
+    /// Number of names shown in the base citation for a list of `len` names.
+    fn displayed_name_count(&self, len: usize) -> usize {
+        match self
+            .config
+            .contributors
+            .as_ref()
+            .and_then(|c| c.shorten.as_ref())
+        {
+            Some(opts) if len >= opts.min as usize => opts.use_first as usize,
+            _ => len,
         }
-        !collision
     }
 
     /// Group references by author-year for disambiguation.
@@ -502,12 +629,16 @@ mod tests {
             })),
             editor: None,
             translator: None,
+            composer: None,
+            illustrator: None,
             issued: EdtfString(year.to_string()),
+            submitted: None,
             publisher: None,
             url: None,
             accessed: None,
             language: None,
             note: None,
+            abstract_text: None,
             isbn: None,
             doi: None,
             edition: None,
@@ -518,6 +649,15 @@ mod tests {
             keywords: None,
             original_date: None,
             original_title: None,
+            original_publisher: None,
+            number_of_volumes: None,
+            number_of_pages: None,
+            archive: None,
+            archive_place: None,
+            archive_location: None,
+            call_number: None,
+            scale: None,
+            dimensions: None,
         }))
     }
 
@@ -547,6 +687,7 @@ mod tests {
                 ascending: false,
                 order: None,
                 sort_order: None,
+                empty_author: None,
             }],
         };
 
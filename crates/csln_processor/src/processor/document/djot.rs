@@ -7,7 +7,7 @@ SPDX-FileCopyrightText: © 2023-2026 Bruce D'Arcus
 
 use super::CitationParser;
 use crate::{Citation, CitationItem};
-use csln_core::citation::{CitationMode, LocatorType};
+use csln_core::citation::{CitationMode, LocatorSegment, LocatorType};
 use winnow::ascii::space0;
 use winnow::combinator::{opt, repeat};
 use winnow::error::ContextError;
@@ -144,50 +144,53 @@ fn parse_citation_item_no_integral(input: &mut &str) -> winnow::Result<CitationI
 }
 
 /// Parse locators in either `p. 23` or `page: 23, section: V` format.
+///
+/// Comma-separated parts that start with a known label become a composite
+/// locator (`bk. 2, ch. 3`); other parts continue the previous value
+/// (`pp. 23, 25`).
 fn parse_hybrid_locators(item: &mut CitationItem, locator_str: &str) {
     let lp = locator_str.trim();
     if lp.is_empty() {
         return;
     }
 
-    // Check for explicit key-value: `page: 23`
-    if let Some(colon_pos) = lp.find(':') {
-        let key = lp[..colon_pos].trim().to_lowercase();
-        let val_with_rest = lp[colon_pos + 1..].trim();
-
-        let val = if let Some(comma_pos) = val_with_rest.find(',') {
-            &val_with_rest[..comma_pos]
-        } else {
-            val_with_rest
-        };
-
-        item.label = map_label_str(&key);
-        item.locator = Some(val.trim().to_string());
-    } else {
-        // Fallback to shorthand: `p. 23`
-        if let Some(space_pos) = lp.find(' ') {
-            let label_str = lp[..space_pos].trim_end_matches('.');
-            let value = &lp[space_pos + 1..];
-
-            if let Some(lt) = map_label_str(label_str) {
-                item.label = Some(lt);
-                item.locator = Some(value.to_string());
-            } else {
-                // Not a known label, treat whole string as locator (default to page)
-                item.label = Some(LocatorType::Page);
-                item.locator = Some(lp.to_string());
+    let mut parts: Vec<LocatorSegment> = Vec::new();
+    for part in lp.split(',') {
+        match (parse_labeled_locator(part), parts.last_mut()) {
+            (Some(segment), _) => parts.push(segment),
+            (None, Some(previous)) => {
+                previous.locator = format!("{},{}", previous.locator, part);
             }
-        } else {
-            // No label, assume page
-            item.label = Some(LocatorType::Page);
-            item.locator = Some(lp.to_string());
+            // No known label, assume page
+            (None, None) => parts.push(LocatorSegment {
+                label: LocatorType::Page,
+                locator: part.trim().to_string(),
+            }),
         }
     }
+
+    if parts.len() > 1 {
+        item.locators = Some(parts);
+    } else if let Some(segment) = parts.pop() {
+        item.label = Some(segment.label);
+        item.locator = Some(segment.locator);
+    }
+}
+
+/// Parse a single `page: 23` or `p. 23` locator part.
+fn parse_labeled_locator(part: &str) -> Option<LocatorSegment> {
+    let part = part.trim();
+    let (label, value) = part.split_once(':').or_else(|| part.split_once(' '))?;
+    Some(LocatorSegment {
+        label: map_label_str(label)?,
+        locator: value.trim().to_string(),
+    })
 }
 
 fn map_label_str(s: &str) -> Option<LocatorType> {
     match s.trim().trim_end_matches('.').to_lowercase().as_str() {
         "p" | "page" | "pp" => Some(LocatorType::Page),
+        "bk" | "book" => Some(LocatorType::Book),
         "vol" | "volume" => Some(LocatorType::Volume),
         "ch" | "chap" | "chapter" => Some(LocatorType::Chapter),
         "sec" | "section" => Some(LocatorType::Section),
@@ -237,6 +240,30 @@ mod tests {
         assert_eq!(citation.items[0].label, Some(LocatorType::Section));
     }
 
+    #[test]
+    fn test_parse_composite_locator() {
+        let parser = DjotParser;
+        let content = "[@homer, bk. 2, ch. 3; @kuhn1962, pp. 23, 25]";
+        let citations = parser.parse_citations(content);
+
+        let (_, _, citation) = &citations[0];
+        assert_eq!(
+            citation.items[0].locators,
+            Some(vec![
+                LocatorSegment {
+                    label: LocatorType::Book,
+                    locator: "2".to_string(),
+                },
+                LocatorSegment {
+                    label: LocatorType::Chapter,
+                    locator: "3".to_string(),
+                },
+            ])
+        );
+        assert_eq!(citation.items[1].locators, None);
+        assert_eq!(citation.items[1].locator, Some("23, 25".to_string()));
+    }
+
     #[test]
     fn test_parse_suppress_author() {
         let parser = DjotParser;
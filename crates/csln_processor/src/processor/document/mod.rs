@@ -12,6 +12,26 @@ mod tests;
 
 use crate::Citation;
 use crate::processor::Processor;
+use csln_core::options::NoteMarker;
+use std::collections::HashSet;
+
+/// Symbols used by [`NoteMarker::Symbol`], in order.
+const NOTE_SYMBOLS: [char; 4] = ['*', '\u{2020}', '\u{2021}', '\u{00A7}'];
+
+/// Generate the marker for the `n`th note (1-based).
+///
+/// Symbol markers cycle through *, †, ‡, § and then repeat each symbol
+/// once more per cycle (**, ††, ...).
+pub fn note_marker(n: usize, marker: NoteMarker) -> String {
+    let index = n.saturating_sub(1);
+    match marker {
+        NoteMarker::Number => n.to_string(),
+        NoteMarker::Symbol => NOTE_SYMBOLS[index % NOTE_SYMBOLS.len()]
+            .to_string()
+            .repeat(index / NOTE_SYMBOLS.len() + 1),
+        NoteMarker::Letter => crate::values::int_to_letter(n as u32).unwrap_or_default(),
+    }
+}
 
 /// A trait for document parsers that can identify citations.
 pub trait CitationParser {
@@ -34,6 +54,11 @@ pub enum DocumentFormat {
 }
 
 impl Processor {
+    /// The marker for the `n`th generated note, per the style's `note-marker`.
+    pub fn note_marker(&self, n: usize) -> String {
+        note_marker(n, self.get_config().note_marker.unwrap_or_default())
+    }
+
     /// Process citations in a document and append a bibliography.
     pub fn process_document<P, F>(
         &self,
@@ -41,6 +66,43 @@ impl Processor {
         parser: &P,
         format: DocumentFormat,
     ) -> String
+    where
+        P: CitationParser,
+        F: crate::render::format::OutputFormat<Output = String>,
+    {
+        let mut result = self.render_document_citations::<P, F>(content, parser);
+
+        let bib_heading = match format {
+            DocumentFormat::Latex => "\n\n\\section*{Bibliography}\n\n",
+            _ => "\n\n# Bibliography\n\n",
+        };
+        result.push_str(bib_heading);
+
+        let bib_content = self.render_grouped_bibliography_with_format::<F>();
+        result.push_str(&bib_content);
+
+        finish_document(result, format)
+    }
+
+    /// Process citations in a document without appending a bibliography.
+    pub fn process_document_citations<P, F>(
+        &self,
+        content: &str,
+        parser: &P,
+        format: DocumentFormat,
+    ) -> String
+    where
+        P: CitationParser,
+        F: crate::render::format::OutputFormat<Output = String>,
+    {
+        finish_document(
+            self.render_document_citations::<P, F>(content, parser),
+            format,
+        )
+    }
+
+    /// Replace each parsed citation with its rendering.
+    fn render_document_citations<P, F>(&self, content: &str, parser: &P) -> String
     where
         P: CitationParser,
         F: crate::render::format::OutputFormat<Output = String>,
@@ -51,31 +113,28 @@ impl Processor {
         let citation_models: Vec<Citation> = parsed.iter().map(|(_, _, c)| c.clone()).collect();
         let normalized = self.normalize_note_context(&citation_models);
 
-        // Render citations in the specified format
-        for ((start, end, _), citation) in parsed.into_iter().zip(normalized.into_iter()) {
+        // Render citations in the specified format, in document order so
+        // that repeat citations take their subsequent form
+        let mut cited_before = HashSet::new();
+        for ((start, end, _), citation) in parsed.into_iter().zip(normalized) {
             result.push_str(&content[last_idx..start]);
-            match self.process_citation_with_format::<F>(&citation) {
+            match self.process_citation_after_with_format::<F>(&citation, &cited_before) {
                 Ok(rendered) => result.push_str(&rendered),
                 Err(_) => result.push_str(&content[start..end]),
             }
+            cited_before.extend(citation.items.iter().map(|item| item.id.clone()));
             last_idx = end;
         }
 
         result.push_str(&content[last_idx..]);
+        result
+    }
+}
 
-        let bib_heading = match format {
-            DocumentFormat::Latex => "\n\n\\section*{Bibliography}\n\n",
-            _ => "\n\n# Bibliography\n\n",
-        };
-        result.push_str(bib_heading);
-
-        let bib_content = self.render_grouped_bibliography_with_format::<F>();
-        result.push_str(&bib_content);
-
-        // Convert to HTML if requested
-        match format {
-            DocumentFormat::Html => self::djot::djot_to_html(&result),
-            DocumentFormat::Djot | DocumentFormat::Plain | DocumentFormat::Latex => result,
-        }
+/// Convert the rendered document to HTML if requested.
+fn finish_document(result: String, format: DocumentFormat) -> String {
+    match format {
+        DocumentFormat::Html => self::djot::djot_to_html(&result),
+        DocumentFormat::Djot | DocumentFormat::Plain | DocumentFormat::Latex => result,
     }
 }
@@ -262,3 +262,46 @@ fn test_repro_djot_rendering() {
     assert!(result.contains("Integral: Doe (2020)"));
     assert!(result.contains("SuppressAuthor: (2020)"));
 }
+
+#[test]
+fn test_symbol_note_markers() {
+    use crate::processor::document::note_marker;
+    use csln_core::options::NoteMarker;
+
+    let markers: Vec<String> = (1..=5)
+        .map(|n| note_marker(n, NoteMarker::Symbol))
+        .collect();
+    assert_eq!(markers, ["*", "\u{2020}", "\u{2021}", "\u{00A7}", "**"]);
+    assert_eq!(note_marker(8, NoteMarker::Symbol), "\u{00A7}\u{00A7}");
+    assert_eq!(note_marker(28, NoteMarker::Letter), "ab");
+
+    let style: Style = serde_yaml::from_str("info: {}\noptions:\n  note-marker: symbol\n").unwrap();
+    assert_eq!(
+        style.options.as_ref().and_then(|o| o.note_marker),
+        Some(NoteMarker::Symbol)
+    );
+    let processor = Processor::new(style, make_test_bib());
+    assert_eq!(processor.note_marker(2), "\u{2020}");
+    assert_eq!(Processor::default().note_marker(2), "2");
+}
+
+#[test]
+fn test_used_and_uncited_references() {
+    let processor = Processor::new(Style::default(), make_test_bib());
+    let parser = DjotParser;
+
+    assert!(processor.used_references().is_empty());
+    assert_eq!(processor.uncited_references(), ["item1", "item2"]);
+
+    processor.process_document_citations::<_, PlainText>(
+        "See [@item1].",
+        &parser,
+        DocumentFormat::Plain,
+    );
+
+    let used = processor.used_references();
+    assert_eq!(used.len(), 1);
+    assert!(used.contains("item1"));
+    drop(used);
+    assert_eq!(processor.uncited_references(), ["item2"]);
+}
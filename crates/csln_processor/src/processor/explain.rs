@@ -0,0 +1,340 @@
+/*
+SPDX-License-Identifier: MPL-2.0
+SPDX-FileCopyrightText: © 2023-2026 Bruce D'Arcus
+*/
+
+//! Rendering traces for debugging styles.
+//!
+//! [`Processor::explain_citation`](super::Processor::explain_citation) reports
+//! which template components fired for each cited item, which were suppressed
+//! (and why), and which disambiguation decisions applied.
+//! [`CitationExplanation::diagnostics`] flattens a trace into warnings
+//! suitable for machine-readable reports. [`MissingVariables`] records the
+//! gaps found by a style-against-data preflight.
+
+use crate::values::{ProcHints, RenderContext};
+use csln_core::citation::CitationMode;
+use csln_core::template::{ComponentOverride, TemplateComponent};
+use serde::Serialize;
+use std::fmt;
+
+/// A structured trace of how a citation was rendered.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct CitationExplanation {
+    /// The citation mode used to select the template.
+    pub mode: CitationMode,
+    /// The rendered citation, if rendering succeeded.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output: Option<String>,
+    /// Per-item traces, in rendered (sorted) order.
+    pub items: Vec<ItemExplanation>,
+}
+
+/// The trace for a single cited item.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct ItemExplanation {
+    /// The cited reference ID.
+    pub id: String,
+    /// The reference type, or `None` if the ID is not in the bibliography.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ref_type: Option<String>,
+    /// Template components in template order.
+    pub components: Vec<ComponentTrace>,
+    /// Disambiguation decisions applied to this item.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub disambiguation: Vec<DisambiguationDecision>,
+}
+
+/// The outcome of a single template component.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct ComponentTrace {
+    /// A short label for the component (e.g., "contributor:author").
+    pub component: String,
+    /// What happened to the component.
+    pub outcome: ComponentOutcome,
+}
+
+/// Whether a component rendered, and with what.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "kebab-case", tag = "status")]
+pub enum ComponentOutcome {
+    /// The component produced output.
+    Rendered {
+        value: String,
+        /// The variable substituted for an empty one (e.g., title for author).
+        #[serde(skip_serializing_if = "Option::is_none")]
+        substituted: Option<String>,
+    },
+    /// The component produced no output.
+    Suppressed { reason: SuppressionReason },
+}
+
+/// Why a component produced no output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum SuppressionReason {
+    /// The reference has no value for the component's variable.
+    Empty,
+    /// The style sets `suppress: true` for this reference type.
+    Style,
+    /// The citation asked for the author to be suppressed.
+    SuppressAuthor,
+    /// The variable was already rendered (CSL "variable-once" rule).
+    AlreadyRendered,
+}
+
+/// A disambiguation decision applied to a cited item.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "kebab-case", tag = "kind")]
+pub enum DisambiguationDecision {
+    /// A year suffix was assigned (1-based position within the group).
+    YearSuffix { index: usize, of: usize },
+    /// Given names were expanded.
+    GivenNames,
+    /// Additional names were shown before et al.
+    AddNames { min_names: usize },
+    /// The short title was added.
+    AddTitle,
+}
+
+impl DisambiguationDecision {
+    /// Collect the decisions recorded in processing hints.
+    pub(crate) fn from_hints(hints: &ProcHints) -> Vec<Self> {
+        let mut decisions = Vec::new();
+        if let Some(min_names) = hints.min_names_to_show {
+            decisions.push(Self::AddNames { min_names });
+        }
+        if hints.expand_given_names {
+            decisions.push(Self::GivenNames);
+        }
+        if hints.add_title {
+            decisions.push(Self::AddTitle);
+        }
+        if hints.disamb_condition {
+            decisions.push(Self::YearSuffix {
+                index: hints.group_index,
+                of: hints.group_length,
+            });
+        }
+        decisions
+    }
+}
+
+/// Template variables a reference has no value for.
+///
+/// Returned by
+/// [`Processor::validate_style_against_data`](super::Processor::validate_style_against_data).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct MissingVariables {
+    /// The reference ID.
+    pub id: String,
+    /// Component labels (e.g., "title:parentserial") in template order.
+    pub variables: Vec<String>,
+}
+
+/// How serious a diagnostic is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum DiagnosticLevel {
+    /// Likely a data or style problem.
+    Warning,
+    /// A processor decision worth knowing about.
+    Info,
+}
+
+/// A rendering diagnostic for a single cited item.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct Diagnostic {
+    pub level: DiagnosticLevel,
+    /// A stable identifier for the kind of diagnostic (e.g., "missing-field").
+    pub code: String,
+    /// Whether the item was rendered as a citation or a bibliography entry.
+    pub context: RenderContext,
+    /// The cited reference ID.
+    pub item: String,
+    /// The component the diagnostic concerns, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub component: Option<String>,
+    pub message: String,
+}
+
+impl Diagnostic {
+    fn new(
+        level: DiagnosticLevel,
+        code: &str,
+        context: RenderContext,
+        item: &str,
+        message: String,
+    ) -> Self {
+        Self {
+            level,
+            code: code.to_string(),
+            context,
+            item: item.to_string(),
+            component: None,
+            message,
+        }
+    }
+
+    fn with_component(mut self, component: &str) -> Self {
+        self.component = Some(component.to_string());
+        self
+    }
+}
+
+impl CitationExplanation {
+    /// Flatten this trace into diagnostics.
+    ///
+    /// See [`ItemExplanation::diagnostics`] for what is reported.
+    pub fn diagnostics(&self) -> Vec<Diagnostic> {
+        self.items
+            .iter()
+            .flat_map(|item| item.diagnostics(RenderContext::Citation))
+            .collect()
+    }
+}
+
+impl ItemExplanation {
+    /// Flatten this item's trace into diagnostics for the context it was
+    /// rendered in.
+    ///
+    /// Missing references and empty variables are warnings; style
+    /// suppression, substitution, and disambiguation are informational.
+    /// Components skipped by the variable-once rule are not reported.
+    pub fn diagnostics(&self, context: RenderContext) -> Vec<Diagnostic> {
+        let diagnostic =
+            |level, code, message| Diagnostic::new(level, code, context, &self.id, message);
+        if self.ref_type.is_none() {
+            return vec![diagnostic(
+                DiagnosticLevel::Warning,
+                "missing-reference",
+                format!("reference '{}' not found in bibliography", self.id),
+            )];
+        }
+        let mut diagnostics = Vec::new();
+        for trace in &self.components {
+            let found = match &trace.outcome {
+                ComponentOutcome::Suppressed {
+                    reason: SuppressionReason::Empty,
+                } => diagnostic(
+                    DiagnosticLevel::Warning,
+                    "missing-field",
+                    format!("'{}' has no value for {}", self.id, trace.component),
+                ),
+                ComponentOutcome::Suppressed {
+                    reason: SuppressionReason::Style,
+                } => diagnostic(
+                    DiagnosticLevel::Info,
+                    "suppressed",
+                    format!("style suppresses {}", trace.component),
+                ),
+                ComponentOutcome::Rendered {
+                    substituted: Some(sub),
+                    ..
+                } => diagnostic(
+                    DiagnosticLevel::Info,
+                    "substituted",
+                    format!("{} substituted for {}", sub, trace.component),
+                ),
+                _ => continue,
+            };
+            diagnostics.push(found.with_component(&trace.component));
+        }
+        for decision in &self.disambiguation {
+            let message = match decision {
+                DisambiguationDecision::YearSuffix { index, of } => {
+                    format!("year suffix {} of {} assigned", index, of)
+                }
+                DisambiguationDecision::GivenNames => "given names expanded".to_string(),
+                DisambiguationDecision::AddNames { min_names } => {
+                    format!("{} names shown before et al.", min_names)
+                }
+                DisambiguationDecision::AddTitle => "short title added".to_string(),
+            };
+            diagnostics.push(diagnostic(DiagnosticLevel::Info, "disambiguated", message));
+        }
+        diagnostics
+    }
+}
+
+/// Build a short, human-readable label for a template component.
+pub(crate) fn component_label(component: &TemplateComponent) -> String {
+    let label = match component {
+        TemplateComponent::Contributor(c) => format!("contributor:{}", c.contributor.as_str()),
+        TemplateComponent::Date(d) => format!("date:{:?}", d.date),
+        TemplateComponent::Title(t) => format!("title:{:?}", t.title),
+        TemplateComponent::Number(n) => format!("number:{:?}", n.number),
+        TemplateComponent::Variable(v) => format!("variable:{:?}", v.variable),
+        TemplateComponent::Term(t) => format!("term:{:?}", t.term),
+        TemplateComponent::Text(t) => format!("text:{}", t.text),
+        TemplateComponent::Conditional(c) => {
+            format!("if({}|{})", c.then.len(), c.otherwise.len())
+        }
+        TemplateComponent::List(l) => format!("list({})", l.items.len()),
+        _ => "component".to_string(),
+    };
+    label.to_lowercase()
+}
+
+/// Whether the style explicitly suppresses a component for a reference type.
+pub(crate) fn is_style_suppressed(component: &TemplateComponent, ref_type: &str) -> bool {
+    let suppressed_by = |selector: &str| -> Option<bool> {
+        let overrides = component.overrides()?;
+        let mut result = None;
+        for (sel, ov) in overrides {
+            if sel.matches(selector)
+                && let ComponentOverride::Rendering(r) = ov
+                && r.suppress.is_some()
+            {
+                result = r.suppress;
+            }
+        }
+        result
+    };
+    suppressed_by(ref_type)
+        .or_else(|| suppressed_by("default"))
+        .or(component.rendering().suppress)
+        .unwrap_or(false)
+}
+
+impl fmt::Display for CitationExplanation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "mode: {:?}", self.mode)?;
+        if let Some(output) = &self.output {
+            writeln!(f, "output: {}", output)?;
+        }
+        for item in &self.items {
+            match &item.ref_type {
+                Some(ref_type) => writeln!(f, "{} ({})", item.id, ref_type)?,
+                None => writeln!(f, "{} (not found)", item.id)?,
+            }
+            for trace in &item.components {
+                match &trace.outcome {
+                    ComponentOutcome::Rendered {
+                        value,
+                        substituted: Some(sub),
+                    } => writeln!(
+                        f,
+                        "  + {}: {:?} (substituted {})",
+                        trace.component, value, sub
+                    )?,
+                    ComponentOutcome::Rendered { value, .. } => {
+                        writeln!(f, "  + {}: {:?}", trace.component, value)?
+                    }
+                    ComponentOutcome::Suppressed { reason } => {
+                        writeln!(f, "  - {}: {:?}", trace.component, reason)?
+                    }
+                }
+            }
+            for decision in &item.disambiguation {
+                writeln!(f, "  * {:?}", decision)?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -20,8 +20,10 @@ SPDX-FileCopyrightText: © 2023-2026 Bruce D'Arcus
 //!
 //! This is tracked via `rendered_vars` in `process_template()`.
 
+pub mod catalog;
 pub mod disambiguation;
 pub mod document;
+pub mod explain;
 pub mod labels;
 pub mod matching;
 pub mod rendering;
@@ -35,13 +37,20 @@ use crate::reference::{Bibliography, Citation, CitationItem, Reference};
 use crate::render::{ProcEntry, ProcTemplate};
 use crate::values::ProcHints;
 use csln_core::Style;
+use csln_core::Template;
 use csln_core::locale::Locale;
 use csln_core::options::Config;
-use csln_core::template::WrapPunctuation;
+use csln_core::template::TypeSelector;
+use csln_core::template::{CitationNumberForm, WrapPunctuation};
+use indexmap::IndexMap;
 use std::cell::RefCell;
 use std::collections::{HashMap, HashSet};
 
+use self::catalog::CatalogEntry;
 use self::disambiguation::Disambiguator;
+use self::explain::{
+    CitationExplanation, DisambiguationDecision, ItemExplanation, MissingVariables,
+};
 use self::matching::Matcher;
 use self::rendering::Renderer;
 use self::sorting::Sorter;
@@ -57,6 +66,9 @@ pub struct Processor {
     pub bibliography: Bibliography,
     /// The locale for terms and formatting.
     pub locale: Locale,
+    /// Optional locale for month names and other date parts; terms still
+    /// follow `locale`.
+    pub date_locale: Option<Locale>,
     /// Default configuration.
     pub default_config: Config,
     /// Pre-calculated processing hints.
@@ -65,6 +77,28 @@ pub struct Processor {
     pub citation_numbers: RefCell<HashMap<String, usize>>,
     /// IDs of items that were cited in a visible way.
     pub cited_ids: RefCell<HashSet<String>>,
+    /// Callbacks applied to each rendered citation and bibliography entry.
+    pub post_processors: PostProcessors,
+}
+
+/// A callback that transforms a rendered citation or bibliography entry.
+pub type PostProcessor = Box<dyn Fn(&str) -> String + Send + Sync>;
+
+/// Post-processing callbacks, applied in registration order.
+#[derive(Default)]
+pub struct PostProcessors(Vec<PostProcessor>);
+
+impl PostProcessors {
+    /// Pass rendered output through each callback in turn.
+    pub fn apply(&self, output: String) -> String {
+        self.0.iter().fold(output, |output, hook| hook(&output))
+    }
+}
+
+impl std::fmt::Debug for PostProcessors {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "PostProcessors({})", self.0.len())
+    }
 }
 
 impl Default for Processor {
@@ -73,10 +107,12 @@ impl Default for Processor {
             style: Style::default(),
             bibliography: Bibliography::default(),
             locale: Locale::en_us(),
+            date_locale: None,
             default_config: Config::default(),
             hints: HashMap::new(),
             citation_numbers: RefCell::new(HashMap::new()),
             cited_ids: RefCell::new(HashSet::new()),
+            post_processors: PostProcessors::default(),
         }
     }
 }
@@ -134,6 +170,11 @@ impl Processor {
     /// When the style declares an explicit bibliography sort, citation numbers
     /// must follow that sorted bibliography order.
     fn initialize_numeric_citation_numbers(&self) {
+        self.fill_numeric_citation_numbers(&mut self.citation_numbers.borrow_mut());
+    }
+
+    /// Assign numeric citation numbers into `numbers` if it is still empty.
+    fn fill_numeric_citation_numbers(&self, numbers: &mut HashMap<String, usize>) {
         let is_numeric = self
             .get_config()
             .processing
@@ -143,7 +184,6 @@ impl Processor {
             return;
         }
 
-        let mut numbers = self.citation_numbers.borrow_mut();
         if !numbers.is_empty() {
             return;
         }
@@ -154,7 +194,7 @@ impl Processor {
             .as_ref()
             .and_then(|b| b.sort.as_ref())
         {
-            let sorter = crate::grouping::GroupSorter::new(&self.locale);
+            let sorter = self.group_sorter();
             sorter
                 .sort_references(self.bibliography.values().collect(), sort_spec)
                 .into_iter()
@@ -180,10 +220,12 @@ impl Processor {
             style,
             bibliography,
             locale,
+            date_locale: None,
             default_config: Config::default(),
             hints: HashMap::new(),
             citation_numbers: RefCell::new(HashMap::new()),
             cited_ids: RefCell::new(HashSet::new()),
+            post_processors: PostProcessors::default(),
         };
 
         // Pre-calculate hints for disambiguation
@@ -206,11 +248,106 @@ impl Processor {
         Self::with_locale(style, bibliography, locale)
     }
 
+    /// Render dates with another locale's month names, e.g., English months
+    /// in an otherwise German bibliography.
+    pub fn set_date_locale(&mut self, locale: Locale) {
+        self.date_locale = Some(locale);
+    }
+
+    /// Add a callback that transforms each rendered citation and
+    /// bibliography entry, e.g. to inject tracking spans.
+    ///
+    /// Callbacks run after formatting, in the order they were added.
+    pub fn with_post_processor(mut self, hook: PostProcessor) -> Self {
+        self.post_processors.0.push(hook);
+        self
+    }
+
+    /// Register a bibliography template for a reference type at runtime.
+    ///
+    /// The template is added to the style's `type-templates` and takes
+    /// precedence over both the default template and any existing type
+    /// template that matches `item_type`.
+    pub fn register_type_template(&mut self, item_type: String, template: Template) {
+        self.style
+            .bibliography
+            .get_or_insert_with(Default::default)
+            .type_templates
+            .get_or_insert_with(HashMap::new)
+            .insert(TypeSelector::Single(item_type), template);
+    }
+
+    /// Replace the bibliography, e.g. after an editor reloads its library.
+    ///
+    /// Disambiguation hints are recalculated and assigned citation numbers
+    /// are discarded. Cited IDs missing from the new bibliography are
+    /// forgotten.
+    pub fn set_bibliography(&mut self, bibliography: Bibliography) {
+        self.bibliography = bibliography;
+        self.cited_ids
+            .borrow_mut()
+            .retain(|id| self.bibliography.contains_key(id));
+        self.invalidate();
+    }
+
+    /// Insert a reference, or replace the one with the same ID.
+    ///
+    /// A replaced reference keeps its position in the bibliography.
+    pub fn upsert_reference(&mut self, id: impl Into<String>, reference: Reference) {
+        self.bibliography.insert(id.into(), reference);
+        self.invalidate();
+    }
+
+    /// Recompute state derived from the bibliography.
+    fn invalidate(&mut self) {
+        self.citation_numbers.borrow_mut().clear();
+        self.hints = self.calculate_hints();
+    }
+
+    /// IDs of references cited so far by processed citations or documents.
+    pub fn used_references(&self) -> std::cell::Ref<'_, HashSet<String>> {
+        self.cited_ids.borrow()
+    }
+
+    /// IDs of bibliography entries that have not been cited, in
+    /// bibliography order.
+    pub fn uncited_references(&self) -> Vec<&str> {
+        let cited_ids = self.cited_ids.borrow();
+        self.bibliography
+            .keys()
+            .filter(|id| !cited_ids.contains(*id))
+            .map(String::as_str)
+            .collect()
+    }
+
     /// Get the style configuration.
     pub fn get_config(&self) -> &Config {
         self.style.options.as_ref().unwrap_or(&self.default_config)
     }
 
+    /// The lowercase non-dropping particle opening a reference's first name,
+    /// if any (e.g., "van" in "van Gogh").
+    fn leading_particle(&self, id: &str) -> Option<String> {
+        let reference = self.bibliography.get(id)?;
+        let names = reference.author().or_else(|| reference.editor())?;
+        names
+            .to_names_vec()
+            .into_iter()
+            .next()?
+            .non_dropping_particle
+            .filter(|p| p.chars().next().is_some_and(char::is_lowercase))
+    }
+
+    /// Group sorter honoring the style's particle demotion for name keys.
+    fn group_sorter(&self) -> crate::grouping::GroupSorter<'_> {
+        let demote = self
+            .get_bibliography_config()
+            .contributors
+            .as_ref()
+            .and_then(|c| c.demote_non_dropping_particle);
+        crate::grouping::GroupSorter::new(&self.locale).with_particle_demotion(demote)
+    }
+
     /// Get merged config for citation context.
     ///
     /// Combines global options with citation-specific overrides.
@@ -275,6 +412,7 @@ impl Processor {
                             &self.style,
                             &self.bibliography,
                             &self.locale,
+                            self.date_locale.as_ref(),
                             &bib_config,
                             &self.hints,
                             &self.citation_numbers,
@@ -311,6 +449,8 @@ impl Processor {
             suppress_author: false,
             locator: None,
             locator_label: None,
+            locators: None,
+            date_locale: self.date_locale.as_ref(),
         };
 
         ProcEntryMetadata {
@@ -319,6 +459,28 @@ impl Processor {
                 .map(|a| crate::values::format_contributors_short(&a.to_names_vec(), &options)),
             year: reference.issued().map(|i| i.year().to_string()),
             title: reference.title().map(|t| t.to_string()),
+            annotation: self
+                .get_bibliography_config()
+                .bibliography
+                .as_ref()
+                .is_some_and(|bib| bib.annotate)
+                .then(|| reference.note())
+                .flatten()
+                .filter(|note| !note.is_empty()),
+            abstract_text: self
+                .get_bibliography_config()
+                .bibliography
+                .as_ref()
+                .filter(|bib| bib.show_abstract)
+                .and_then(|bib| {
+                    reference
+                        .abstract_text()
+                        .filter(|text| !text.is_empty())
+                        .map(|text| match bib.abstract_max_length {
+                            Some(max) => crate::values::truncate_words(&text, max),
+                            None => text,
+                        })
+                }),
         }
     }
 
@@ -340,6 +502,7 @@ impl Processor {
             &self.style,
             &self.bibliography,
             &self.locale,
+            self.date_locale.as_ref(),
             &bib_config,
             &self.hints,
             &self.citation_numbers,
@@ -356,7 +519,7 @@ impl Processor {
             .as_ref()
             .and_then(|b| b.sort.as_ref())
         {
-            let sorter = crate::grouping::GroupSorter::new(&self.locale);
+            let sorter = self.group_sorter();
             return sorter.sort_references(references, sort_spec);
         }
 
@@ -376,7 +539,7 @@ impl Processor {
                 .filter_map(|item| self.bibliography.get(&item.id).map(|r| (item, r)))
                 .collect();
 
-            let sorter = crate::grouping::GroupSorter::new(&self.locale);
+            let sorter = self.group_sorter();
             items_with_refs.sort_by(|a, b| {
                 for sort_key in &sort_spec.template {
                     let cmp = sorter.compare_by_key(a.1, b.1, sort_key);
@@ -425,6 +588,7 @@ impl Processor {
             &self.style,
             &self.bibliography,
             &self.locale,
+            self.date_locale.as_ref(),
             self.get_config(),
             &self.hints,
             &self.citation_numbers,
@@ -466,6 +630,7 @@ impl Processor {
                         &self.style,
                         &self.bibliography,
                         &self.locale,
+                        self.date_locale.as_ref(),
                         &bib_config,
                         &self.hints,
                         &self.citation_numbers,
@@ -482,7 +647,7 @@ impl Processor {
             }
         }
 
-        crate::render::refs_to_string_with_format::<F>(bibliography)
+        self.render_entries::<F>(bibliography)
     }
 
     /// Process a bibliography entry with specific format.
@@ -501,6 +666,7 @@ impl Processor {
             &self.style,
             &self.bibliography,
             &self.locale,
+            self.date_locale.as_ref(),
             &bib_config,
             &self.hints,
             &self.citation_numbers,
@@ -509,10 +675,30 @@ impl Processor {
     }
 
     /// Render a citation to a string using a specific format.
+    ///
+    /// The citation renders as if it were the first in the document; use
+    /// [`Processor::process_citations_with_format`] or
+    /// [`Processor::process_citation_after_with_format`] for repeat
+    /// citations.
     pub fn process_citation_with_format<F>(
         &self,
         citation: &Citation,
     ) -> Result<String, ProcessorError>
+    where
+        F: crate::render::format::OutputFormat<Output = String>,
+    {
+        self.process_citation_after_with_format::<F>(citation, &HashSet::new())
+    }
+
+    /// Render a citation that follows others in the same document.
+    ///
+    /// Items whose IDs are in `cited_before` take their subsequent form
+    /// (e.g., fewer names before et al.).
+    pub fn process_citation_after_with_format<F>(
+        &self,
+        citation: &Citation,
+        cited_before: &HashSet<String>,
+    ) -> Result<String, ProcessorError>
     where
         F: crate::render::format::OutputFormat<Output = String>,
     {
@@ -521,7 +707,20 @@ impl Processor {
         for item in &citation.items {
             self.cited_ids.borrow_mut().insert(item.id.clone());
         }
+        self.render_citation_with_format::<F>(citation, cited_before, &self.citation_numbers)
+    }
 
+    /// Render a citation against the given citation numbers, without
+    /// recording its items as cited.
+    fn render_citation_with_format<F>(
+        &self,
+        citation: &Citation,
+        cited_before: &HashSet<String>,
+        citation_numbers: &RefCell<HashMap<String, usize>>,
+    ) -> Result<String, ProcessorError>
+    where
+        F: crate::render::format::OutputFormat<Output = String>,
+    {
         // Resolve the effective citation spec
         let default_spec = csln_core::CitationSpec::default();
         let effective_spec = self
@@ -555,14 +754,16 @@ impl Processor {
             processing,
             csln_core::options::Processing::Numeric | csln_core::options::Processing::Label(_)
         );
-        let renderer = Renderer::new(
+        let mut renderer = Renderer::new(
             &self.style,
             &self.bibliography,
             &self.locale,
+            self.date_locale.as_ref(),
             &cite_config,
             &self.hints,
-            &self.citation_numbers,
+            citation_numbers,
         );
+        renderer.cited_before = Some(cited_before);
 
         // Process group components
         let rendered_groups = if is_author_date {
@@ -583,32 +784,36 @@ impl Processor {
             )?
         };
 
+        // A note citation opens a sentence, so a leading particle is
+        // capitalized ("Van Gogh" rather than "van Gogh").
+        let mut rendered_groups = rendered_groups;
+        if self.is_note_style()
+            && citation.prefix.is_none()
+            && let Some(item) = sorted_items.first()
+            && item.prefix.is_none()
+            && let Some(particle) = self.leading_particle(&item.id)
+            && let Some(first) = rendered_groups.first_mut()
+            && first.starts_with(particle.as_str())
+        {
+            *first = crate::values::capitalize_first(first);
+        }
+
         let fmt = F::default();
-        let content = fmt.join(rendered_groups, inter_delimiter);
 
         // Apply citation-level prefix/suffix from input
         let citation_prefix = citation.prefix.as_deref().unwrap_or("");
         let citation_suffix = citation.suffix.as_deref().unwrap_or("");
 
         // Ensure proper spacing for prefix/suffix
-        let formatted_prefix =
-            if !citation_prefix.is_empty() && !citation_prefix.ends_with(char::is_whitespace) {
-                format!("{} ", citation_prefix)
-            } else {
-                citation_prefix.to_string()
-            };
+        let formatted_prefix = rendering::space_prefix(citation_prefix);
+        let formatted_suffix = rendering::space_suffix(citation_suffix);
 
-        let formatted_suffix =
-            if !citation_suffix.is_empty() && !citation_suffix.starts_with(char::is_whitespace) {
-                format!(" {}", citation_suffix)
+        let affix_citation = |content: String, prefix: &str, suffix: &str| {
+            if !prefix.is_empty() || !suffix.is_empty() {
+                fmt.affix(prefix, content, suffix)
             } else {
-                citation_suffix.to_string()
-            };
-
-        let output = if !citation_prefix.is_empty() || !citation_suffix.is_empty() {
-            fmt.affix(&formatted_prefix, content, &formatted_suffix)
-        } else {
-            content
+                content
+            }
         };
 
         // Get wrap/prefix/suffix from citation spec
@@ -618,26 +823,305 @@ impl Processor {
             .unwrap_or(&WrapPunctuation::None);
         let spec_prefix = effective_spec.prefix.as_deref().unwrap_or("");
         let spec_suffix = effective_spec.suffix.as_deref().unwrap_or("");
-
-        // For integral (narrative) citations, don't apply wrapping
-        // (they're part of the narrative text, not parenthetical)
-        let wrapped = if matches!(citation.mode, csln_core::citation::CitationMode::Integral) {
-            // Integral mode: skip wrapping, apply only prefix/suffix
-            if !spec_prefix.is_empty() || !spec_suffix.is_empty() {
+        let is_integral = matches!(citation.mode, csln_core::citation::CitationMode::Integral);
+
+        let wrap_citation = |output: String| {
+            // For integral (narrative) citations, don't apply wrapping
+            // (they're part of the narrative text, not parenthetical)
+            if is_integral {
+                // Integral mode: skip wrapping, apply only prefix/suffix
+                if !spec_prefix.is_empty() || !spec_suffix.is_empty() {
+                    fmt.affix(spec_prefix, output, spec_suffix)
+                } else {
+                    output
+                }
+            } else if let Some(form) = &effective_spec.citation_number_form {
+                // Non-integral numeric citation: the number form replaces wrap
+                match form {
+                    CitationNumberForm::Bracket => {
+                        fmt.wrap_punctuation(&WrapPunctuation::Brackets, output)
+                    }
+                    CitationNumberForm::Parenthesis => {
+                        fmt.wrap_punctuation(&WrapPunctuation::Parentheses, output)
+                    }
+                    CitationNumberForm::Superscript => fmt.superscript(output),
+                    CitationNumberForm::Plain => output,
+                }
+            } else if *wrap != WrapPunctuation::None {
+                // Non-integral mode: apply wrap
+                fmt.wrap_punctuation(wrap, output)
+            } else if !spec_prefix.is_empty() || !spec_suffix.is_empty() {
                 fmt.affix(spec_prefix, output, spec_suffix)
             } else {
                 output
             }
-        } else if *wrap != WrapPunctuation::None {
-            // Non-integral mode: apply wrap
-            fmt.wrap_punctuation(wrap, output)
-        } else if !spec_prefix.is_empty() || !spec_suffix.is_empty() {
-            fmt.affix(spec_prefix, output, spec_suffix)
+        };
+
+        let merge_parentheticals = effective_spec.merge_parentheticals.unwrap_or(true);
+        let wrapped = if !merge_parentheticals && !is_integral && rendered_groups.len() > 1 {
+            // Separate parentheticals: the input prefix opens the first and
+            // the input suffix closes the last.
+            let last = rendered_groups.len() - 1;
+            let parts = rendered_groups
+                .into_iter()
+                .enumerate()
+                .map(|(i, group)| {
+                    let prefix = if i == 0 {
+                        formatted_prefix.as_str()
+                    } else {
+                        ""
+                    };
+                    let suffix = if i == last {
+                        formatted_suffix.as_str()
+                    } else {
+                        ""
+                    };
+                    wrap_citation(affix_citation(group, prefix, suffix))
+                })
+                .collect();
+            fmt.join(parts, " ")
         } else {
-            output
+            let content = fmt.join(rendered_groups, inter_delimiter);
+            wrap_citation(affix_citation(
+                content,
+                &formatted_prefix,
+                &formatted_suffix,
+            ))
         };
 
-        Ok(fmt.finish(wrapped))
+        Ok(self.post_processors.apply(fmt.finish(wrapped)))
+    }
+
+    /// Render bibliography entries, applying post-processing callbacks.
+    fn render_entries<F>(&self, entries: Vec<ProcEntry>) -> String
+    where
+        F: crate::render::format::OutputFormat<Output = String>,
+    {
+        crate::render::bibliography::refs_to_string_with_post_processor::<F>(entries, |entry| {
+            self.post_processors.apply(entry)
+        })
+    }
+
+    /// Explain how a citation renders.
+    ///
+    /// Returns a trace of which template components fired for each cited
+    /// item, which were suppressed (and why), and which disambiguation or
+    /// substitution decisions applied. Intended for style debugging.
+    pub fn explain_citation(&self, citation: &Citation) -> CitationExplanation {
+        let output = self.process_citation(citation).ok();
+
+        let default_spec = csln_core::CitationSpec::default();
+        let effective_spec = self
+            .style
+            .citation
+            .as_ref()
+            .map(|cs| cs.resolve_for_mode(&citation.mode))
+            .unwrap_or(std::borrow::Cow::Borrowed(&default_spec));
+        let template = effective_spec.resolve_template().unwrap_or_default();
+
+        let cite_config = self.get_citation_config();
+        let renderer = Renderer::new(
+            &self.style,
+            &self.bibliography,
+            &self.locale,
+            self.date_locale.as_ref(),
+            &cite_config,
+            &self.hints,
+            &self.citation_numbers,
+        );
+
+        let items = self
+            .sort_citation_items(citation.items.clone(), &effective_spec)
+            .into_iter()
+            .map(|item| {
+                let Some(reference) = self.bibliography.get(&item.id) else {
+                    return ItemExplanation {
+                        id: item.id,
+                        ..Default::default()
+                    };
+                };
+                let citation_number = self
+                    .citation_numbers
+                    .borrow()
+                    .get(&item.id)
+                    .copied()
+                    .unwrap_or(0);
+                let options = crate::values::RenderOptions {
+                    config: &cite_config,
+                    locale: &self.locale,
+                    context: crate::values::RenderContext::Citation,
+                    mode: citation.mode.clone(),
+                    suppress_author: citation.suppress_author,
+                    locator: item.locator.as_deref(),
+                    locator_label: item.label.clone(),
+                    locators: item.locators.as_deref(),
+                    date_locale: self.date_locale.as_ref(),
+                };
+                let components =
+                    renderer.explain_template(reference, &template, options, citation_number);
+                let disambiguation = self
+                    .hints
+                    .get(&item.id)
+                    .map(DisambiguationDecision::from_hints)
+                    .unwrap_or_default();
+                ItemExplanation {
+                    ref_type: Some(reference.ref_type()),
+                    components,
+                    disambiguation,
+                    id: item.id,
+                }
+            })
+            .collect();
+
+        CitationExplanation {
+            mode: citation.mode.clone(),
+            output,
+            items,
+        }
+    }
+
+    /// Explain how a bibliography entry renders.
+    ///
+    /// Like [`Processor::explain_citation`], but traces the bibliography
+    /// template for the reference's type. Disambiguation decisions are left
+    /// to the citation trace. An ID not in the bibliography gives an item
+    /// with no reference type.
+    pub fn explain_bibliography_entry(&self, id: &str) -> ItemExplanation {
+        let Some(reference) = self.bibliography.get(id) else {
+            return ItemExplanation {
+                id: id.to_string(),
+                ..Default::default()
+            };
+        };
+        let bib_config = self.get_bibliography_config();
+        let renderer = Renderer::new(
+            &self.style,
+            &self.bibliography,
+            &self.locale,
+            self.date_locale.as_ref(),
+            &bib_config,
+            &self.hints,
+            &self.citation_numbers,
+        );
+        let entry_number = self.citation_numbers.borrow().get(id).copied().unwrap_or(0);
+        let components = renderer
+            .bibliography_template(&reference.ref_type())
+            .map(|template| {
+                renderer.explain_template(
+                    reference,
+                    &template,
+                    renderer.bibliography_options(),
+                    entry_number,
+                )
+            })
+            .unwrap_or_default();
+        ItemExplanation {
+            id: id.to_string(),
+            ref_type: Some(reference.ref_type()),
+            components,
+            disambiguation: Vec::new(),
+        }
+    }
+
+    /// Check which variables the bibliography template needs but the data lacks.
+    ///
+    /// Returns one entry per reference, in bibliography order, listing the
+    /// template-referenced variables it has no value for. References with
+    /// nothing missing are omitted, so an empty result means the data covers
+    /// the style. Intended as a preflight for UIs that warn about gaps.
+    pub fn validate_style_against_data(&self) -> Vec<MissingVariables> {
+        let bib_config = self.get_bibliography_config();
+        let renderer = Renderer::new(
+            &self.style,
+            &self.bibliography,
+            &self.locale,
+            self.date_locale.as_ref(),
+            &bib_config,
+            &self.hints,
+            &self.citation_numbers,
+        );
+        let options = renderer.bibliography_options();
+
+        self.bibliography
+            .iter()
+            .filter_map(|(id, reference)| {
+                let template = renderer.bibliography_template(&reference.ref_type())?;
+                let variables = renderer.missing_variables(reference, &template, &options);
+                (!variables.is_empty()).then(|| MissingVariables {
+                    id: id.clone(),
+                    variables,
+                })
+            })
+            .collect()
+    }
+
+    /// Render a reference as a library catalog record.
+    ///
+    /// The call number, title, author, and shelf location are rendered in a
+    /// fixed catalog format with default options, so the record looks the
+    /// same whatever the style. Terms still follow the processor's locale.
+    /// Returns `None` if the ID is not in the bibliography.
+    pub fn catalog_entry(&self, id: &str) -> Option<CatalogEntry> {
+        let reference = self.bibliography.get(id)?;
+        let catalog_config = Config::default();
+        let renderer = Renderer::new(
+            &self.style,
+            &self.bibliography,
+            &self.locale,
+            self.date_locale.as_ref(),
+            &catalog_config,
+            &self.hints,
+            &self.citation_numbers,
+        );
+        Some(CatalogEntry::from_reference(&renderer, id, reference))
+    }
+
+    /// Compute a fingerprint of a citation's rendering.
+    ///
+    /// The hash covers the rendered output, the citation mode, and each cited
+    /// item's citation number and disambiguation decisions. It uses FNV-1a
+    /// over a fixed byte encoding, so callers can compare fingerprints
+    /// between runs and builds to cheaply detect which citations changed.
+    ///
+    /// Fingerprinting does not mark the citation's items as cited or assign
+    /// citation numbers.
+    pub fn citation_fingerprint(&self, citation: &Citation) -> u64 {
+        use std::hash::Hasher;
+
+        let citation_numbers = RefCell::new(self.citation_numbers.borrow().clone());
+        self.fill_numeric_citation_numbers(&mut citation_numbers.borrow_mut());
+
+        let mut hasher = fnv::FnvHasher::default();
+        // Length-prefix each string so adjacent fields cannot run together.
+        let write_str = |hasher: &mut fnv::FnvHasher, s: &str| {
+            hasher.write_u64(s.len() as u64);
+            hasher.write(s.as_bytes());
+        };
+        let output = self.render_citation_with_format::<crate::render::plain::PlainText>(
+            citation,
+            &HashSet::new(),
+            &citation_numbers,
+        );
+        match output {
+            Ok(output) => write_str(&mut hasher, &output),
+            Err(err) => write_str(&mut hasher, &err.to_string()),
+        }
+        write_str(&mut hasher, &format!("{:?}", citation.mode));
+        for item in &citation.items {
+            write_str(&mut hasher, &item.id);
+            let number = citation_numbers.borrow().get(&item.id).copied();
+            hasher.write_u64(number.map_or(0, |n| n as u64 + 1));
+            let decisions = self
+                .hints
+                .get(&item.id)
+                .map(DisambiguationDecision::from_hints)
+                .unwrap_or_default();
+            write_str(
+                &mut hasher,
+                &serde_json::to_string(&decisions).unwrap_or_default(),
+            );
+        }
+        hasher.finish()
     }
 
     /// Render multiple citations in order with note-context normalization.
@@ -654,9 +1138,14 @@ impl Processor {
         F: crate::render::format::OutputFormat<Output = String>,
     {
         let normalized = self.normalize_note_context(citations);
+        let mut cited_before = HashSet::new();
         normalized
             .iter()
-            .map(|c| self.process_citation_with_format::<F>(c))
+            .map(|c| {
+                let rendered = self.process_citation_after_with_format::<F>(c, &cited_before);
+                cited_before.extend(c.items.iter().map(|item| item.id.clone()));
+                rendered
+            })
             .collect()
     }
 
@@ -668,8 +1157,9 @@ impl Processor {
     /// Render the bibliography with grouping for uncited (nocite) items.
     ///
     /// If `style.bibliography.groups` is defined, uses configurable grouping
-    /// with per-group sorting. Otherwise, falls back to hardcoded cited/uncited
-    /// grouping for backward compatibility.
+    /// with per-group sorting. If `keyword-groups` is defined, divides entries
+    /// into sections by keyword. Otherwise, falls back to hardcoded
+    /// cited/uncited grouping for backward compatibility.
     pub fn render_grouped_bibliography_with_format<F>(&self) -> String
     where
         F: crate::render::format::OutputFormat<Output = String>,
@@ -683,10 +1173,71 @@ impl Processor {
             return self.render_with_custom_groups::<F>(&processed.bibliography, groups);
         }
 
+        if let Some(bib_spec) = &self.style.bibliography
+            && let Some(keyword_groups) = &bib_spec.keyword_groups
+        {
+            return self.render_with_keyword_groups::<F>(&processed.bibliography, keyword_groups);
+        }
+
         // Fallback to hardcoded cited/uncited grouping
         self.render_with_legacy_grouping::<F>(&processed.bibliography)
     }
 
+    /// Render a separate bibliography for each keyword category.
+    ///
+    /// References are partitioned as for `keyword-groups`, using the style's
+    /// mapping when it defines one, and each section is sorted and numbered
+    /// on its own. Returns the rendered bibliographies keyed by section label,
+    /// in section order.
+    pub fn render_named_bibliographies(&self) -> IndexMap<String, String> {
+        self.render_named_bibliographies_with_format::<crate::render::plain::PlainText>()
+    }
+
+    /// Render a separate bibliography for each keyword category using a
+    /// specific format.
+    pub fn render_named_bibliographies_with_format<F>(&self) -> IndexMap<String, String>
+    where
+        F: crate::render::format::OutputFormat<Output = String>,
+    {
+        let default_grouping = csln_core::KeywordGrouping::default();
+        let grouping = self
+            .style
+            .bibliography
+            .as_ref()
+            .and_then(|bib_spec| bib_spec.keyword_groups.as_ref())
+            .unwrap_or(&default_grouping);
+        let bib_config = self.get_bibliography_config();
+        let renderer = Renderer::new(
+            &self.style,
+            &self.bibliography,
+            &self.locale,
+            self.date_locale.as_ref(),
+            &bib_config,
+            &self.hints,
+            &self.citation_numbers,
+        );
+
+        crate::grouping::partition_by_keyword(self.bibliography.values().collect(), grouping)
+            .into_iter()
+            .map(|(label, section)| {
+                let entries: Vec<ProcEntry> = self
+                    .sort_references(section)
+                    .into_iter()
+                    .enumerate()
+                    .filter_map(|(i, r)| {
+                        Some(ProcEntry {
+                            id: r.id().unwrap_or_default(),
+                            template: renderer
+                                .process_bibliography_entry_with_format::<F>(r, i + 1)?,
+                            metadata: self.extract_metadata(r),
+                        })
+                    })
+                    .collect();
+                (label, self.render_entries::<F>(entries))
+            })
+            .collect()
+    }
+
     fn resolve_group_heading(&self, heading: &csln_core::GroupHeading) -> Option<String> {
         match heading {
             csln_core::GroupHeading::Literal { literal } => Some(literal.clone()),
@@ -745,7 +1296,7 @@ impl Processor {
     where
         F: crate::render::format::OutputFormat<Output = String>,
     {
-        use crate::grouping::{GroupSorter, SelectorEvaluator};
+        use crate::grouping::SelectorEvaluator;
         use csln_core::grouping::DisambiguationScope;
         use std::collections::HashSet;
 
@@ -753,7 +1304,7 @@ impl Processor {
         let cited_ids = self.cited_ids.borrow();
 
         let evaluator = SelectorEvaluator::new(&cited_ids);
-        let sorter = GroupSorter::new(&self.locale);
+        let sorter = self.group_sorter();
 
         let mut assigned: HashSet<String> = HashSet::new();
         let mut result = String::new();
@@ -830,6 +1381,7 @@ impl Processor {
                     &effective_style,
                     &self.bibliography,
                     &self.locale,
+                    self.date_locale.as_ref(),
                     &bib_config,
                     hints,
                     &self.citation_numbers,
@@ -868,7 +1420,7 @@ impl Processor {
             }
 
             // Render entries
-            result.push_str(&crate::render::refs_to_string_with_format::<F>(entries_vec));
+            result.push_str(&self.render_entries::<F>(entries_vec));
         }
 
         // Fallback for ungrouped items
@@ -882,7 +1434,46 @@ impl Processor {
             if !result.is_empty() {
                 result.push_str("\n\n");
             }
-            result.push_str(&crate::render::refs_to_string_with_format::<F>(unassigned));
+            result.push_str(&self.render_entries::<F>(unassigned));
+        }
+
+        fmt.finish(result)
+    }
+
+    /// Render bibliography sections derived from reference keywords.
+    fn render_with_keyword_groups<F>(
+        &self,
+        bibliography: &[ProcEntry],
+        grouping: &csln_core::KeywordGrouping,
+    ) -> String
+    where
+        F: crate::render::format::OutputFormat<Output = String>,
+    {
+        let fmt = F::default();
+        let refs: Vec<&Reference> = bibliography
+            .iter()
+            .filter_map(|entry| self.bibliography.get(&entry.id))
+            .collect();
+        let entries_by_id: HashMap<&str, &ProcEntry> = bibliography
+            .iter()
+            .map(|entry| (entry.id.as_str(), entry))
+            .collect();
+
+        let mut result = String::new();
+        for (label, section) in crate::grouping::partition_by_keyword(refs, grouping) {
+            let entries: Vec<ProcEntry> = section
+                .into_iter()
+                .filter_map(|r| {
+                    let id = r.id()?;
+                    entries_by_id.get(id.as_str()).map(|&entry| entry.clone())
+                })
+                .collect();
+
+            if !result.is_empty() {
+                result.push_str("\n\n");
+            }
+            result.push_str(&format!("# {}\n\n", label));
+            result.push_str(&self.render_entries::<F>(entries));
         }
 
         fmt.finish(result)
@@ -906,9 +1497,7 @@ impl Processor {
         let mut result = String::new();
 
         if !cited_entries.is_empty() {
-            result.push_str(&crate::render::refs_to_string_with_format::<F>(
-                cited_entries,
-            ));
+            result.push_str(&self.render_entries::<F>(cited_entries));
         }
 
         fmt.finish(result)
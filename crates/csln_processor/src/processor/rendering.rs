@@ -1,11 +1,15 @@
 use crate::error::ProcessorError;
+use crate::processor::explain::{
+    ComponentOutcome, ComponentTrace, SuppressionReason, component_label, is_style_suppressed,
+};
 use crate::reference::{Bibliography, Reference};
 use crate::render::{ProcTemplate, ProcTemplateComponent};
 use crate::values::{ComponentValues, ProcHints, RenderContext, RenderOptions};
 use csln_core::locale::Locale;
 use csln_core::options::Config;
-use csln_core::template::ComponentOverride;
 use csln_core::template::TemplateComponent;
+use csln_core::template::{ComponentOverride, ComponentPosition};
+use std::borrow::Cow;
 use std::cell::RefCell;
 use std::collections::{HashMap, HashSet};
 
@@ -13,9 +17,13 @@ pub struct Renderer<'a> {
     pub style: &'a csln_core::Style,
     pub bibliography: &'a Bibliography,
     pub locale: &'a Locale,
+    pub date_locale: Option<&'a Locale>,
     pub config: &'a Config,
     pub hints: &'a HashMap<String, ProcHints>,
     pub citation_numbers: &'a RefCell<HashMap<String, usize>>,
+    /// IDs cited earlier in the document; these items take their subsequent
+    /// form.
+    pub cited_before: Option<&'a HashSet<String>>,
 }
 
 impl<'a> Renderer<'a> {
@@ -23,6 +31,7 @@ impl<'a> Renderer<'a> {
         style: &'a csln_core::Style,
         bibliography: &'a Bibliography,
         locale: &'a Locale,
+        date_locale: Option<&'a Locale>,
         config: &'a Config,
         hints: &'a HashMap<String, ProcHints>,
         citation_numbers: &'a RefCell<HashMap<String, usize>>,
@@ -31,12 +40,23 @@ impl<'a> Renderer<'a> {
             style,
             bibliography,
             locale,
+            date_locale,
             config,
             hints,
             citation_numbers,
+            cited_before: None,
         }
     }
 
+    /// Disambiguation hints for a reference, marked `subsequent` if it was
+    /// cited earlier in the document.
+    fn hints_for(&self, reference: &Reference) -> ProcHints {
+        let id = reference.id().unwrap_or_default();
+        let mut hints = self.hints.get(&id).cloned().unwrap_or_default();
+        hints.subsequent = self.cited_before.is_some_and(|ids| ids.contains(&id));
+        hints
+    }
+
     /// Check if this is a numeric style with integral mode.
     fn should_render_author_year_for_numeric_integral(
         &self,
@@ -99,24 +119,6 @@ impl<'a> Renderer<'a> {
         !has_explicit_integral
     }
 
-    /// Ensure suffix has proper spacing (add space if suffix doesn't start with
-    /// punctuation and isn't empty).
-    fn ensure_suffix_spacing(&self, suffix: &str) -> String {
-        if suffix.is_empty() {
-            String::new()
-        } else if suffix.starts_with(char::is_whitespace)
-            || suffix.starts_with(',')
-            || suffix.starts_with(';')
-            || suffix.starts_with('.')
-        {
-            // Already has leading space or punctuation
-            suffix.to_string()
-        } else {
-            // Add space before suffix to separate from content
-            format!(" {}", suffix)
-        }
-    }
-
     /// Render author + citation number for numeric integral citations.
     fn render_author_year_for_numeric_integral_with_format<F>(
         &self,
@@ -131,11 +133,13 @@ impl<'a> Renderer<'a> {
         let options = RenderOptions {
             config: self.config,
             locale: self.locale,
+            date_locale: self.date_locale,
             context: RenderContext::Citation,
             mode: csln_core::citation::CitationMode::Integral,
             suppress_author: false,
             locator: item.locator.as_deref(),
             locator_label: item.label.clone(),
+            locators: item.locators.as_deref(),
         };
 
         // Render author in short form
@@ -201,11 +205,13 @@ impl<'a> Renderer<'a> {
         let options = RenderOptions {
             config: self.config,
             locale: self.locale,
+            date_locale: self.date_locale,
             context: RenderContext::Citation,
             mode: csln_core::citation::CitationMode::Integral,
             suppress_author: false,
             locator: item.locator.as_deref(),
             locator_label: item.label.clone(),
+            locators: item.locators.as_deref(),
         };
 
         if let Some(contributor) = reference.author().or_else(|| reference.editor()) {
@@ -296,15 +302,10 @@ impl<'a> Renderer<'a> {
                     let prefix = item.prefix.as_deref().unwrap_or("");
                     let suffix = item.suffix.as_deref().unwrap_or("");
 
-                    let formatted_prefix =
-                        if !prefix.is_empty() && !prefix.ends_with(char::is_whitespace) {
-                            format!("{} ", prefix)
-                        } else {
-                            prefix.to_string()
-                        };
+                    let formatted_prefix = space_prefix(prefix);
 
                     let content = if !prefix.is_empty() || !suffix.is_empty() {
-                        let spaced_suffix = self.ensure_suffix_spacing(suffix);
+                        let spaced_suffix = space_suffix(suffix);
                         fmt.affix(&formatted_prefix, item_str, &spaced_suffix)
                     } else {
                         item_str
@@ -318,15 +319,10 @@ impl<'a> Renderer<'a> {
                     let prefix = item.prefix.as_deref().unwrap_or("");
                     let suffix = item.suffix.as_deref().unwrap_or("");
 
-                    let formatted_prefix =
-                        if !prefix.is_empty() && !prefix.ends_with(char::is_whitespace) {
-                            format!("{} ", prefix)
-                        } else {
-                            prefix.to_string()
-                        };
+                    let formatted_prefix = space_prefix(prefix);
 
                     let content = if !prefix.is_empty() || !suffix.is_empty() {
-                        let spaced_suffix = self.ensure_suffix_spacing(suffix);
+                        let spaced_suffix = space_suffix(suffix);
                         fmt.affix(&formatted_prefix, item_str, &spaced_suffix)
                     } else {
                         item_str
@@ -366,6 +362,7 @@ impl<'a> Renderer<'a> {
                     citation_number,
                     item.locator.as_deref(),
                     item.label.clone(),
+                    item.locators.as_deref(),
                 ) {
                     let item_str = crate::render::citation::citation_to_string_with_format::<F>(
                         &proc,
@@ -378,15 +375,10 @@ impl<'a> Renderer<'a> {
                         let prefix = item.prefix.as_deref().unwrap_or("");
                         let suffix = item.suffix.as_deref().unwrap_or("");
 
-                        let formatted_prefix =
-                            if !prefix.is_empty() && !prefix.ends_with(char::is_whitespace) {
-                                format!("{} ", prefix)
-                            } else {
-                                prefix.to_string()
-                            };
+                        let formatted_prefix = space_prefix(prefix);
 
                         let content = if !prefix.is_empty() || !suffix.is_empty() {
-                            let spaced_suffix = self.ensure_suffix_spacing(suffix);
+                            let spaced_suffix = space_suffix(suffix);
                             fmt.affix(&formatted_prefix, item_str, &spaced_suffix)
                         } else {
                             item_str
@@ -486,6 +478,7 @@ impl<'a> Renderer<'a> {
                     citation_number,
                     first_item.locator.as_deref(),
                     first_item.label.clone(),
+                    first_item.locators.as_deref(),
                 ) {
                     // Use integral-specific delimiter, defaulting to space for narrative
                     let integral_delimiter = spec.delimiter.as_deref().unwrap_or(" ");
@@ -501,15 +494,10 @@ impl<'a> Renderer<'a> {
                     let prefix = first_item.prefix.as_deref().unwrap_or("");
                     let suffix = first_item.suffix.as_deref().unwrap_or("");
 
-                    let formatted_prefix =
-                        if !prefix.is_empty() && !prefix.ends_with(char::is_whitespace) {
-                            format!("{} ", prefix)
-                        } else {
-                            prefix.to_string()
-                        };
+                    let formatted_prefix = space_prefix(prefix);
 
                     let content = if !prefix.is_empty() || !suffix.is_empty() {
-                        let spaced_suffix = self.ensure_suffix_spacing(suffix);
+                        let spaced_suffix = space_suffix(suffix);
                         fmt.affix(&formatted_prefix, item_str, &spaced_suffix)
                     } else {
                         item_str
@@ -543,6 +531,7 @@ impl<'a> Renderer<'a> {
                         citation_number,
                         item.locator.as_deref(),
                         item.label.clone(),
+                        item.locators.as_deref(),
                     ) {
                         let item_str = crate::render::citation::citation_to_string_with_format::<F>(
                             &proc,
@@ -554,14 +543,9 @@ impl<'a> Renderer<'a> {
                         if !item_str.is_empty() {
                             let prefix = item.prefix.as_deref().unwrap_or("");
                             let suffix = item.suffix.as_deref().unwrap_or("");
-                            let formatted_prefix =
-                                if !prefix.is_empty() && !prefix.ends_with(char::is_whitespace) {
-                                    format!("{} ", prefix)
-                                } else {
-                                    prefix.to_string()
-                                };
+                            let formatted_prefix = space_prefix(prefix);
                             let content = if !prefix.is_empty() || !suffix.is_empty() {
-                                let spaced_suffix = self.ensure_suffix_spacing(suffix);
+                                let spaced_suffix = space_suffix(suffix);
                                 fmt.affix(&formatted_prefix, item_str, &spaced_suffix)
                             } else {
                                 item_str
@@ -595,6 +579,7 @@ impl<'a> Renderer<'a> {
                     citation_number,
                     item.locator.as_deref(),
                     item.label.clone(),
+                    item.locators.as_deref(),
                 ) {
                     let item_str = crate::render::citation::citation_to_string_with_format::<F>(
                         &proc,
@@ -606,7 +591,7 @@ impl<'a> Renderer<'a> {
                     if !item_str.is_empty() {
                         let suffix = item.suffix.as_deref().unwrap_or("");
                         if !suffix.is_empty() {
-                            let spaced_suffix = self.ensure_suffix_spacing(suffix);
+                            let spaced_suffix = space_suffix(suffix);
                             item_parts.push(fmt.affix("", item_str, &spaced_suffix));
                         } else {
                             item_parts.push(item_str);
@@ -653,30 +638,24 @@ impl<'a> Renderer<'a> {
                                     joined_items
                                 )
                             } else {
-                                format!("{}{}{}", author_part, intra_delimiter, joined_items)
+                                use crate::render::citation::push_merging_period;
+                                let mut content = author_part.clone();
+                                push_merging_period(&mut content, intra_delimiter);
+                                push_merging_period(&mut content, &joined_items);
+                                content
                             }
                         }
                     }
                 };
                 let ids: Vec<String> = group.iter().map(|item| item.id.clone()).collect();
 
-                let formatted_prefix =
-                    if !prefix.is_empty() && !prefix.ends_with(char::is_whitespace) {
-                        format!("{} ", prefix)
-                    } else {
-                        prefix.to_string()
-                    };
+                let formatted_prefix = space_prefix(prefix);
 
                 rendered_groups.push(fmt.citation(ids, fmt.affix(&formatted_prefix, content, "")));
             } else if !author_part.is_empty() {
                 let ids: Vec<String> = group.iter().map(|item| item.id.clone()).collect();
 
-                let formatted_prefix =
-                    if !prefix.is_empty() && !prefix.ends_with(char::is_whitespace) {
-                        format!("{} ", prefix)
-                    } else {
-                        prefix.to_string()
-                    };
+                let formatted_prefix = space_prefix(prefix);
 
                 rendered_groups
                     .push(fmt.citation(ids, fmt.affix(&formatted_prefix, author_part, "")));
@@ -685,12 +664,7 @@ impl<'a> Renderer<'a> {
                 let content = item_parts.join(intra_delimiter);
                 let ids: Vec<String> = group.iter().map(|item| item.id.clone()).collect();
 
-                let formatted_prefix =
-                    if !prefix.is_empty() && !prefix.ends_with(char::is_whitespace) {
-                        format!("{} ", prefix)
-                    } else {
-                        prefix.to_string()
-                    };
+                let formatted_prefix = space_prefix(prefix);
 
                 rendered_groups.push(fmt.citation(ids, fmt.affix(&formatted_prefix, content, "")));
             }
@@ -712,22 +686,20 @@ impl<'a> Renderer<'a> {
         let options = RenderOptions {
             config: self.config,
             locale: self.locale,
+            date_locale: self.date_locale,
             context: RenderContext::Citation,
             mode: mode.clone(),
             suppress_author: false,
             locator: None,
             locator_label: None,
+            locators: None,
         };
 
         // Try to use the first semantically relevant component (including nested lists)
         // so disambiguation hints and component-specific formatting are preserved.
         // This ensures substitution, shortening, and mode-dependent conjunctions are respected.
         if let Some(comp) = template.first().and_then(find_grouping_component) {
-            let hints = self
-                .hints
-                .get(&reference.id().unwrap_or_default())
-                .cloned()
-                .unwrap_or_default();
+            let hints = self.hints_for(reference);
             if let Some(vals) = comp.values::<F>(reference, &hints, &options)
                 && !vals.value.is_empty()
             {
@@ -871,44 +843,131 @@ impl<'a> Renderer<'a> {
     where
         F: crate::render::format::OutputFormat<Output = String>,
     {
+        let template = self.bibliography_template(&reference.ref_type())?;
+
+        self.process_template_with_number_internal_with_format::<F>(
+            reference,
+            &template,
+            self.bibliography_options(),
+            entry_number,
+        )
+    }
+
+    /// Resolve the bibliography template for a reference type.
+    pub(crate) fn bibliography_template(&self, ref_type: &str) -> Option<Vec<TemplateComponent>> {
         let bib_spec = self.style.bibliography.as_ref()?;
 
         // Resolve default template (handles preset vs explicit)
         let default_template = bib_spec.resolve_template()?;
 
         // Determine effective template (override or default)
-        let ref_type = reference.ref_type();
-        let template = if let Some(type_templates) = &bib_spec.type_templates {
-            let mut matched_template = None;
-            for (selector, t) in type_templates {
-                if selector.matches(&ref_type) {
-                    matched_template = Some(t.clone());
-                    break;
-                }
-            }
-            matched_template.unwrap_or(default_template)
-        } else {
-            default_template
+        let Some(type_templates) = &bib_spec.type_templates else {
+            return Some(default_template);
         };
+        // An exact single-type selector wins over multi-type selectors.
+        let matched_template = type_templates
+            .get(&csln_core::template::TypeSelector::Single(
+                ref_type.to_string(),
+            ))
+            .or_else(|| {
+                type_templates
+                    .iter()
+                    .find(|(selector, _)| selector.matches(ref_type))
+                    .map(|(_, t)| t)
+            })
+            .cloned();
+        Some(matched_template.unwrap_or(default_template))
+    }
 
-        let template_ref = &template;
-
-        let options = RenderOptions {
+    /// Render options for bibliography entries.
+    pub(crate) fn bibliography_options(&self) -> RenderOptions<'a> {
+        RenderOptions {
             config: self.config,
             locale: self.locale,
+            date_locale: self.date_locale,
             context: RenderContext::Bibliography,
             mode: csln_core::citation::CitationMode::NonIntegral,
             suppress_author: false,
             locator: None,
             locator_label: None,
-        };
+            locators: None,
+        }
+    }
 
-        self.process_template_with_number_internal_with_format::<F>(
-            reference,
-            template_ref,
-            options,
-            entry_number,
-        )
+    /// List the variables a template references that a reference lacks.
+    ///
+    /// Components the style suppresses for the reference type are skipped, as
+    /// are terms, literal text, and processor-assigned numbers. Conditionals
+    /// contribute only the branch taken for this reference.
+    pub(crate) fn missing_variables(
+        &self,
+        reference: &Reference,
+        template: &[TemplateComponent],
+        options: &RenderOptions<'_>,
+    ) -> Vec<String> {
+        let default_hint = ProcHints::default();
+        let hint = self
+            .hints
+            .get(&reference.id().unwrap_or_default())
+            .unwrap_or(&default_hint);
+        let mut missing = Vec::new();
+        self.collect_missing_variables(reference, template, hint, options, &mut missing);
+        missing
+    }
+
+    fn collect_missing_variables(
+        &self,
+        reference: &Reference,
+        template: &[TemplateComponent],
+        hint: &ProcHints,
+        options: &RenderOptions<'_>,
+        missing: &mut Vec<String>,
+    ) {
+        use csln_core::template::NumberVariable;
+
+        let ref_type = reference.ref_type();
+        for component in template {
+            let component = resolve_component_for_ref_type(component, &ref_type);
+            if is_style_suppressed(&component, &ref_type) {
+                continue;
+            }
+            match &component {
+                TemplateComponent::List(list) => {
+                    self.collect_missing_variables(reference, &list.items, hint, options, missing);
+                    continue;
+                }
+                TemplateComponent::Conditional(conditional) => {
+                    let branch = if crate::values::conditional::condition_holds::<
+                        crate::render::plain::PlainText,
+                    >(
+                        &conditional.condition, reference, hint, options
+                    ) {
+                        &conditional.then
+                    } else {
+                        &conditional.otherwise
+                    };
+                    self.collect_missing_variables(reference, branch, hint, options, missing);
+                    continue;
+                }
+                TemplateComponent::Term(_) | TemplateComponent::Text(_) => continue,
+                TemplateComponent::Number(n)
+                    if matches!(
+                        n.number,
+                        NumberVariable::CitationNumber | NumberVariable::CitationLabel
+                    ) =>
+                {
+                    continue;
+                }
+                _ => {}
+            }
+            let present = component
+                .values::<crate::render::plain::PlainText>(reference, hint, options)
+                .is_some_and(|v| !v.value.is_empty());
+            let label = component_label(&component);
+            if !present && !missing.contains(&label) {
+                missing.push(label);
+            }
+        }
     }
 
     /// Process a template for a reference with citation number.
@@ -923,6 +982,7 @@ impl<'a> Renderer<'a> {
         citation_number: usize,
         locator: Option<&str>,
         locator_label: Option<csln_core::citation::LocatorType>,
+        locators: Option<&[csln_core::citation::LocatorSegment]>,
     ) -> Option<ProcTemplate> {
         self.process_template_with_number_with_format::<crate::render::plain::PlainText>(
             reference,
@@ -933,6 +993,7 @@ impl<'a> Renderer<'a> {
             citation_number,
             locator,
             locator_label,
+            locators,
         )
     }
 
@@ -948,6 +1009,7 @@ impl<'a> Renderer<'a> {
         citation_number: usize,
         locator: Option<&str>,
         locator_label: Option<csln_core::citation::LocatorType>,
+        locators: Option<&[csln_core::citation::LocatorSegment]>,
     ) -> Option<ProcTemplate>
     where
         F: crate::render::format::OutputFormat<Output = String>,
@@ -955,11 +1017,13 @@ impl<'a> Renderer<'a> {
         let options = RenderOptions {
             config: self.config,
             locale: self.locale,
+            date_locale: self.date_locale,
             context,
             mode,
             suppress_author,
             locator,
             locator_label,
+            locators,
         };
         self.process_template_with_number_internal_with_format::<F>(
             reference,
@@ -995,12 +1059,39 @@ impl<'a> Renderer<'a> {
     where
         F: crate::render::format::OutputFormat<Output = String>,
     {
-        let default_hint = ProcHints::default();
-        let base_hint = self
-            .hints
-            .get(&reference.id().unwrap_or_default())
-            .unwrap_or(&default_hint);
+        self.process_template_traced::<F>(reference, template, options, citation_number, None)
+    }
 
+    /// Trace how each template component renders for a reference.
+    pub(crate) fn explain_template(
+        &self,
+        reference: &Reference,
+        template: &[TemplateComponent],
+        options: RenderOptions<'_>,
+        citation_number: usize,
+    ) -> Vec<ComponentTrace> {
+        let mut trace = Vec::new();
+        self.process_template_traced::<crate::render::plain::PlainText>(
+            reference,
+            template,
+            options,
+            citation_number,
+            Some(&mut trace),
+        );
+        trace
+    }
+
+    fn process_template_traced<F>(
+        &self,
+        reference: &Reference,
+        template: &[TemplateComponent],
+        options: RenderOptions<'_>,
+        citation_number: usize,
+        mut trace: Option<&mut Vec<ComponentTrace>>,
+    ) -> Option<ProcTemplate>
+    where
+        F: crate::render::format::OutputFormat<Output = String>,
+    {
         // Create a hint with citation number
         let hint = ProcHints {
             citation_number: if citation_number > 0 {
@@ -1008,7 +1099,7 @@ impl<'a> Renderer<'a> {
             } else {
                 None
             },
-            ..base_hint.clone()
+            ..self.hints_for(reference)
         };
 
         // Track rendered variables to prevent duplicates (CSL 1.0 spec:
@@ -1026,7 +1117,15 @@ impl<'a> Renderer<'a> {
             }
         };
 
-        let components: Vec<ProcTemplateComponent> = template
+        // Title disambiguation adds the short title after the author.
+        let template: Cow<'_, [TemplateComponent]> =
+            if hint.add_title && options.context == RenderContext::Citation {
+                Cow::Owned(insert_disambiguating_title(template, &reference.ref_type()))
+            } else {
+                Cow::Borrowed(template)
+            };
+
+        let mut components: Vec<ProcTemplateComponent> = template
             .iter()
             .filter_map(|component| {
                 let ref_type = reference.ref_type().to_string();
@@ -1034,19 +1133,58 @@ impl<'a> Renderer<'a> {
                 // Get unique key for this variable (e.g., "contributor:Author")
                 let var_key = get_variable_key(&resolved_component);
 
+                let mut record = |outcome: ComponentOutcome| {
+                    if let Some(trace) = trace.as_deref_mut() {
+                        trace.push(ComponentTrace {
+                            component: component_label(&resolved_component),
+                            outcome,
+                        });
+                    }
+                };
+
                 // Skip if this variable was already rendered
                 if let Some(ref key) = var_key {
                     let base = key_base(key);
                     if rendered_vars.contains(key) || substituted_bases.contains(&base) {
+                        record(ComponentOutcome::Suppressed {
+                            reason: SuppressionReason::AlreadyRendered,
+                        });
                         return None;
                     }
                 }
 
                 // Extract value from reference using the requested format
-                let mut values = resolved_component.values::<F>(reference, &hint, &options)?;
-                if values.value.is_empty() {
+                let Some(mut values) = resolved_component
+                    .values::<F>(reference, &hint, &options)
+                    .filter(|v| !v.value.is_empty())
+                else {
+                    let reason = if is_style_suppressed(&resolved_component, &ref_type) {
+                        SuppressionReason::Style
+                    } else if options.suppress_author
+                        && matches!(
+                            &resolved_component,
+                            TemplateComponent::Contributor(c)
+                                if c.contributor == csln_core::template::ContributorRole::Author
+                        )
+                    {
+                        SuppressionReason::SuppressAuthor
+                    } else {
+                        SuppressionReason::Empty
+                    };
+                    record(ComponentOutcome::Suppressed { reason });
                     return None;
-                }
+                };
+                // Some components resolve a value but are hidden at render time.
+                record(if is_style_suppressed(&resolved_component, &ref_type) {
+                    ComponentOutcome::Suppressed {
+                        reason: SuppressionReason::Style,
+                    }
+                } else {
+                    ComponentOutcome::Rendered {
+                        value: values.value.clone(),
+                        substituted: values.substituted_key.clone(),
+                    }
+                });
 
                 // If whole-entry linking is enabled and this component doesn't have a URL,
                 // try to resolve it from global config.
@@ -1082,6 +1220,16 @@ impl<'a> Renderer<'a> {
             })
             .collect();
 
+        // Components positioned by the style (usually per type) move to the
+        // start or end; the rest keep their template order.
+        components.sort_by_cached_key(|component| {
+            match crate::render::component::get_effective_rendering(component).position {
+                Some(ComponentPosition::Start) => 0,
+                None => 1,
+                Some(ComponentPosition::End) => 2,
+            }
+        });
+
         if components.is_empty() {
             None
         } else {
@@ -1114,6 +1262,33 @@ impl<'a> Renderer<'a> {
     }
 }
 
+/// Separate a citation prefix from the citation body with a single space,
+/// unless it ends with whitespace or opening punctuation (e.g., "see" and
+/// "see " both give "see ").
+pub(crate) fn space_prefix(prefix: &str) -> String {
+    if prefix.is_empty()
+        || prefix.ends_with(char::is_whitespace)
+        || prefix.ends_with(['(', '[', '\u{201C}', '\u{2018}'])
+    {
+        prefix.to_string()
+    } else {
+        format!("{} ", prefix)
+    }
+}
+
+/// Separate a citation suffix from the citation body with a single space,
+/// unless it begins with whitespace or punctuation (e.g., ", chap. 3").
+pub(crate) fn space_suffix(suffix: &str) -> String {
+    if suffix.is_empty()
+        || suffix.starts_with(char::is_whitespace)
+        || suffix.starts_with([',', ';', '.', ':', ')', ']'])
+    {
+        suffix.to_string()
+    } else {
+        format!(" {}", suffix)
+    }
+}
+
 fn strip_author_component(component: &TemplateComponent) -> Option<TemplateComponent> {
     match component {
         TemplateComponent::Contributor(c)
@@ -1140,6 +1315,56 @@ fn strip_author_component(component: &TemplateComponent) -> Option<TemplateCompo
     }
 }
 
+/// The short title that title disambiguation adds to a citation.
+pub(crate) fn disambiguating_title() -> csln_core::template::TemplateTitle {
+    use csln_core::template::{TemplateTitle, TitleForm, TitleType};
+
+    TemplateTitle {
+        title: TitleType::Primary,
+        form: Some(TitleForm::Short),
+        ..Default::default()
+    }
+}
+
+/// Insert a short primary title after the author, unless the template
+/// already renders the primary title for the reference type.
+fn insert_disambiguating_title(
+    template: &[TemplateComponent],
+    ref_type: &str,
+) -> Vec<TemplateComponent> {
+    use csln_core::template::ContributorRole;
+
+    if template.iter().any(|c| renders_primary_title(c, ref_type)) {
+        return template.to_vec();
+    }
+    let position = template
+        .iter()
+        .position(|c| {
+            matches!(c, TemplateComponent::Contributor(c) if c.contributor == ContributorRole::Author)
+        })
+        .map_or(0, |i| i + 1);
+    let mut template = template.to_vec();
+    template.insert(position, TemplateComponent::Title(disambiguating_title()));
+    template
+}
+
+/// Whether the component renders the primary title once type-specific
+/// overrides are applied.
+fn renders_primary_title(component: &TemplateComponent, ref_type: &str) -> bool {
+    let component = resolve_component_for_ref_type(component, ref_type);
+    if is_style_suppressed(&component, ref_type) {
+        return false;
+    }
+    match &component {
+        TemplateComponent::Title(t) => t.title == csln_core::template::TitleType::Primary,
+        TemplateComponent::List(list) => list
+            .items
+            .iter()
+            .any(|c| renders_primary_title(c, ref_type)),
+        _ => false,
+    }
+}
+
 fn find_grouping_component(component: &TemplateComponent) -> Option<&TemplateComponent> {
     match component {
         TemplateComponent::Contributor(_) | TemplateComponent::Title(_) => Some(component),
@@ -1236,6 +1461,9 @@ mod tests {
         let date1 = TemplateComponent::Date(TemplateDate {
             date: DateVariable::Issued,
             form: DateForm::Year,
+            show_label: None,
+            label_text_case: None,
+            floruit: None,
             rendering: Rendering::default(),
             fallback: None,
             links: None,
@@ -1247,6 +1475,9 @@ mod tests {
         let date2 = TemplateComponent::Date(TemplateDate {
             date: DateVariable::Issued,
             form: DateForm::Year,
+            show_label: None,
+            label_text_case: None,
+            floruit: None,
             rendering: Rendering {
                 prefix: Some(", ".to_string()),
                 ..Default::default()
@@ -1261,6 +1492,9 @@ mod tests {
         let date3 = TemplateComponent::Date(TemplateDate {
             date: DateVariable::Issued,
             form: DateForm::Year,
+            show_label: None,
+            label_text_case: None,
+            floruit: None,
             rendering: Rendering {
                 suffix: Some(".".to_string()),
                 ..Default::default()
@@ -1307,6 +1541,9 @@ mod tests {
                 TemplateComponent::Date(TemplateDate {
                     date: DateVariable::Issued,
                     form: DateForm::Year,
+                    show_label: None,
+                    label_text_case: None,
+                    floruit: None,
                     rendering: Rendering::default(),
                     fallback: None,
                     links: None,
@@ -1,15 +1,21 @@
+use crate::collation::Collator;
 use crate::reference::Reference;
 use csln_core::locale::Locale;
-use csln_core::options::{Config, SortKey};
+use csln_core::options::{Config, DemoteNonDroppingParticle, SortKey};
 
 pub struct Sorter<'a> {
     config: &'a Config,
     locale: &'a Locale,
+    collator: Collator,
 }
 
 impl<'a> Sorter<'a> {
     pub fn new(config: &'a Config, locale: &'a Locale) -> Self {
-        Self { config, locale }
+        Self {
+            config,
+            locale,
+            collator: Collator::new(&locale.locale),
+        }
     }
 
     /// Sort references according to style instructions.
@@ -17,6 +23,12 @@ impl<'a> Sorter<'a> {
         let mut refs = references;
         let processing = self.config.processing.as_ref().cloned().unwrap_or_default();
         let proc_config = processing.config();
+        let demote_particle = self
+            .config
+            .contributors
+            .as_ref()
+            .and_then(|c| c.demote_non_dropping_particle)
+            != Some(DemoteNonDroppingParticle::Never);
 
         if let Some(sort_config) = &proc_config.sort {
             // Build a composite sort that handles all keys together
@@ -28,11 +40,11 @@ impl<'a> Sorter<'a> {
                             let a_sort_key = a
                                 .author()
                                 .and_then(|c| c.to_names_vec().first().cloned())
-                                .map(|n| n.family_or_literal().to_lowercase())
+                                .map(|n| n.family_sort_key(demote_particle).to_lowercase())
                                 .or_else(|| {
                                     a.editor()
                                         .and_then(|c| c.to_names_vec().first().cloned())
-                                        .map(|n| n.family_or_literal().to_lowercase())
+                                        .map(|n| n.family_sort_key(demote_particle).to_lowercase())
                                 })
                                 .or_else(|| {
                                     a.title().map(|t| {
@@ -45,11 +57,11 @@ impl<'a> Sorter<'a> {
                             let b_sort_key = b
                                 .author()
                                 .and_then(|c| c.to_names_vec().first().cloned())
-                                .map(|n| n.family_or_literal().to_lowercase())
+                                .map(|n| n.family_sort_key(demote_particle).to_lowercase())
                                 .or_else(|| {
                                     b.editor()
                                         .and_then(|c| c.to_names_vec().first().cloned())
-                                        .map(|n| n.family_or_literal().to_lowercase())
+                                        .map(|n| n.family_sort_key(demote_particle).to_lowercase())
                                 })
                                 .or_else(|| {
                                     b.title().map(|t| {
@@ -61,9 +73,9 @@ impl<'a> Sorter<'a> {
                                 .unwrap_or_default();
 
                             if sort.ascending {
-                                a_sort_key.cmp(&b_sort_key)
+                                self.collator.compare(&a_sort_key, &b_sort_key)
                             } else {
-                                b_sort_key.cmp(&a_sort_key)
+                                self.collator.compare(&b_sort_key, &a_sort_key)
                             }
                         }
                         SortKey::Year => {
@@ -97,9 +109,9 @@ impl<'a> Sorter<'a> {
                                 .to_lowercase();
 
                             if sort.ascending {
-                                a_title.cmp(&b_title)
+                                self.collator.compare(&a_title, &b_title)
                             } else {
-                                b_title.cmp(&a_title)
+                                self.collator.compare(&b_title, &a_title)
                             }
                         }
                         SortKey::CitationNumber => std::cmp::Ordering::Equal,
@@ -139,6 +139,103 @@ fn test_process_citation() {
     assert_eq!(result, "(Kuhn, 1962)");
 }
 
+#[test]
+fn test_explain_citation_reports_suppressed_component() {
+    use crate::processor::explain::{ComponentOutcome, SuppressionReason};
+    use csln_core::template::{SimpleVariable, TemplateVariable};
+
+    let mut style = make_style();
+    if let Some(template) = style.citation.as_mut().and_then(|c| c.template.as_mut()) {
+        template.push(TemplateComponent::Variable(TemplateVariable {
+            variable: SimpleVariable::Doi,
+            ..Default::default()
+        }));
+    }
+    let processor = Processor::new(style, make_bibliography());
+
+    let citation = Citation {
+        items: vec![
+            crate::reference::CitationItem {
+                id: "kuhn1962".to_string(),
+                ..Default::default()
+            },
+            crate::reference::CitationItem {
+                id: "missing".to_string(),
+                ..Default::default()
+            },
+        ],
+        ..Default::default()
+    };
+
+    let explanation = processor.explain_citation(&citation);
+    assert_eq!(explanation.output, None);
+    assert_eq!(explanation.items.len(), 2);
+
+    let kuhn = &explanation.items[0];
+    assert_eq!(kuhn.ref_type.as_deref(), Some("book"));
+    assert!(matches!(
+        &kuhn.components[0].outcome,
+        ComponentOutcome::Rendered { value, .. } if value == "Kuhn"
+    ));
+    let doi = kuhn
+        .components
+        .iter()
+        .find(|c| c.component == "variable:doi")
+        .unwrap();
+    assert_eq!(
+        doi.outcome,
+        ComponentOutcome::Suppressed {
+            reason: SuppressionReason::Empty
+        }
+    );
+
+    assert_eq!(explanation.items[1].id, "missing");
+    assert!(explanation.items[1].ref_type.is_none());
+}
+
+#[test]
+fn test_citation_fingerprint_tracks_wrap() {
+    let citation = Citation {
+        items: vec![crate::reference::CitationItem {
+            id: "kuhn1962".to_string(),
+            ..Default::default()
+        }],
+        ..Default::default()
+    };
+
+    let processor = Processor::new(make_style(), make_bibliography());
+    let fingerprint = processor.citation_fingerprint(&citation);
+    assert_eq!(fingerprint, processor.citation_fingerprint(&citation));
+    assert_eq!(
+        fingerprint,
+        Processor::new(make_style(), make_bibliography()).citation_fingerprint(&citation)
+    );
+
+    let mut style = make_style();
+    if let Some(citation_spec) = style.citation.as_mut() {
+        citation_spec.wrap = Some(WrapPunctuation::Brackets);
+    }
+    let processor = Processor::new(style, make_bibliography());
+    assert_ne!(fingerprint, processor.citation_fingerprint(&citation));
+}
+
+#[test]
+fn test_citation_fingerprint_does_not_mark_items_cited() {
+    let citation = Citation {
+        items: vec![crate::reference::CitationItem {
+            id: "kuhn1962".to_string(),
+            ..Default::default()
+        }],
+        ..Default::default()
+    };
+
+    let processor = Processor::new(make_style(), make_bibliography());
+    processor.citation_fingerprint(&citation);
+
+    assert!(processor.used_references().is_empty());
+    assert!(processor.citation_numbers.borrow().is_empty());
+}
+
 #[test]
 fn test_normalize_note_context_assigns_missing_numbers() {
     let style = make_note_style();
@@ -251,6 +348,58 @@ fn test_citation_locator_label_renders_term() {
     assert_eq!(rendered, "(Kuhn, 1962, p. 23)");
 }
 
+#[test]
+fn test_citation_composite_locator() {
+    let locator = |delimiter: Option<&str>| {
+        csln_core::TemplateComponent::Variable(csln_core::template::TemplateVariable {
+            variable: csln_core::template::SimpleVariable::Locator,
+            locator_delimiter: delimiter.map(str::to_string),
+            ..Default::default()
+        })
+    };
+    let citation = Citation {
+        items: vec![crate::reference::CitationItem {
+            id: "kuhn1962".to_string(),
+            locators: Some(vec![
+                csln_core::citation::LocatorSegment {
+                    label: csln_core::citation::LocatorType::Book,
+                    locator: "2".to_string(),
+                },
+                csln_core::citation::LocatorSegment {
+                    label: csln_core::citation::LocatorType::Chapter,
+                    locator: "3".to_string(),
+                },
+            ]),
+            ..Default::default()
+        }],
+        ..Default::default()
+    };
+
+    for (delimiter, expected) in [
+        (None, "(Kuhn, bk. 2, ch. 3)"),
+        (Some("; "), "(Kuhn, bk. 2; ch. 3)"),
+    ] {
+        let mut style = make_style();
+        style.citation = Some(csln_core::CitationSpec {
+            template: Some(vec![
+                csln_core::TemplateComponent::Contributor(
+                    csln_core::template::TemplateContributor {
+                        contributor: ContributorRole::Author,
+                        form: ContributorForm::Short,
+                        ..Default::default()
+                    },
+                ),
+                locator(delimiter),
+            ]),
+            wrap: Some(WrapPunctuation::Parentheses),
+            delimiter: Some(", ".to_string()),
+            ..Default::default()
+        });
+        let processor = Processor::new(style, make_bibliography());
+        assert_eq!(processor.process_citation(&citation).unwrap(), expected);
+    }
+}
+
 #[test]
 fn test_citation_locator_label_renders_term_with_loaded_locale() {
     use std::path::Path;
@@ -503,6 +652,8 @@ fn test_disambiguation_givenname() {
                 names: true,
                 add_givenname: true,
                 year_suffix: true,
+                strategy: None,
+                givenname_rule: None,
             }),
         })),
         contributors: Some(ContributorConfig {
@@ -571,6 +722,111 @@ fn test_disambiguation_givenname() {
     assert!(cit_b.contains("A. Smith"));
 }
 
+fn build_givenname_processor(
+    rule: Option<csln_core::options::GivennameDisambiguationRule>,
+    refs: Vec<(&str, Vec<Name>)>,
+) -> Processor {
+    use csln_core::options::{Disambiguation, Processing, ProcessingCustom};
+
+    let mut style = make_style();
+    style.options = Some(Config {
+        processing: Some(Processing::Custom(ProcessingCustom {
+            disambiguate: Some(Disambiguation {
+                names: false,
+                add_givenname: true,
+                year_suffix: true,
+                strategy: None,
+                givenname_rule: rule,
+            }),
+            ..Default::default()
+        })),
+        contributors: Some(ContributorConfig {
+            initialize_with: Some(". ".to_string()),
+            and: Some(AndOptions::Symbol),
+            ..Default::default()
+        }),
+        ..Default::default()
+    });
+
+    let mut bib = indexmap::IndexMap::new();
+    for (id, authors) in refs {
+        bib.insert(
+            id.to_string(),
+            Reference::from(LegacyReference {
+                id: id.to_string(),
+                ref_type: "book".to_string(),
+                author: Some(authors),
+                title: Some(id.to_string()),
+                issued: Some(DateVariable::year(2020)),
+                ..Default::default()
+            }),
+        );
+    }
+    Processor::new(style, bib)
+}
+
+#[test]
+fn test_disambiguation_givenname_escalates_to_full_names() {
+    use csln_core::options::GivennameDisambiguationRule;
+
+    let refs = || {
+        vec![
+            ("john", vec![Name::new("Smith", "John")]),
+            ("jane", vec![Name::new("Smith", "Jane")]),
+            ("brown", vec![Name::new("Brown", "Tom")]),
+        ]
+    };
+
+    // Initials collide ("J. Smith"), so full given names are shown.
+    for rule in [None, Some(GivennameDisambiguationRule::AllNames)] {
+        let processor = build_givenname_processor(rule, refs());
+        assert_eq!(cite_one(&processor, "john"), "(John Smith, 2020)");
+        assert_eq!(cite_one(&processor, "jane"), "(Jane Smith, 2020)");
+        assert_eq!(cite_one(&processor, "brown"), "(Brown, 2020)");
+    }
+
+    // Limited to initials, the year suffix has to resolve the collision.
+    let processor = build_givenname_processor(
+        Some(GivennameDisambiguationRule::AllNamesWithInitials),
+        refs(),
+    );
+    assert_eq!(cite_one(&processor, "jane"), "(Smith, 2020a)");
+    assert_eq!(cite_one(&processor, "john"), "(Smith, 2020b)");
+    assert_eq!(cite_one(&processor, "brown"), "(Brown, 2020)");
+}
+
+#[test]
+fn test_disambiguation_givenname_primary_name() {
+    use csln_core::options::GivennameDisambiguationRule;
+
+    let refs = || {
+        vec![
+            (
+                "smith-lee",
+                vec![Name::new("Smith", "John"), Name::new("Lee", "Ann")],
+            ),
+            (
+                "smith-lee-2",
+                vec![Name::new("Smith", "Rob"), Name::new("Lee", "Ann")],
+            ),
+        ]
+    };
+
+    let processor = build_givenname_processor(None, refs());
+    assert_eq!(
+        cite_one(&processor, "smith-lee"),
+        "(J. Smith & A. Lee, 2020)"
+    );
+
+    let processor =
+        build_givenname_processor(Some(GivennameDisambiguationRule::PrimaryName), refs());
+    assert_eq!(cite_one(&processor, "smith-lee"), "(J. Smith & Lee, 2020)");
+    assert_eq!(
+        cite_one(&processor, "smith-lee-2"),
+        "(R. Smith & Lee, 2020)"
+    );
+}
+
 #[test]
 fn test_disambiguation_add_names() {
     use csln_core::options::{
@@ -601,6 +857,8 @@ fn test_disambiguation_add_names() {
                 names: true, // disambiguate-add-names
                 add_givenname: false,
                 year_suffix: true,
+                strategy: None,
+                givenname_rule: None,
             }),
         })),
         contributors: Some(ContributorConfig {
@@ -684,6 +942,217 @@ fn test_disambiguation_add_names() {
     assert!(cit_2.contains("Smith") && cit_2.contains("Brown"));
 }
 
+fn build_strategy_processor(
+    strategy: Vec<csln_core::options::DisambiguationStrategy>,
+) -> Processor {
+    build_strategy_processor_with_titles(strategy, ["Alpha", "Beta"])
+}
+
+fn build_strategy_processor_with_titles(
+    strategy: Vec<csln_core::options::DisambiguationStrategy>,
+    titles: [&str; 2],
+) -> Processor {
+    use csln_core::options::{Disambiguation, Processing, ProcessingCustom};
+
+    let mut style = make_style();
+    style.options = Some(Config {
+        processing: Some(Processing::Custom(ProcessingCustom {
+            disambiguate: Some(Disambiguation {
+                names: true,
+                add_givenname: true,
+                year_suffix: true,
+                strategy: Some(strategy),
+                givenname_rule: None,
+            }),
+            ..Default::default()
+        })),
+        contributors: Some(ContributorConfig {
+            initialize_with: Some(". ".to_string()),
+            ..Default::default()
+        }),
+        ..Default::default()
+    });
+
+    let mut bib = indexmap::IndexMap::new();
+    for (id, given, title) in [
+        ("smith-a", "Alice", titles[0]),
+        ("smith-j", "John", titles[1]),
+    ] {
+        bib.insert(
+            id.to_string(),
+            Reference::from(LegacyReference {
+                id: id.to_string(),
+                ref_type: "book".to_string(),
+                author: Some(vec![Name::new("Smith", given)]),
+                title: Some(title.to_string()),
+                issued: Some(DateVariable::year(2020)),
+                ..Default::default()
+            }),
+        );
+    }
+    Processor::new(style, bib)
+}
+
+fn cite_one(processor: &Processor, id: &str) -> String {
+    processor
+        .process_citation(&Citation {
+            items: vec![crate::reference::CitationItem {
+                id: id.to_string(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        })
+        .unwrap()
+}
+
+#[test]
+fn test_disambiguation_strategy_order() {
+    use csln_core::options::DisambiguationStrategy::{AddGivenname, YearSuffix};
+
+    let processor = build_strategy_processor(vec![AddGivenname, YearSuffix]);
+    assert_eq!(cite_one(&processor, "smith-a"), "(A. Smith, 2020)");
+    assert_eq!(cite_one(&processor, "smith-j"), "(J. Smith, 2020)");
+
+    // Putting the year suffix first means given names are never needed.
+    let processor = build_strategy_processor(vec![YearSuffix, AddGivenname]);
+    assert_eq!(cite_one(&processor, "smith-a"), "(Smith, 2020a)");
+    assert_eq!(cite_one(&processor, "smith-j"), "(Smith, 2020b)");
+}
+
+#[test]
+fn test_disambiguation_add_title() {
+    use crate::processor::explain::DisambiguationDecision;
+    use csln_core::options::DisambiguationStrategy::{AddGivenname, AddTitle, YearSuffix};
+
+    let processor = build_strategy_processor(vec![AddTitle, YearSuffix]);
+    assert_eq!(cite_one(&processor, "smith-a"), "(Smith, Alpha, 2020)");
+    assert_eq!(cite_one(&processor, "smith-j"), "(Smith, Beta, 2020)");
+    let explanation = processor.explain_citation(&Citation {
+        items: vec![crate::reference::CitationItem {
+            id: "smith-a".to_string(),
+            ..Default::default()
+        }],
+        ..Default::default()
+    });
+    assert_eq!(
+        explanation.items[0].disambiguation,
+        vec![DisambiguationDecision::AddTitle]
+    );
+
+    // Earlier steps still take precedence.
+    let processor = build_strategy_processor(vec![AddGivenname, AddTitle]);
+    assert_eq!(cite_one(&processor, "smith-a"), "(A. Smith, 2020)");
+}
+
+#[test]
+fn test_disambiguation_add_title_falls_back_to_year_suffix() {
+    use csln_core::options::DisambiguationStrategy::{AddTitle, YearSuffix};
+
+    let processor =
+        build_strategy_processor_with_titles(vec![AddTitle, YearSuffix], ["Alpha", "alpha"]);
+    assert_eq!(cite_one(&processor, "smith-a"), "(Smith, 2020a)");
+    assert_eq!(cite_one(&processor, "smith-j"), "(Smith, 2020b)");
+}
+
+#[test]
+fn test_disambiguation_add_title_keeps_subtitles() {
+    use csln_core::options::DisambiguationStrategy::{AddTitle, YearSuffix};
+
+    // Plain titles are not split at the colon unless the style opts in, so
+    // titles that differ only after it still disambiguate.
+    let processor = build_strategy_processor_with_titles(
+        vec![AddTitle, YearSuffix],
+        ["Alpha: One", "Alpha: Two"],
+    );
+    assert_eq!(cite_one(&processor, "smith-a"), "(Smith, Alpha: One, 2020)");
+    assert_eq!(cite_one(&processor, "smith-j"), "(Smith, Alpha: Two, 2020)");
+}
+
+#[test]
+fn test_disambiguation_add_title_with_suppressed_title() {
+    use csln_core::options::DisambiguationStrategy::{AddTitle, YearSuffix};
+    use csln_core::template::{ComponentOverride, TypeSelector};
+
+    // A title the style suppresses for books does not count as shown.
+    let mut processor = build_strategy_processor(vec![AddTitle, YearSuffix]);
+    let hidden_title = TemplateComponent::Title(TemplateTitle {
+        title: TitleType::Primary,
+        overrides: Some(HashMap::from([(
+            TypeSelector::Single("book".to_string()),
+            ComponentOverride::Rendering(Rendering {
+                suppress: Some(true),
+                ..Default::default()
+            }),
+        )])),
+        ..Default::default()
+    });
+    if let Some(template) = processor
+        .style
+        .citation
+        .as_mut()
+        .and_then(|c| c.template.as_mut())
+    {
+        template.push(hidden_title);
+    }
+    assert_eq!(cite_one(&processor, "smith-a"), "(Smith, Alpha, 2020)");
+}
+
+#[test]
+fn test_set_bibliography_forgets_removed_citations() {
+    use csln_core::options::DisambiguationStrategy::YearSuffix;
+
+    let mut processor = build_strategy_processor(vec![YearSuffix]);
+    cite_one(&processor, "smith-a");
+    cite_one(&processor, "smith-j");
+
+    let mut bibliography = processor.bibliography.clone();
+    bibliography.shift_remove("smith-j");
+    processor.set_bibliography(bibliography);
+
+    assert_eq!(
+        *processor.used_references(),
+        HashSet::from(["smith-a".to_string()])
+    );
+}
+
+#[test]
+fn test_upsert_reference_recalculates_disambiguation() {
+    use csln_core::options::DisambiguationStrategy::YearSuffix;
+
+    let mut processor = build_strategy_processor(vec![YearSuffix]);
+    let mut bibliography = processor.bibliography.clone();
+    let smith_j = bibliography.shift_remove("smith-j").unwrap();
+    processor.set_bibliography(bibliography);
+    assert_eq!(cite_one(&processor, "smith-a"), "(Smith, 2020)");
+
+    processor.upsert_reference("smith-j", smith_j);
+    assert_eq!(cite_one(&processor, "smith-a"), "(Smith, 2020a)");
+    assert_eq!(cite_one(&processor, "smith-j"), "(Smith, 2020b)");
+
+    // Replacing a reference re-renders with the new data.
+    processor.upsert_reference(
+        "smith-j",
+        Reference::from(LegacyReference {
+            id: "smith-j".to_string(),
+            ref_type: "book".to_string(),
+            author: Some(vec![Name::new("Jones", "John")]),
+            title: Some("Beta".to_string()),
+            issued: Some(DateVariable::year(2021)),
+            ..Default::default()
+        }),
+    );
+    assert_eq!(cite_one(&processor, "smith-a"), "(Smith, 2020)");
+    assert_eq!(cite_one(&processor, "smith-j"), "(Jones, 2021)");
+    assert_eq!(processor.bibliography.get_index(1).unwrap().0, "smith-j");
+}
+
+#[test]
+fn test_disambiguation_strategy_opt_out() {
+    let processor = build_strategy_processor(vec![]);
+    assert_eq!(cite_one(&processor, "smith-a"), "(Smith, 2020)");
+    assert_eq!(cite_one(&processor, "smith-j"), "(Smith, 2020)");
+}
+
 #[test]
 fn test_disambiguation_combined_expansion() {
     use csln_core::options::{
@@ -721,6 +1190,8 @@ fn test_disambiguation_combined_expansion() {
                 names: true,
                 add_givenname: true,
                 year_suffix: true,
+                strategy: None,
+                givenname_rule: None,
             }),
         })),
         contributors: Some(ContributorConfig {
@@ -1227,6 +1698,93 @@ fn test_citation_grouping_different_authors() {
     );
 }
 
+fn make_particle_bibliography() -> Bibliography {
+    let mut gogh = Name::new("Gogh", "Vincent");
+    gogh.non_dropping_particle = Some("van".to_string());
+    let mut bib = Bibliography::new();
+    bib.insert(
+        "smith".to_string(),
+        Reference::from(LegacyReference {
+            id: "smith".to_string(),
+            ref_type: "book".to_string(),
+            author: Some(vec![Name::new("Smith", "John")]),
+            title: Some("A Book".to_string()),
+            issued: Some(DateVariable::year(2020)),
+            ..Default::default()
+        }),
+    );
+    bib.insert(
+        "gogh".to_string(),
+        Reference::from(LegacyReference {
+            id: "gogh".to_string(),
+            ref_type: "book".to_string(),
+            author: Some(vec![gogh]),
+            title: Some("Letters".to_string()),
+            issued: Some(DateVariable::year(1888)),
+            ..Default::default()
+        }),
+    );
+    bib
+}
+
+#[test]
+fn test_sort_demoted_particle() {
+    use csln_core::options::DemoteNonDroppingParticle;
+
+    let sorted_ids = |demote| {
+        let mut style = make_style();
+        if let Some(contributors) = style.options.as_mut().and_then(|o| o.contributors.as_mut()) {
+            contributors.demote_non_dropping_particle = Some(demote);
+        }
+        let processor = Processor::new(style, make_particle_bibliography());
+        let bib = processor.bibliography.values().collect();
+        processor
+            .sort_references(bib)
+            .into_iter()
+            .filter_map(|r| r.id())
+            .collect::<Vec<_>>()
+    };
+
+    // Demoted, "van Gogh" files under "G"; kept, under "V".
+    assert_eq!(
+        sorted_ids(DemoteNonDroppingParticle::SortOnly),
+        vec!["gogh", "smith"]
+    );
+    assert_eq!(
+        sorted_ids(DemoteNonDroppingParticle::DisplayAndSort),
+        vec!["gogh", "smith"]
+    );
+    assert_eq!(
+        sorted_ids(DemoteNonDroppingParticle::Never),
+        vec!["smith", "gogh"]
+    );
+}
+
+#[test]
+fn test_note_citation_capitalizes_leading_particle() {
+    let cite = |style: Style, prefix: Option<&str>| {
+        let processor = Processor::new(style, make_particle_bibliography());
+        processor
+            .process_citation(&Citation {
+                items: vec![crate::reference::CitationItem {
+                    id: "gogh".to_string(),
+                    prefix: prefix.map(str::to_string),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            })
+            .unwrap()
+    };
+
+    assert_eq!(cite(make_note_style(), None), "(Van Gogh, 1888)");
+    // Mid-sentence uses keep the particle as written.
+    assert_eq!(
+        cite(make_note_style(), Some("see ")),
+        "(see van Gogh, 1888)"
+    );
+    assert_eq!(cite(make_style(), None), "(van Gogh, 1888)");
+}
+
 #[test]
 fn test_sort_anonymous_work_by_title() {
     // Anonymous works (no author) should sort by title, with leading articles stripped
@@ -1462,6 +2020,7 @@ fn test_numeric_citation_numbers_follow_bibliography_sort() {
                 ascending: true,
                 order: None,
                 sort_order: None,
+                empty_author: None,
             }],
         }),
         ..Default::default()
@@ -1504,6 +2063,80 @@ fn test_numeric_citation_numbers_follow_bibliography_sort() {
     assert_eq!(result, "[1]");
 }
 
+#[test]
+fn test_numeric_empty_author_sorts_by_title() {
+    let mut style = make_style();
+    style.options = Some(Config {
+        processing: Some(Processing::Numeric),
+        ..Default::default()
+    });
+    style.citation = Some(csln_core::CitationSpec {
+        template: Some(vec![TemplateComponent::Number(
+            csln_core::template::TemplateNumber {
+                number: csln_core::template::NumberVariable::CitationNumber,
+                ..Default::default()
+            },
+        )]),
+        wrap: Some(WrapPunctuation::Brackets),
+        ..Default::default()
+    });
+    style.bibliography = Some(BibliographySpec {
+        sort: Some(csln_core::grouping::GroupSort {
+            template: vec![csln_core::grouping::GroupSortKey {
+                key: csln_core::grouping::SortKey::Author,
+                ascending: true,
+                order: None,
+                sort_order: None,
+                empty_author: Some(csln_core::grouping::EmptyAuthorSort::Title),
+            }],
+        }),
+        ..Default::default()
+    });
+
+    let mut bib = Bibliography::new();
+    for (id, family) in [("smith2020", "Smith"), ("adams2021", "Adams")] {
+        bib.insert(
+            id.to_string(),
+            Reference::from(LegacyReference {
+                id: id.to_string(),
+                ref_type: "book".to_string(),
+                author: Some(vec![Name::new(family, "Jane")]),
+                issued: Some(DateVariable::year(2020)),
+                ..Default::default()
+            }),
+        );
+    }
+    bib.insert(
+        "report".to_string(),
+        Reference::from(LegacyReference {
+            id: "report".to_string(),
+            ref_type: "report".to_string(),
+            title: Some("The Global Risks Report".to_string()),
+            issued: Some(DateVariable::year(2022)),
+            ..Default::default()
+        }),
+    );
+
+    let processor = Processor::new(style, bib);
+    let ids: Vec<String> = processor
+        .sort_references(processor.bibliography.values().collect())
+        .into_iter()
+        .filter_map(|r| r.id())
+        .collect();
+    // "The" is stripped, so the report sorts as "global" between Adams and Smith.
+    assert_eq!(ids, ["adams2021", "report", "smith2020"]);
+
+    let citation = Citation {
+        mode: csln_core::citation::CitationMode::NonIntegral,
+        items: vec![crate::reference::CitationItem {
+            id: "report".to_string(),
+            ..Default::default()
+        }],
+        ..Default::default()
+    };
+    assert_eq!(processor.process_citation(&citation).unwrap(), "[2]");
+}
+
 #[test]
 fn test_numeric_integral_with_multiple_items() {
     use csln_core::options::Processing;
@@ -1799,3 +2432,228 @@ fn test_group_heading_term_resolves_from_locale() {
 
     assert!(output.contains("# and"));
 }
+
+fn make_keyword_bibliography() -> indexmap::IndexMap<String, Reference> {
+    let mut bib = indexmap::IndexMap::new();
+    for (id, family, keyword) in [
+        ("r1", "Adams", Some("Science; methods")),
+        ("r2", "Baker", Some("history")),
+        ("r3", "Clark", None),
+        ("r4", "Davis", Some(" science ")),
+    ] {
+        bib.insert(
+            id.to_string(),
+            Reference::from(LegacyReference {
+                id: id.to_string(),
+                ref_type: "book".to_string(),
+                author: Some(vec![Name::new(family, "A.")]),
+                issued: Some(DateVariable::year(2000)),
+                extra: keyword
+                    .map(|k| HashMap::from([("keyword".to_string(), serde_json::json!(k))]))
+                    .unwrap_or_default(),
+                ..Default::default()
+            }),
+        );
+    }
+    bib
+}
+
+#[test]
+fn test_keyword_groups_by_first_keyword() {
+    let mut style = make_style();
+    style.bibliography.as_mut().unwrap().keyword_groups =
+        Some(csln_core::KeywordGrouping::default());
+
+    let processor = Processor::new(style, make_keyword_bibliography());
+    let output =
+        processor.render_grouped_bibliography_with_format::<crate::render::plain::PlainText>();
+
+    assert_eq!(
+        output,
+        "# history\n\nBaker, A. (2000)\n\n# Science\n\nAdams, A. (2000)\n\nDavis, A. (2000)\n\n# Uncategorized\n\nClark, A. (2000)"
+    );
+}
+
+#[test]
+fn test_keyword_groups_with_mapping() {
+    let mut style = make_style();
+    style.bibliography.as_mut().unwrap().keyword_groups = Some(csln_core::KeywordGrouping {
+        mapping: Some(indexmap::IndexMap::from([
+            ("methods".to_string(), "Methodology".to_string()),
+            ("History".to_string(), "Background".to_string()),
+        ])),
+        uncategorized: Some("Other".to_string()),
+    });
+
+    let processor = Processor::new(style, make_keyword_bibliography());
+    let output =
+        processor.render_grouped_bibliography_with_format::<crate::render::plain::PlainText>();
+
+    assert_eq!(
+        output,
+        "# Background\n\nBaker, A. (2000)\n\n# Methodology\n\nAdams, A. (2000)\n\n# Other\n\nClark, A. (2000)\n\nDavis, A. (2000)"
+    );
+}
+
+#[test]
+fn test_keyword_mapping_prefers_first_listed_key() {
+    let mut style = make_style();
+    style.bibliography.as_mut().unwrap().keyword_groups = Some(csln_core::KeywordGrouping {
+        mapping: Some(indexmap::IndexMap::from([
+            ("History".to_string(), "Background".to_string()),
+            ("history".to_string(), "Context".to_string()),
+        ])),
+        uncategorized: Some("Other".to_string()),
+    });
+
+    let processor = Processor::new(style, make_keyword_bibliography());
+    let output =
+        processor.render_grouped_bibliography_with_format::<crate::render::plain::PlainText>();
+
+    assert!(
+        output.starts_with("# Background\n\nBaker, A. (2000)"),
+        "{output}"
+    );
+    assert!(!output.contains("Context"), "{output}");
+}
+
+#[test]
+fn test_named_bibliographies_by_category() {
+    let mut style = make_style();
+    style.bibliography.as_mut().unwrap().keyword_groups = Some(csln_core::KeywordGrouping {
+        mapping: Some(indexmap::IndexMap::from([
+            ("primary".to_string(), "Primary Sources".to_string()),
+            ("secondary".to_string(), "Works Cited".to_string()),
+        ])),
+        uncategorized: None,
+    });
+    let mut bib = indexmap::IndexMap::new();
+    for (id, family, keyword) in [
+        ("p2", "Zeno", "primary"),
+        ("s1", "Adams", "secondary"),
+        ("p1", "Brown", "primary"),
+    ] {
+        bib.insert(
+            id.to_string(),
+            Reference::from(LegacyReference {
+                id: id.to_string(),
+                ref_type: "book".to_string(),
+                author: Some(vec![Name::new(family, "A.")]),
+                issued: Some(DateVariable::year(2000)),
+                extra: HashMap::from([("keyword".to_string(), serde_json::json!(keyword))]),
+                ..Default::default()
+            }),
+        );
+    }
+
+    let processor = Processor::new(style, bib);
+    let bibliographies = processor.render_named_bibliographies();
+
+    assert_eq!(
+        bibliographies.keys().collect::<Vec<_>>(),
+        ["Primary Sources", "Works Cited"]
+    );
+    assert_eq!(
+        bibliographies["Primary Sources"],
+        "Brown, A. (2000)\n\nZeno, A. (2000)"
+    );
+    assert_eq!(bibliographies["Works Cited"], "Adams, A. (2000)");
+}
+
+#[test]
+fn test_register_type_template() {
+    use csln_core::template::{TitleType, TypeSelector};
+
+    let mut style = make_style();
+    style.bibliography.as_mut().unwrap().type_templates = Some(HashMap::from([(
+        TypeSelector::Multiple(vec!["manuscript".to_string(), "report".to_string()]),
+        vec![csln_core::tc_title!(Primary, suffix = " [style]")],
+    )]));
+
+    let legacy: LegacyReference = serde_json::from_value(serde_json::json!({
+        "id": "ms1",
+        "type": "manuscript",
+        "title": "Field Notes",
+        "author": [{"family": "Darwin", "given": "Charles"}],
+        "issued": {"date-parts": [[1835]]}
+    }))
+    .unwrap();
+    let mut bib = indexmap::IndexMap::new();
+    bib.insert("ms1".to_string(), Reference::from(legacy));
+
+    let mut processor = Processor::new(style, bib);
+    assert_eq!(processor.render_bibliography(), "Field Notes [style]");
+
+    processor.register_type_template(
+        "manuscript".to_string(),
+        vec![TemplateComponent::Title(TemplateTitle {
+            title: TitleType::Primary,
+            rendering: Rendering {
+                prefix: Some("Manuscript: ".to_string()),
+                ..Default::default()
+            },
+            ..Default::default()
+        })],
+    );
+    assert_eq!(processor.render_bibliography(), "Manuscript: Field Notes");
+}
+
+#[test]
+fn test_processor_with_post_processor_is_send() {
+    fn assert_send<T: Send>(_: &T) {}
+    let processor = Processor::new(make_style(), make_bibliography())
+        .with_post_processor(Box::new(|s| s.to_string()));
+    assert_send(&processor);
+}
+
+#[test]
+fn test_post_processors_apply_in_order() {
+    let processor = Processor::new(make_style(), make_bibliography())
+        .with_post_processor(Box::new(|s| s.to_uppercase()))
+        .with_post_processor(Box::new(|s| format!("[{s}]")));
+
+    let citation = Citation {
+        items: vec![crate::reference::CitationItem {
+            id: "kuhn1962".to_string(),
+            ..Default::default()
+        }],
+        ..Default::default()
+    };
+    assert_eq!(
+        processor.process_citation(&citation).unwrap(),
+        "[(KUHN, 1962)]"
+    );
+
+    let bibliography = processor.render_bibliography();
+    assert!(bibliography.starts_with("[KUHN"), "{bibliography}");
+    assert!(bibliography.ends_with(']'), "{bibliography}");
+}
+
+#[test]
+fn test_catalog_entry_for_book_with_call_number() {
+    let legacy: LegacyReference = serde_json::from_value(serde_json::json!({
+        "id": "rustbook",
+        "type": "book",
+        "title": "The Rust Programming Language",
+        "author": [
+            {"family": "Klabnik", "given": "Steve"},
+            {"family": "Nichols", "given": "Carol"}
+        ],
+        "call-number": "QA76.73.R87 K53 2019",
+        "archive": "Main Library",
+        "archive_location": "Stacks, Level 3",
+        "issued": {"date-parts": [[2019]]}
+    }))
+    .unwrap();
+    let mut bib = Bibliography::new();
+    bib.insert("rustbook".to_string(), Reference::from(legacy));
+    let processor = Processor::new(make_style(), bib);
+
+    let entry = processor.catalog_entry("rustbook").unwrap();
+    assert_eq!(entry.call_number.as_deref(), Some("QA76.73.R87 K53 2019"));
+    assert_eq!(
+        entry.to_string(),
+        "QA76.73.R87 K53 2019\nThe Rust Programming Language / Steve Klabnik, Carol Nichols\nMain Library, Stacks, Level 3"
+    );
+    assert!(processor.catalog_entry("missing").is_none());
+}
@@ -23,10 +23,84 @@ pub use csln_core::reference::{
 /// A bibliography is a collection of references keyed by ID.
 pub type Bibliography = indexmap::IndexMap<String, Reference>;
 
+/// A key present in both bibliographies of a merge with differing references.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MergeConflict {
+    /// The shared reference ID.
+    pub id: String,
+    /// The reference already in the bibliography, which is kept.
+    pub existing: Reference,
+    /// The differing reference from the merged bibliography, which is dropped.
+    pub incoming: Reference,
+}
+
+/// Operations on a [`Bibliography`].
+pub trait BibliographyExt {
+    /// Merge `other` into this bibliography.
+    ///
+    /// New keys are appended in order. When a key already exists, the
+    /// existing reference is kept; if the incoming one differs, the pair is
+    /// returned as a [`MergeConflict`] so the caller can resolve it.
+    fn merge(&mut self, other: Bibliography) -> Vec<MergeConflict>;
+}
+
+impl BibliographyExt for Bibliography {
+    fn merge(&mut self, other: Bibliography) -> Vec<MergeConflict> {
+        let mut conflicts = Vec::new();
+        for (id, incoming) in other {
+            match self.get(&id) {
+                Some(existing) if *existing != incoming => conflicts.push(MergeConflict {
+                    id,
+                    existing: existing.clone(),
+                    incoming,
+                }),
+                Some(_) => {}
+                None => {
+                    self.insert(id, incoming);
+                }
+            }
+        }
+        conflicts
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn book(id: &str, title: &str) -> Reference {
+        Reference::from(csl_legacy::csl_json::Reference {
+            id: id.to_string(),
+            ref_type: "book".to_string(),
+            title: Some(title.to_string()),
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn test_merge_reports_conflicting_key() {
+        let mut bib = Bibliography::new();
+        bib.insert("kuhn".to_string(), book("kuhn", "Structure"));
+        bib.insert("same".to_string(), book("same", "Shared"));
+
+        let mut other = Bibliography::new();
+        other.insert("kuhn".to_string(), book("kuhn", "Revolutions"));
+        other.insert("same".to_string(), book("same", "Shared"));
+        other.insert("new".to_string(), book("new", "Addition"));
+
+        let conflicts = bib.merge(other);
+        assert_eq!(
+            conflicts,
+            vec![MergeConflict {
+                id: "kuhn".to_string(),
+                existing: book("kuhn", "Structure"),
+                incoming: book("kuhn", "Revolutions"),
+            }]
+        );
+        assert_eq!(bib.keys().collect::<Vec<_>>(), ["kuhn", "same", "new"]);
+        assert_eq!(bib["kuhn"], book("kuhn", "Structure"));
+    }
+
     #[test]
     fn test_parse_csl_json() {
         let json = r#"{
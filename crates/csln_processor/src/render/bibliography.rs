@@ -6,6 +6,7 @@ SPDX-FileCopyrightText: © 2023-2026 Bruce D'Arcus
 use crate::render::component::{ProcEntry, render_component_with_format};
 use crate::render::format::OutputFormat;
 use crate::render::plain::PlainText;
+use csln_core::options::TerminalPunctuation;
 use std::fmt::Write;
 
 /// Check if a character is a final punctuation mark (not a space).
@@ -22,153 +23,202 @@ pub fn refs_to_string(proc_entries: Vec<ProcEntry>) -> String {
 /// Render processed templates into a final bibliography string using a specific format.
 pub fn refs_to_string_with_format<F: OutputFormat<Output = String>>(
     proc_entries: Vec<ProcEntry>,
+) -> String {
+    refs_to_string_with_post_processor::<F>(proc_entries, |entry| entry)
+}
+
+/// Render processed templates into a final bibliography string, passing each
+/// formatted entry through `post_process` before it is joined.
+pub fn refs_to_string_with_post_processor<F: OutputFormat<Output = String>>(
+    proc_entries: Vec<ProcEntry>,
+    post_process: impl Fn(String) -> String,
 ) -> String {
     let fmt = F::default();
-    let mut rendered_entries = Vec::new();
-
-    for entry in &proc_entries {
-        let mut entry_output = String::new();
-        let proc_template = &entry.template;
-
-        // Check locale option for punctuation placement in quotes.
-        let punctuation_in_quote = proc_template
-            .first()
-            .and_then(|c| c.config.as_ref())
-            .is_some_and(|cfg| cfg.punctuation_in_quote);
-
-        // Get the bibliography separator from the config, defaulting to ". "
-        let default_separator = proc_template
-            .first()
-            .and_then(|c| c.config.as_ref())
-            .and_then(|cfg| cfg.bibliography.as_ref())
-            .and_then(|bib| bib.separator.as_deref())
-            .unwrap_or(". ");
-
-        for (j, component) in proc_template.iter().enumerate() {
-            let rendered = render_component_with_format::<F>(component);
-            if rendered.is_empty() {
-                continue;
-            }
+    let rendered_entries = proc_entries
+        .iter()
+        .map(|entry| post_process(render_entry(&fmt, entry)))
+        .collect();
+
+    let wrapper = proc_entries
+        .first()
+        .and_then(|entry| entry.template.first())
+        .and_then(|c| c.config.as_ref())
+        .and_then(|cfg| cfg.bibliography.as_ref())
+        .and_then(|bib| bib.wrapper.as_ref());
+
+    let output = match wrapper {
+        Some(wrapper) => fmt.wrapped_bibliography(rendered_entries, wrapper),
+        None => fmt.bibliography(rendered_entries),
+    };
+    fmt.finish(output)
+}
 
-            // Add separator between components.
-            if j > 0 && !entry_output.is_empty() {
-                let last_char = entry_output.chars().last().unwrap_or(' ');
-                let first_char = rendered.chars().next().unwrap_or(' ');
-
-                // Derive the first punctuation/char of the separator for comparison
-                let sep_first_char = default_separator.chars().next().unwrap_or('.');
-
-                // Check if last output ends with intentional punctuation (not just space).
-                // Component suffixes like ", " should be preserved and NOT followed by default separator.
-                // We only suppress the separator if the last non-space character is punctuation.
-                let trimmed_last = entry_output.trim_end().chars().last().unwrap_or(' ');
-                let ends_with_punctuation = is_final_punctuation(trimmed_last);
-
-                // Skip adding separator if:
-                // 1. The rendered component already starts with separator-like punctuation
-                // 2. Special handling for quotes with punctuation-in-quote locales
-                let starts_with_separator = matches!(first_char, ',' | ';' | ':' | ' ' | '.' | '(');
-
-                if starts_with_separator {
-                    // Component prefix already provides separation (or opens with paren)
-                    // If it starts with '(' and entry_output doesn't end with space, add one
-                    if first_char == '(' && !last_char.is_whitespace() && last_char != '[' {
-                        entry_output.push(' ');
-                    }
-                } else if ends_with_punctuation {
-                    // entry_output ends with punctuation (component suffix with punctuation).
-                    // This suffix is intentional formatting. Do NOT add default separator.
-                    // Just ensure there's space before the next component.
-                    if !last_char.is_whitespace() {
-                        entry_output.push(' ');
-                    }
-                    // If last_char is already whitespace, it's part of the component suffix,
-                    // so we preserve it as-is (e.g., ", " stays as ", ")
-                } else if punctuation_in_quote
-                    && (last_char == '"' || last_char == '\u{201D}')
-                    && sep_first_char == '.'
-                {
-                    // Special case: move period inside closing quote for locales that want it
-                    entry_output.pop();
-                    let quote_str = if last_char == '\u{201D}' {
-                        ".\u{201D} "
-                    } else {
-                        ".\" "
-                    };
-                    entry_output.push_str(quote_str);
+/// Render a single processed entry using a specific format, without the
+/// bibliography container.
+pub fn ref_to_string_with_format<F: OutputFormat<Output = String>>(entry: &ProcEntry) -> String {
+    let fmt = F::default();
+    fmt.finish(render_entry(&fmt, entry))
+}
+
+/// Render one bibliography entry, including its entry markup.
+fn render_entry<F: OutputFormat<Output = String>>(fmt: &F, entry: &ProcEntry) -> String {
+    let mut entry_output = String::new();
+    let proc_template = &entry.template;
+
+    // Check locale option for punctuation placement in quotes.
+    let punctuation_in_quote = proc_template
+        .first()
+        .and_then(|c| c.config.as_ref())
+        .is_some_and(|cfg| cfg.punctuation_in_quote);
+
+    // Get the bibliography separator from the config, defaulting to ". "
+    let default_separator = proc_template
+        .first()
+        .and_then(|c| c.config.as_ref())
+        .and_then(|cfg| cfg.bibliography.as_ref())
+        .and_then(|bib| bib.separator.as_deref())
+        .unwrap_or(". ");
+
+    for (j, component) in proc_template.iter().enumerate() {
+        let rendered = render_component_with_format::<F>(component);
+        if rendered.is_empty() {
+            continue;
+        }
+
+        // Add separator between components.
+        if j > 0 && !entry_output.is_empty() {
+            let last_char = entry_output.chars().last().unwrap_or(' ');
+            let first_char = rendered.chars().next().unwrap_or(' ');
+
+            // Derive the first punctuation/char of the separator for comparison
+            let sep_first_char = default_separator.chars().next().unwrap_or('.');
+
+            // Check if last output ends with intentional punctuation (not just space).
+            // Component suffixes like ", " should be preserved and NOT followed by default separator.
+            // We only suppress the separator if the last non-space character is punctuation.
+            let trimmed_last = entry_output.trim_end().chars().last().unwrap_or(' ');
+            let ends_with_punctuation = is_final_punctuation(trimmed_last);
+
+            // Skip adding separator if:
+            // 1. The rendered component already starts with separator-like punctuation
+            // 2. Special handling for quotes with punctuation-in-quote locales
+            let starts_with_separator = matches!(first_char, ',' | ';' | ':' | ' ' | '.' | '(');
+
+            if starts_with_separator {
+                // Component prefix already provides separation (or opens with paren)
+                // If it starts with '(' and entry_output doesn't end with space, add one
+                if first_char == '(' && !last_char.is_whitespace() && last_char != '[' {
+                    entry_output.push(' ');
+                }
+            } else if ends_with_punctuation {
+                // entry_output ends with punctuation (component suffix with punctuation).
+                // This suffix is intentional formatting. Do NOT add default separator.
+                // Just ensure there's space before the next component.
+                if !last_char.is_whitespace() {
+                    entry_output.push(' ');
+                }
+                // If last_char is already whitespace, it's part of the component suffix,
+                // so we preserve it as-is (e.g., ", " stays as ", ")
+            } else if punctuation_in_quote
+                && (last_char == '"' || last_char == '\u{201D}')
+                && sep_first_char == '.'
+            {
+                // Special case: move period inside closing quote for locales that want it
+                entry_output.pop();
+                let quote_str = if last_char == '\u{201D}' {
+                    ".\u{201D} "
                 } else {
-                    // Normal case: add the configured separator
-                    // Skip adding separator if we already have a space
-                    if !last_char.is_whitespace() && !first_char.is_whitespace() {
-                        entry_output.push_str(default_separator);
-                    } else if !last_char.is_whitespace() && first_char.is_whitespace() {
-                        // entry_output ends with content, component starts with space
-                        // don't add separator, but maybe ensure it has punctuation if separator is ". "
-                        if default_separator.starts_with('.') && !ends_with_punctuation {
-                            entry_output.push('.');
-                        }
+                    ".\" "
+                };
+                entry_output.push_str(quote_str);
+            } else {
+                // Normal case: add the configured separator
+                // Skip adding separator if we already have a space
+                if !last_char.is_whitespace() && !first_char.is_whitespace() {
+                    entry_output.push_str(default_separator);
+                } else if !last_char.is_whitespace() && first_char.is_whitespace() {
+                    // entry_output ends with content, component starts with space
+                    // don't add separator, but maybe ensure it has punctuation if separator is ". "
+                    if default_separator.starts_with('.') && !ends_with_punctuation {
+                        entry_output.push('.');
                     }
                 }
             }
-            let _ = write!(&mut entry_output, "{}", rendered);
         }
+        let _ = write!(&mut entry_output, "{}", rendered);
+    }
 
-        // Apply entry suffix
-        let bib_cfg = proc_template
-            .first()
-            .and_then(|c| c.config.as_ref())
-            .and_then(|cfg| cfg.bibliography.as_ref());
-        let entry_suffix = bib_cfg.and_then(|bib| bib.entry_suffix.as_deref());
-        match entry_suffix {
-            Some(suffix) if !suffix.is_empty() => {
-                let ends_with_url = ends_with_url_or_doi(&entry_output);
-                if ends_with_url {
-                    // Skip entry suffix for entries ending with URL/DOI
-                } else if !entry_output.ends_with(suffix.chars().next().unwrap_or('.')) {
-                    if suffix == "."
-                        && punctuation_in_quote
-                        && (entry_output.ends_with('"') || entry_output.ends_with('\u{201D}'))
-                    {
-                        let is_curly = entry_output.ends_with('\u{201D}');
-                        entry_output.pop();
-                        entry_output.push_str(if is_curly { ".\u{201D}" } else { ".\"" });
-                    } else {
-                        entry_output.push_str(suffix);
-                    }
+    // Apply entry suffix
+    let bib_cfg = proc_template
+        .first()
+        .and_then(|c| c.config.as_ref())
+        .and_then(|cfg| cfg.bibliography.as_ref());
+    let terminal = bib_cfg
+        .and_then(|bib| bib.terminal_punctuation)
+        .unwrap_or_default();
+    let entry_suffix = bib_cfg
+        .and_then(|bib| bib.entry_suffix.as_deref())
+        .filter(|_| terminal == TerminalPunctuation::Preserve);
+    match entry_suffix {
+        Some(suffix) if !suffix.is_empty() => {
+            let ends_with_url = ends_with_url_or_doi(&entry_output);
+            if ends_with_url {
+                // Skip entry suffix for entries ending with URL/DOI
+            } else if !entry_output.ends_with(suffix.chars().next().unwrap_or('.')) {
+                if suffix == "."
+                    && punctuation_in_quote
+                    && (entry_output.ends_with('"') || entry_output.ends_with('\u{201D}'))
+                {
+                    let is_curly = entry_output.ends_with('\u{201D}');
+                    entry_output.pop();
+                    entry_output.push_str(if is_curly { ".\u{201D}" } else { ".\"" });
+                } else {
+                    entry_output.push_str(suffix);
                 }
             }
-            _ => {}
         }
+        _ => {}
+    }
 
-        cleanup_dangling_punctuation(&mut entry_output);
-
-        // Resolve entry URL if whole-entry linking is enabled
-        let entry_url = proc_template
-            .first()
-            .and_then(|c| c.config.as_ref())
-            .and_then(|cfg| cfg.links.as_ref())
-            .and_then(|links| {
-                use csln_core::options::LinkAnchor;
-                if matches!(links.anchor, Some(LinkAnchor::Entry)) {
-                    // We need the reference to resolve the URL.
-                    // This is a bit tricky as ProcEntry doesn't have the reference.
-                    // But we can look it up from the bibliography if we had access to it.
-                    // For now, let's see if any component in the template has a URL resolved.
-                    proc_template.iter().find_map(|c| c.url.clone())
-                } else {
-                    None
-                }
-            });
-
-        rendered_entries.push(fmt.entry(
-            &entry.id,
-            entry_output,
-            entry_url.as_deref(),
-            &entry.metadata,
-        ));
+    cleanup_dangling_punctuation(&mut entry_output);
+
+    if terminal == TerminalPunctuation::Period {
+        append_terminal_period(fmt, &mut entry_output, punctuation_in_quote);
     }
 
-    fmt.finish(fmt.bibliography(rendered_entries))
+    let entry_output = match &entry.metadata.annotation {
+        Some(note) => fmt.annotated_entry(entry_output, fmt.text(note)),
+        None => entry_output,
+    };
+    let entry_output = match &entry.metadata.abstract_text {
+        Some(text) => fmt.abstract_entry(entry_output, fmt.text(text)),
+        None => entry_output,
+    };
+
+    // Resolve entry URL if whole-entry linking is enabled
+    let entry_url = proc_template
+        .first()
+        .and_then(|c| c.config.as_ref())
+        .and_then(|cfg| cfg.links.as_ref())
+        .and_then(|links| {
+            use csln_core::options::LinkAnchor;
+            if matches!(links.anchor, Some(LinkAnchor::Entry)) {
+                // We need the reference to resolve the URL.
+                // This is a bit tricky as ProcEntry doesn't have the reference.
+                // But we can look it up from the bibliography if we had access to it.
+                // For now, let's see if any component in the template has a URL resolved.
+                proc_template.iter().find_map(|c| c.url.clone())
+            } else {
+                None
+            }
+        });
+
+    fmt.entry(
+        &entry.id,
+        entry_output,
+        entry_url.as_deref(),
+        &entry.metadata,
+    )
 }
 
 /// Check if the output ends with a URL or DOI (to suppress trailing period).
@@ -186,6 +236,35 @@ fn ends_with_url_or_doi(output: &str) -> bool {
     }
 }
 
+/// End an entry with a period unless its text, ignoring any closing markup,
+/// already ends with terminal punctuation or a URL/DOI.
+fn append_terminal_period<F: OutputFormat<Output = String>>(
+    fmt: &F,
+    output: &mut String,
+    punctuation_in_quote: bool,
+) {
+    output.truncate(output.trim_end().len());
+    let (text, markup) = fmt.split_trailing_markup(output);
+    let before_quote = text.trim_end_matches(['"', '\u{201D}']);
+    if before_quote.is_empty()
+        || before_quote.ends_with(['.', '?', '!'])
+        || ends_with_url_or_doi(text)
+    {
+        return;
+    }
+    match text.chars().last() {
+        Some(quote @ ('"' | '\u{201D}')) if punctuation_in_quote => {
+            *output = format!(
+                "{}.{}{}",
+                &text[..text.len() - quote.len_utf8()],
+                quote,
+                markup
+            );
+        }
+        _ => output.push('.'),
+    }
+}
+
 fn cleanup_dangling_punctuation(output: &mut String) {
     let patterns = [
         (", .", "."),
@@ -224,6 +303,102 @@ mod tests {
     use crate::render::component::ProcTemplateComponent;
     use csln_core::template::{Rendering, TemplateComponent};
 
+    fn render_with_terminal(terminal: TerminalPunctuation, values: &[&str]) -> String {
+        use csln_core::options::{BibliographyConfig, Config};
+
+        let config = Config {
+            bibliography: Some(BibliographyConfig {
+                entry_suffix: Some(".".to_string()),
+                terminal_punctuation: Some(terminal),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let template = values
+            .iter()
+            .map(|value| ProcTemplateComponent {
+                template_component: TemplateComponent::Variable(
+                    csln_core::template::TemplateVariable::default(),
+                ),
+                value: value.to_string(),
+                prefix: None,
+                suffix: None,
+                ref_type: None,
+                config: Some(config.clone()),
+                url: None,
+                pre_formatted: false,
+            })
+            .collect();
+        refs_to_string(vec![ProcEntry {
+            id: "id1".to_string(),
+            template,
+            metadata: crate::render::format::ProcEntryMetadata::default(),
+        }])
+    }
+
+    #[test]
+    fn test_terminal_punctuation_period() {
+        assert_eq!(
+            render_with_terminal(TerminalPunctuation::Period, &["Kuhn", "Structure"]),
+            "Kuhn. Structure."
+        );
+        assert_eq!(
+            render_with_terminal(TerminalPunctuation::Period, &["Kuhn", "Structure, 2nd ed."]),
+            "Kuhn. Structure, 2nd ed."
+        );
+        assert_eq!(
+            render_with_terminal(TerminalPunctuation::Period, &["Kuhn", "What Is Science?"]),
+            "Kuhn. What Is Science?"
+        );
+    }
+
+    #[test]
+    fn test_terminal_punctuation_before_markup() {
+        use crate::render::djot::Djot;
+        use crate::render::html::Html;
+        use crate::render::latex::Latex;
+
+        fn terminate<F: OutputFormat<Output = String>>(output: &str, in_quote: bool) -> String {
+            let mut output = output.to_string();
+            append_terminal_period(&F::default(), &mut output, in_quote);
+            output
+        }
+
+        assert_eq!(
+            terminate::<Html>("<span><i>What Is Science?</i></span>", false),
+            "<span><i>What Is Science?</i></span>"
+        );
+        assert_eq!(
+            terminate::<Html>("<i>Structure</i>", false),
+            "<i>Structure</i>."
+        );
+        assert_eq!(
+            terminate::<Html>("<span>\u{201C}Title\u{201D}</span>", true),
+            "<span>\u{201C}Title.\u{201D}</span>"
+        );
+        assert_eq!(
+            terminate::<Djot>("[_What Is Science?_]{.csln-title}", false),
+            "[_What Is Science?_]{.csln-title}"
+        );
+        assert_eq!(terminate::<Djot>("_Structure_", false), "_Structure_.");
+        assert_eq!(
+            terminate::<Latex>(r"\textit{What Is Science?}", false),
+            r"\textit{What Is Science?}"
+        );
+    }
+
+    #[test]
+    fn test_terminal_punctuation_none() {
+        assert_eq!(
+            render_with_terminal(TerminalPunctuation::None, &["Kuhn", "Structure"]),
+            "Kuhn. Structure"
+        );
+        assert_eq!(
+            render_with_terminal(TerminalPunctuation::Preserve, &["Kuhn", "Structure"]),
+            "Kuhn. Structure."
+        );
+    }
+
     #[test]
     fn test_bibliography_separator_suppression() {
         use csln_core::options::{BibliographyConfig, Config};
@@ -366,6 +541,62 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_html_bibliography_wrapper() {
+        use crate::render::html::Html;
+        use csln_core::options::{BibliographyConfig, BibliographyWrapper, Config};
+        use csln_core::template::TemplateTerm;
+
+        let config = Config {
+            bibliography: Some(BibliographyConfig {
+                wrapper: Some(BibliographyWrapper {
+                    element: "section".to_string(),
+                    class: Some("references".to_string()),
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let entries = ["ref-1", "ref-2"]
+            .iter()
+            .map(|id| ProcEntry {
+                id: id.to_string(),
+                template: vec![ProcTemplateComponent {
+                    template_component: TemplateComponent::Term(TemplateTerm::default()),
+                    value: format!("Content {}", id),
+                    config: Some(config.clone()),
+                    ..Default::default()
+                }],
+                metadata: crate::render::format::ProcEntryMetadata::default(),
+            })
+            .collect();
+
+        let result = refs_to_string_with_format::<Html>(entries);
+        assert_eq!(
+            result,
+            r#"<section class="references">
+<div class="csln-entry" id="ref-ref-1">Content ref-1</div>
+<div class="csln-entry" id="ref-ref-2">Content ref-2</div>
+</section>"#
+        );
+    }
+
+    #[test]
+    fn test_html_bibliography_wrapper_attributes() {
+        use crate::render::html::Html;
+        use csln_core::options::BibliographyWrapper;
+
+        let wrapper = BibliographyWrapper {
+            element: "div onclick=x".to_string(),
+            class: Some(r#"refs" data-x="<y>"#.to_string()),
+        };
+        assert_eq!(
+            Html.wrapped_bibliography(vec!["Entry".to_string()], &wrapper),
+            "<div class=\"refs&quot; data-x=&quot;&lt;y&gt;\">\nEntry\n</div>"
+        );
+    }
+
     #[test]
     fn test_component_suffix_preserved_elsevier_harvard() {
         use csln_core::options::{BibliographyConfig, Config};
@@ -55,10 +55,10 @@ pub fn citation_to_string_with_format<F: OutputFormat<Output = String>>(
                 content.push(if is_curly { '\u{201D}' } else { '"' });
                 content.push_str(&delim[1..]);
             } else {
-                content.push_str(delim);
+                push_merging_period(&mut content, delim);
             }
         }
-        content.push_str(part);
+        push_merging_period(&mut content, part);
     }
 
     let (open, close) = match wrap {
@@ -71,6 +71,16 @@ pub fn citation_to_string_with_format<F: OutputFormat<Output = String>>(
     format!("{}{}{}", open, content, close)
 }
 
+/// Append rendered text, dropping its leading period when the text so far
+/// already ends with one (e.g., "et al." followed by ". ").
+pub(crate) fn push_merging_period(content: &mut String, next: &str) {
+    let next = match next.strip_prefix('.') {
+        Some(rest) if content.ends_with('.') => rest,
+        _ => next,
+    };
+    content.push_str(next);
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
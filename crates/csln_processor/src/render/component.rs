@@ -110,20 +110,20 @@ pub fn render_component_with_format_and_renderer<F: OutputFormat<Output = String
         output = fmt.link(url, output);
     }
 
-    // 3. Inner affixes + extracted val prefix/suffix
-    let total_inner_prefix = format!(
-        "{}{}",
-        inner_prefix,
-        component.prefix.as_deref().unwrap_or_default()
-    );
-    let total_inner_suffix = format!(
-        "{}{}",
-        component.suffix.as_deref().unwrap_or_default(),
-        inner_suffix
-    );
-
-    if !total_inner_prefix.is_empty() || !total_inner_suffix.is_empty() {
-        output = fmt.inner_affix(&total_inner_prefix, output, &total_inner_suffix);
+    // 3. Extracted val prefix/suffix, which are already formatted (e.g. a
+    // label and its no-break space), then inner affixes
+    if component.prefix.is_some() || component.suffix.is_some() {
+        output = fmt.join(
+            vec![
+                component.prefix.clone().unwrap_or_default(),
+                output,
+                component.suffix.clone().unwrap_or_default(),
+            ],
+            "",
+        );
+    }
+    if !inner_prefix.is_empty() || !inner_suffix.is_empty() {
+        output = fmt.inner_affix(inner_prefix, output, inner_suffix);
     }
 
     // 4. Wrap
@@ -187,10 +187,22 @@ pub fn render_component_with_format_and_renderer<F: OutputFormat<Output = String
                     SimpleVariable::Isbn => "isbn",
                     SimpleVariable::Issn => "issn",
                     SimpleVariable::Pmid => "pmid",
+                    SimpleVariable::Pmcid => "pmcid",
                     SimpleVariable::Note => "note",
                     SimpleVariable::Publisher => "publisher",
                     SimpleVariable::PublisherPlace => "publisher-place",
+                    SimpleVariable::OriginalPublisher => "original-publisher",
+                    SimpleVariable::OriginalPublisherPlace => "original-publisher-place",
                     SimpleVariable::Archive => "archive",
+                    SimpleVariable::ArchiveLocation => "archive-location",
+                    SimpleVariable::ArchivePlace => "archive-place",
+                    SimpleVariable::CallNumber => "call-number",
+                    SimpleVariable::Scale => "scale",
+                    SimpleVariable::Dimensions => "dimensions",
+                    SimpleVariable::Source => "source",
+                    SimpleVariable::Authority => "authority",
+                    SimpleVariable::Status => "status",
+                    SimpleVariable::PatentNumber => "patent-number",
                     _ => "variable",
                 }
             )),
@@ -305,6 +317,7 @@ pub fn get_title_category_rendering(
             .container_monograph
             .as_ref()
             .or(titles_config.monograph.as_ref()),
+        TitleType::Reviewed => titles_config.monograph.as_ref(),
         TitleType::Primary => {
             if let Some(cat) = mapped_category {
                 match cat.as_str() {
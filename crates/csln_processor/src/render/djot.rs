@@ -6,7 +6,9 @@ SPDX-FileCopyrightText: © 2023-2026 Bruce D'Arcus
 //! Djot output format.
 
 use super::format::OutputFormat;
-use csln_core::template::WrapPunctuation;
+use csln_core::locale::LabelSpace;
+use csln_core::options::BibliographyWrapper;
+use csln_core::template::{UrlWrap, WrapPunctuation};
 
 #[derive(Default, Clone)]
 pub struct Djot;
@@ -27,6 +29,36 @@ impl OutputFormat for Djot {
         output
     }
 
+    fn split_trailing_markup<'a>(&self, output: &'a str) -> (&'a str, &'a str) {
+        // Closing delimiters ("_", "*", "^") and spans with attributes
+        // ("]{.title}"); escaped characters are text.
+        let mut text = output;
+        loop {
+            if text.ends_with('}') && !text.ends_with(r"\}") {
+                match text.rfind("]{") {
+                    Some(close) => text = &text[..close],
+                    None => break,
+                }
+            } else if text.ends_with(['_', '*', '^']) && !text[..text.len() - 1].ends_with('\\') {
+                text = &text[..text.len() - 1];
+            } else {
+                break;
+            }
+        }
+        output.split_at(text.len())
+    }
+
+    fn label_space(&self, space: LabelSpace) -> Self::Output {
+        // An escaped space is a no-break space in Djot, which has no
+        // narrow form, so that one stays a Unicode character.
+        match space {
+            LabelSpace::Space => " ",
+            LabelSpace::NoBreak => "\\ ",
+            LabelSpace::NarrowNoBreak => "\u{202F}",
+        }
+        .to_string()
+    }
+
     fn emph(&self, content: Self::Output) -> Self::Output {
         if content.is_empty() {
             return content;
@@ -48,6 +80,13 @@ impl OutputFormat for Djot {
         format!("[{}]{{.small-caps}}", content)
     }
 
+    fn superscript(&self, content: Self::Output) -> Self::Output {
+        if content.is_empty() {
+            return content;
+        }
+        format!("^{}^", content)
+    }
+
     fn quote(&self, content: Self::Output) -> Self::Output {
         if content.is_empty() {
             return content;
@@ -79,6 +118,17 @@ impl OutputFormat for Djot {
         format!("[{}]{{.{}}}", content, class)
     }
 
+    fn url_wrap(&self, wrap: UrlWrap, url: &str) -> Self::Output {
+        // A bare `<url>` is a Djot autolink, which drops the brackets, and
+        // characters such as `_` would start inline markup.
+        let url = escape_markup(url);
+        match wrap {
+            UrlWrap::Angle => format!(r"\<{}\>", url),
+            UrlWrap::Paren => format!("({})", url),
+            UrlWrap::None => url,
+        }
+    }
+
     fn link(&self, url: &str, content: Self::Output) -> Self::Output {
         if content.is_empty() {
             return content;
@@ -86,6 +136,23 @@ impl OutputFormat for Djot {
         format!("[{}]({})", content, url)
     }
 
+    fn wrapped_bibliography(
+        &self,
+        entries: Vec<Self::Output>,
+        wrapper: &BibliographyWrapper,
+    ) -> Self::Output {
+        // Djot divs carry a class but no element name.
+        format!(
+            "::: {}\n{}\n:::",
+            wrapper.class.as_deref().unwrap_or(&wrapper.element),
+            self.join(entries, "\n\n")
+        )
+    }
+
+    fn annotated_entry(&self, entry: Self::Output, annotation: Self::Output) -> Self::Output {
+        format!("{}\n\n{}", entry, annotation)
+    }
+
     fn entry(
         &self,
         _id: &str,
@@ -100,3 +167,31 @@ impl OutputFormat for Djot {
         }
     }
 }
+
+/// Backslash-escape the characters Djot reads as inline markup.
+fn escape_markup(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        if matches!(
+            c,
+            '\\' | '`'
+                | '*'
+                | '_'
+                | '^'
+                | '~'
+                | '['
+                | ']'
+                | '{'
+                | '}'
+                | '<'
+                | '>'
+                | '$'
+                | '\''
+                | '"'
+        ) {
+            out.push('\\');
+        }
+        out.push(c);
+    }
+    out
+}
@@ -5,7 +5,9 @@ SPDX-FileCopyrightText: © 2023-2026 Bruce D'Arcus
 
 //! Output format trait for pluggable renderers.
 
-use csln_core::template::WrapPunctuation;
+use csln_core::locale::LabelSpace;
+use csln_core::options::BibliographyWrapper;
+use csln_core::template::{UrlWrap, WrapPunctuation};
 
 /// Trait for defining how to render template components into a specific format.
 ///
@@ -42,9 +44,32 @@ pub trait OutputFormat: Default + Clone {
     /// Render content in small capitals.
     fn small_caps(&self, content: Self::Output) -> Self::Output;
 
+    /// Render content as superscript (vertical-align: superscript).
+    ///
+    /// Formats without superscript markup return the content unchanged.
+    fn superscript(&self, content: Self::Output) -> Self::Output {
+        content
+    }
+
+    /// Render the space between a label and its value (e.g., "p. 42").
+    ///
+    /// Formats without their own no-break syntax use the Unicode character.
+    fn label_space(&self, space: LabelSpace) -> Self::Output {
+        self.text(space.as_str())
+    }
+
     /// Render content enclosed in quotation marks.
     fn quote(&self, content: Self::Output) -> Self::Output;
 
+    /// Split rendered output into its text and the markup that closes it
+    /// (e.g., "</i></span>"), so that trailing punctuation can be checked
+    /// against the text itself.
+    ///
+    /// The default implementation treats the output as plain text.
+    fn split_trailing_markup<'a>(&self, output: &'a str) -> (&'a str, &'a str) {
+        (output, "")
+    }
+
     /// Apply outer prefix and suffix strings to the content.
     ///
     /// These are typically the "prefix" and "suffix" fields from the CSLN style.
@@ -69,6 +94,18 @@ pub trait OutputFormat: Default + Clone {
         content
     }
 
+    /// Render the visible text of a URL enclosed in delimiters.
+    ///
+    /// The default implementation escapes the wrapped string with
+    /// [`OutputFormat::text`].
+    fn url_wrap(&self, wrap: UrlWrap, url: &str) -> Self::Output {
+        match wrap {
+            UrlWrap::Angle => self.text(&format!("<{}>", url)),
+            UrlWrap::Paren => self.text(&format!("({})", url)),
+            UrlWrap::None => self.text(url),
+        }
+    }
+
     /// Hyperlink the content to a URL.
     fn link(&self, url: &str, content: Self::Output) -> Self::Output;
 
@@ -84,6 +121,32 @@ pub trait OutputFormat: Default + Clone {
         self.join(entries, "\n\n")
     }
 
+    /// Render a bibliography container using a style-configured wrapper.
+    ///
+    /// The default implementation ignores the wrapper and falls back to
+    /// [`OutputFormat::bibliography`].
+    fn wrapped_bibliography(
+        &self,
+        entries: Vec<Self::Output>,
+        _wrapper: &BibliographyWrapper,
+    ) -> Self::Output {
+        self.bibliography(entries)
+    }
+
+    /// Append an annotation to a rendered bibliography entry.
+    ///
+    /// The default implementation places the annotation on its own line.
+    fn annotated_entry(&self, entry: Self::Output, annotation: Self::Output) -> Self::Output {
+        self.join(vec![entry, annotation], "\n")
+    }
+
+    /// Append an abstract block to a rendered bibliography entry.
+    ///
+    /// The default implementation sets the abstract off as its own paragraph.
+    fn abstract_entry(&self, entry: Self::Output, abstract_text: Self::Output) -> Self::Output {
+        self.join(vec![entry, abstract_text], "\n\n")
+    }
+
     /// Render a single bibliography entry with its unique identifier and optional link.
     ///
     /// The default implementation just returns the content.
@@ -107,4 +170,8 @@ pub struct ProcEntryMetadata {
     pub year: Option<String>,
     /// Rendered title string.
     pub title: Option<String>,
+    /// Annotation rendered after the entry, when the style enables it.
+    pub annotation: Option<String>,
+    /// Abstract rendered after the entry, when the style enables it.
+    pub abstract_text: Option<String>,
 }
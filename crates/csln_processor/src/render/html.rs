@@ -6,7 +6,9 @@ SPDX-FileCopyrightText: © 2023-2026 Bruce D'Arcus
 //! HTML output format.
 
 use super::format::OutputFormat;
-use csln_core::template::WrapPunctuation;
+use csln_core::locale::LabelSpace;
+use csln_core::options::BibliographyWrapper;
+use csln_core::template::{UrlWrap, WrapPunctuation};
 
 #[derive(Default, Clone)]
 pub struct Html;
@@ -27,6 +29,26 @@ impl OutputFormat for Html {
         output
     }
 
+    fn label_space(&self, space: LabelSpace) -> Self::Output {
+        match space {
+            LabelSpace::Space => " ",
+            LabelSpace::NoBreak => "&nbsp;",
+            LabelSpace::NarrowNoBreak => "&#8239;",
+        }
+        .to_string()
+    }
+
+    fn split_trailing_markup<'a>(&self, output: &'a str) -> (&'a str, &'a str) {
+        let mut text = output;
+        while text.ends_with('>') {
+            match text.rfind('<') {
+                Some(open) if text[open..].starts_with("</") => text = &text[..open],
+                _ => break,
+            }
+        }
+        output.split_at(text.len())
+    }
+
     fn emph(&self, content: Self::Output) -> Self::Output {
         if content.is_empty() {
             return content;
@@ -51,6 +73,13 @@ impl OutputFormat for Html {
         )
     }
 
+    fn superscript(&self, content: Self::Output) -> Self::Output {
+        if content.is_empty() {
+            return content;
+        }
+        format!("<sup>{}</sup>", content)
+    }
+
     fn quote(&self, content: Self::Output) -> Self::Output {
         if content.is_empty() {
             return content;
@@ -93,6 +122,15 @@ impl OutputFormat for Html {
         )
     }
 
+    fn url_wrap(&self, wrap: UrlWrap, url: &str) -> Self::Output {
+        // Angle brackets are markup, so escape them in the visible text.
+        match wrap {
+            UrlWrap::Angle => format!("&lt;{}&gt;", url),
+            UrlWrap::Paren => format!("({})", url),
+            UrlWrap::None => url.to_string(),
+        }
+    }
+
     fn link(&self, url: &str, content: Self::Output) -> Self::Output {
         if content.is_empty() {
             return content;
@@ -113,6 +151,49 @@ impl OutputFormat for Html {
         )
     }
 
+    fn wrapped_bibliography(
+        &self,
+        entries: Vec<Self::Output>,
+        wrapper: &BibliographyWrapper,
+    ) -> Self::Output {
+        // The element name comes from the style, so fall back to a div
+        // rather than emit arbitrary markup.
+        let is_tag_name = wrapper
+            .element
+            .chars()
+            .next()
+            .is_some_and(|c| c.is_ascii_alphabetic())
+            && wrapper
+                .element
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-');
+        let el = if is_tag_name {
+            wrapper.element.as_str()
+        } else {
+            "div"
+        };
+        let class = wrapper
+            .class
+            .as_ref()
+            .map(|c| format!(r#" class="{}""#, escape_attribute(c)))
+            .unwrap_or_default();
+        format!("<{el}{class}>\n{}\n</{el}>", self.join(entries, "\n"))
+    }
+
+    fn annotated_entry(&self, entry: Self::Output, annotation: Self::Output) -> Self::Output {
+        format!(
+            r#"{}<div class="csln-annotation">{}</div>"#,
+            entry, annotation
+        )
+    }
+
+    fn abstract_entry(&self, entry: Self::Output, abstract_text: Self::Output) -> Self::Output {
+        format!(
+            r#"{}<div class="csln-abstract">{}</div>"#,
+            entry, abstract_text
+        )
+    }
+
     fn entry(
         &self,
         id: &str,
@@ -140,3 +221,12 @@ impl OutputFormat for Html {
         format!(r#"<div class="csln-entry" {}>{}</div>"#, attrs, content)
     }
 }
+
+/// Escape a value for use inside a double-quoted attribute.
+fn escape_attribute(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('"', "&quot;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
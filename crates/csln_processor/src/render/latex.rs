@@ -6,7 +6,8 @@ SPDX-FileCopyrightText: © 2023-2026 Bruce D'Arcus
 //! LaTeX output format.
 
 use super::format::OutputFormat;
-use csln_core::template::WrapPunctuation;
+use csln_core::locale::LabelSpace;
+use csln_core::template::{UrlWrap, WrapPunctuation};
 
 /// LaTeX renderer.
 #[derive(Debug, Clone, Default)]
@@ -37,6 +38,25 @@ impl OutputFormat for Latex {
         output
     }
 
+    fn label_space(&self, space: LabelSpace) -> Self::Output {
+        match space {
+            LabelSpace::Space => " ",
+            LabelSpace::NoBreak => "~",
+            LabelSpace::NarrowNoBreak => r"\,",
+        }
+        .to_string()
+    }
+
+    fn split_trailing_markup<'a>(&self, output: &'a str) -> (&'a str, &'a str) {
+        // Closing braces of commands such as \textit{...}; an escaped "\}"
+        // is text.
+        let mut text = output;
+        while text.ends_with('}') && !text.ends_with(r"\}") {
+            text = &text[..text.len() - 1];
+        }
+        output.split_at(text.len())
+    }
+
     fn emph(&self, content: Self::Output) -> Self::Output {
         format!(r"\textit{{{}}}", content)
     }
@@ -49,6 +69,10 @@ impl OutputFormat for Latex {
         format!(r"\textsc{{{}}}", content)
     }
 
+    fn superscript(&self, content: Self::Output) -> Self::Output {
+        format!(r"\textsuperscript{{{}}}", content)
+    }
+
     fn quote(&self, content: Self::Output) -> Self::Output {
         format!("``{}''", content)
     }
@@ -76,6 +100,16 @@ impl OutputFormat for Latex {
         content
     }
 
+    fn url_wrap(&self, wrap: UrlWrap, url: &str) -> Self::Output {
+        // Bare `<` and `>` render as other glyphs in the default font encoding.
+        let url = self.text(url);
+        match wrap {
+            UrlWrap::Angle => format!(r"\textless{{}}{}\textgreater{{}}", url),
+            UrlWrap::Paren => format!("({})", url),
+            UrlWrap::None => url,
+        }
+    }
+
     fn link(&self, url: &str, content: Self::Output) -> Self::Output {
         format!(r"\href{{{}}}{{{}}}", url, content)
     }
@@ -87,6 +121,14 @@ impl OutputFormat for Latex {
         )
     }
 
+    fn annotated_entry(&self, entry: Self::Output, annotation: Self::Output) -> Self::Output {
+        format!("{}\n\\par {}", entry, annotation)
+    }
+
+    fn abstract_entry(&self, entry: Self::Output, abstract_text: Self::Output) -> Self::Output {
+        format!("{}\n\\par {}", entry, abstract_text)
+    }
+
     fn entry(
         &self,
         _id: &str,
@@ -6,6 +6,7 @@ SPDX-FileCopyrightText: © 2023-2026 Bruce D'Arcus
 //! Plain text output format.
 
 use super::format::OutputFormat;
+use csln_core::locale::LabelSpace;
 use csln_core::template::WrapPunctuation;
 
 #[derive(Default, Clone)]
@@ -26,6 +27,11 @@ impl OutputFormat for PlainText {
         output
     }
 
+    fn label_space(&self, _space: LabelSpace) -> Self::Output {
+        // Plain text keeps an ordinary space.
+        " ".to_string()
+    }
+
     fn emph(&self, content: Self::Output) -> Self::Output {
         if content.is_empty() {
             return content;
@@ -46,6 +52,26 @@ impl OutputFormat for PlainText {
         content
     }
 
+    fn superscript(&self, content: Self::Output) -> Self::Output {
+        // Use Unicode superscript digits; other characters are kept as is.
+        content
+            .chars()
+            .map(|c| match c {
+                '0' => '⁰',
+                '1' => '¹',
+                '2' => '²',
+                '3' => '³',
+                '4' => '⁴',
+                '5' => '⁵',
+                '6' => '⁶',
+                '7' => '⁷',
+                '8' => '⁸',
+                '9' => '⁹',
+                _ => c,
+            })
+            .collect()
+    }
+
     fn quote(&self, content: Self::Output) -> Self::Output {
         if content.is_empty() {
             return content;
@@ -0,0 +1,98 @@
+/*
+SPDX-License-Identifier: MPL-2.0
+SPDX-FileCopyrightText: © 2023-2026 Bruce D'Arcus
+*/
+
+use crate::reference::Reference;
+use crate::values::{ComponentValues, ProcHints, ProcValues, RenderOptions};
+use csln_core::template::{
+    Condition, ConditionVariable, DelimiterPunctuation, TemplateComponent, TemplateConditional,
+    TemplateDate, TemplateNumber, TemplateTitle, TemplateVariable,
+};
+
+impl ComponentValues for TemplateConditional {
+    fn values<F: crate::render::format::OutputFormat<Output = String>>(
+        &self,
+        reference: &Reference,
+        hints: &ProcHints,
+        options: &RenderOptions<'_>,
+    ) -> Option<ProcValues<F::Output>> {
+        let branch = if condition_holds::<F>(&self.condition, reference, hints, options) {
+            &self.then
+        } else {
+            &self.otherwise
+        };
+
+        // Like a list, a branch that renders only terms is suppressed.
+        let (values, has_content) =
+            crate::values::list::render_items::<F>(branch, reference, hints, options);
+        if values.is_empty() || !has_content {
+            return None;
+        }
+
+        let delimiter = self
+            .delimiter
+            .as_ref()
+            .unwrap_or(&DelimiterPunctuation::None)
+            .to_string_with_space();
+
+        Some(ProcValues {
+            value: F::default().join(values, &delimiter),
+            pre_formatted: true,
+            ..Default::default()
+        })
+    }
+}
+
+pub(crate) fn condition_holds<F: crate::render::format::OutputFormat<Output = String>>(
+    condition: &Condition,
+    reference: &Reference,
+    hints: &ProcHints,
+    options: &RenderOptions<'_>,
+) -> bool {
+    let is_present = |variable: &ConditionVariable| {
+        is_variable_present::<F>(variable, reference, hints, options)
+    };
+    condition
+        .item_type
+        .as_ref()
+        .is_none_or(|selector| selector.matches(&reference.ref_type()))
+        && condition.present.iter().all(is_present)
+        && !condition.absent.iter().any(is_present)
+}
+
+/// Check whether a reference has a value for a variable.
+///
+/// Contributors are looked up directly so that author substitution does not
+/// count as presence; other variables are present when they render.
+fn is_variable_present<F: crate::render::format::OutputFormat<Output = String>>(
+    variable: &ConditionVariable,
+    reference: &Reference,
+    hints: &ProcHints,
+    options: &RenderOptions<'_>,
+) -> bool {
+    let component = match variable {
+        ConditionVariable::Contributor(role) => {
+            return crate::values::contributor::contributor_for_role(reference, role).is_some();
+        }
+        ConditionVariable::Simple(variable) => TemplateComponent::Variable(TemplateVariable {
+            variable: variable.clone(),
+            ..Default::default()
+        }),
+        ConditionVariable::Number(number) => TemplateComponent::Number(TemplateNumber {
+            number: number.clone(),
+            ..Default::default()
+        }),
+        ConditionVariable::Date(date) => TemplateComponent::Date(TemplateDate {
+            date: date.clone(),
+            ..Default::default()
+        }),
+        ConditionVariable::Title(title) => TemplateComponent::Title(TemplateTitle {
+            title: title.clone(),
+            ..Default::default()
+        }),
+    };
+    component
+        .values::<F>(reference, hints, options)
+        .is_some_and(|v| !v.value.is_empty())
+}
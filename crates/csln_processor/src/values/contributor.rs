@@ -1,10 +1,11 @@
 use crate::reference::Reference;
 use crate::values::{ComponentValues, ProcHints, ProcValues, RenderContext, RenderOptions};
-use csln_core::locale::TermForm;
+use csln_core::locale::{Locale, TermForm};
 use csln_core::options::{
     AndOptions, AndOtherOptions, DemoteNonDroppingParticle, DisplayAsSort, EditorLabelFormat,
     ShortenListOptions, SubstituteKey,
 };
+use csln_core::reference::join_particle;
 use csln_core::template::{ContributorForm, ContributorRole, NameOrder, TemplateContributor};
 
 fn is_role_label_omitted(options: &RenderOptions<'_>, role: &ContributorRole) -> bool {
@@ -21,6 +22,35 @@ fn is_role_label_omitted(options: &RenderOptions<'_>, role: &ContributorRole) ->
         })
 }
 
+/// Whether a role takes a role label (e.g., "ed.", "dir.") by default.
+fn is_labelled_role(role: &ContributorRole) -> bool {
+    matches!(
+        role,
+        ContributorRole::Editor
+            | ContributorRole::Translator
+            | ContributorRole::Director
+            | ContributorRole::Composer
+            | ContributorRole::Illustrator
+    )
+}
+
+/// Look up the contributor a reference records for a role.
+pub(crate) fn contributor_for_role(
+    reference: &Reference,
+    role: &ContributorRole,
+) -> Option<csln_core::reference::Contributor> {
+    match role {
+        ContributorRole::Author => reference.author(),
+        ContributorRole::Editor => reference.editor(),
+        ContributorRole::Translator => reference.translator(),
+        ContributorRole::Director => reference.director(),
+        ContributorRole::Composer => reference.composer(),
+        ContributorRole::Illustrator => reference.illustrator(),
+        ContributorRole::ReviewedAuthor => reference.reviewed_author(),
+        _ => None,
+    }
+}
+
 impl ComponentValues for TemplateContributor {
     fn values<F: crate::render::format::OutputFormat<Output = String>>(
         &self,
@@ -91,16 +121,8 @@ impl ComponentValues for TemplateContributor {
         }
 
         let contributor = match &component.contributor {
-            ContributorRole::Author => {
-                if options.suppress_author {
-                    None
-                } else {
-                    reference.author()
-                }
-            }
-            ContributorRole::Editor => reference.editor(),
-            ContributorRole::Translator => reference.translator(),
-            _ => None,
+            ContributorRole::Author if options.suppress_author => None,
+            role => contributor_for_role(reference, role),
         };
 
         // Resolve multilingual names if configured
@@ -384,17 +406,25 @@ impl ComponentValues for TemplateContributor {
             let term_form = match label_config.form {
                 RoleLabelForm::Short => TermForm::Short,
                 RoleLabelForm::Long => TermForm::Long,
+                RoleLabelForm::Verb => TermForm::Verb,
+                RoleLabelForm::VerbShort => TermForm::VerbShort,
             };
 
             // Parse the role from term string (e.g., "editor" -> ContributorRole::Editor)
             let role = match label_config.term.as_str() {
-                "editor" => Some(ContributorRole::Editor),
-                "translator" => Some(ContributorRole::Translator),
-                _ => Some(component.contributor.clone()), // Fall back to current role
+                "editor" => ContributorRole::Editor,
+                "translator" => ContributorRole::Translator,
+                "director" => ContributorRole::Director,
+                "composer" => ContributorRole::Composer,
+                "illustrator" => ContributorRole::Illustrator,
+                _ => component.contributor.clone(), // Fall back to current role
             };
 
             // Look up term from locale
-            let term_text = role.and_then(|r| options.locale.role_term(&r, plural, term_form));
+            let term_text = options
+                .locale
+                .role_term(&role, plural, term_form)
+                .map(|t| crate::values::apply_text_case(t, label_config.text_case));
 
             // Apply placement
             match label_config.placement {
@@ -412,10 +442,7 @@ impl ComponentValues for TemplateContributor {
                 .and_then(|c| c.editor_label_format);
 
             if let Some(format) = editor_format {
-                if matches!(
-                    component.contributor,
-                    ContributorRole::Editor | ContributorRole::Translator
-                ) {
+                if is_labelled_role(&component.contributor) {
                     let plural = names_vec.len() > 1;
                     match format {
                         EditorLabelFormat::VerbPrefix => {
@@ -509,10 +536,7 @@ impl ComponentValues for TemplateContributor {
                             None,
                         )
                     }
-                    (
-                        ContributorForm::Long,
-                        ContributorRole::Editor | ContributorRole::Translator,
-                    ) => {
+                    (ContributorForm::Long, role) if is_labelled_role(role) => {
                         let plural = names_vec.len() > 1;
                         let term = options.locale.role_term(
                             &component.contributor,
@@ -539,12 +563,27 @@ impl ComponentValues for TemplateContributor {
             }
         };
 
-        // If we have labels, the value is pre-formatted
-        let is_pre_formatted = role_prefix.is_some() || role_suffix.is_some();
-        let final_value = if is_pre_formatted {
-            fmt.text(&formatted)
-        } else {
-            formatted
+        let shorten = component.shorten.as_ref().or_else(|| {
+            options
+                .config
+                .contributors
+                .as_ref()
+                .and_then(|c| c.shorten.as_ref())
+        });
+        let emphasized = match shorten {
+            Some(s) if s.et_al_emph => {
+                emphasize_et_al(&fmt, &formatted, and_others_term(s, options.locale))
+            }
+            _ => None,
+        };
+
+        // If we have labels or an emphasized et al., the value is pre-formatted
+        let is_pre_formatted =
+            role_prefix.is_some() || role_suffix.is_some() || emphasized.is_some();
+        let final_value = match emphasized {
+            Some(value) => value,
+            None if is_pre_formatted => fmt.text(&formatted),
+            None => formatted,
         };
 
         Some(ProcValues {
@@ -563,6 +602,38 @@ impl ComponentValues for TemplateContributor {
     }
 }
 
+/// The "and others" term for shortened name lists: the style's explicit
+/// term, else the locale's "et al." or "and others" term.
+fn and_others_term<'a>(shorten: &'a ShortenListOptions, locale: &'a Locale) -> &'a str {
+    match (&shorten.term, shorten.and_others) {
+        (Some(term), _) => term,
+        (None, AndOtherOptions::EtAl) => locale.et_al(),
+        (None, AndOtherOptions::Text) => locale.and_others(),
+    }
+}
+
+/// Emphasize a trailing "et al." term apart from the names before it.
+///
+/// The term's terminal period is kept outside the emphasis so it can merge
+/// with following punctuation. Returns `None` if the names do not end with
+/// the term.
+fn emphasize_et_al<F: crate::render::format::OutputFormat<Output = String>>(
+    fmt: &F,
+    formatted: &str,
+    et_al: &str,
+) -> Option<String> {
+    let term = et_al.trim_end_matches('.');
+    let matched = [et_al, term]
+        .into_iter()
+        .find(|t| !t.is_empty() && formatted.ends_with(t))?;
+    let names = &formatted[..formatted.len() - matched.len()];
+    let period = &matched[term.len()..];
+    Some(fmt.join(
+        vec![fmt.text(names), fmt.emph(fmt.text(term)), fmt.text(period)],
+        "",
+    ))
+}
+
 /// Format a list of names according to style options.
 #[allow(clippy::too_many_arguments)]
 pub fn format_names(
@@ -580,6 +651,11 @@ pub fn format_names(
         return String::new();
     }
 
+    // Count every name, regardless of et-al shortening.
+    if matches!(form, ContributorForm::Count) {
+        return names.len().to_string();
+    }
+
     let config = options.config.contributors.as_ref();
     let locale = options.locale;
 
@@ -588,25 +664,29 @@ pub fn format_names(
     // 2. Else use global config
     let shorten = shorten_override.or_else(|| config.and_then(|c| c.shorten.as_ref()));
 
-    let and_others = shorten
-        .map(|opts| opts.and_others)
-        .unwrap_or(AndOtherOptions::EtAl);
+    let et_al_term = shorten.map_or(locale.et_al(), |opts| and_others_term(opts, locale));
 
     let (first_names, use_et_al, last_names) = if let Some(opts) = shorten {
+        // Later citations of a reference may shorten further.
+        let (min, use_first) = match (opts.subsequent, hints.subsequent) {
+            (Some(subsequent), true) => (subsequent.min, subsequent.use_first),
+            _ => (opts.min, opts.use_first),
+        };
+
         // Phase 3: Et-al Disambiguation Logic
         // When min_names_to_show is set (name expansion disambiguation),
         // determine effective threshold for et-al application.
         let effective_min = if let Some(expanded) = hints.min_names_to_show {
             // Name expansion disambiguation: show at least 'expanded' names.
             // If normal et-al threshold is met, apply et-al but show 'expanded' names.
-            expanded.max(opts.use_first as usize)
+            expanded.max(use_first as usize)
         } else {
             // Normal mode: use standard et-al threshold
-            opts.use_first as usize
+            use_first as usize
         };
 
         // Apply et-al only if the list exceeds the minimum threshold
-        if names.len() >= opts.min as usize {
+        if names.len() >= min as usize {
             if effective_min >= names.len() {
                 // Show all names (no et-al)
                 (names.iter().collect::<Vec<_>>(), false, Vec::new())
@@ -637,12 +717,33 @@ pub fn format_names(
     let display_as_sort = config.and_then(|c| c.display_as_sort);
     let initialize_with =
         initialize_with_override.or_else(|| config.and_then(|c| c.initialize_with.as_ref()));
+    // Locales that write initials without periods also drop the spacing.
+    let unpunctuated_init = initialize_with
+        .filter(|_| locale.unpunctuated_initials)
+        .map(|init| {
+            init.chars()
+                .filter(|c| *c != '.' && !c.is_whitespace())
+                .collect::<String>()
+        });
+    let initialize_with = unpunctuated_init.as_ref().or(initialize_with);
     let initialize_with_hyphen = config.and_then(|c| c.initialize_with_hyphen);
     let demote_ndp = config.and_then(|c| c.demote_non_dropping_particle.as_ref());
     let sort_separator =
         sort_separator_override.or_else(|| config.and_then(|c| c.sort_separator.as_ref()));
     let delimiter = config.and_then(|c| c.delimiter.as_deref()).unwrap_or(", ");
 
+    // Disambiguation may expand given names for all names or only the
+    // first, and in full rather than as initials.
+    let expand_at =
+        |index: usize| hints.expand_given_names && (index == 0 || !hints.primary_name_only);
+    let initialize_at = |index: usize| {
+        if hints.full_given_names && expand_at(index) {
+            None
+        } else {
+            initialize_with
+        }
+    };
+
     let formatted_first: Vec<String> = first_names
         .iter()
         .enumerate()
@@ -653,11 +754,11 @@ pub fn format_names(
                 i,
                 &display_as_sort,
                 name_order,
-                initialize_with,
+                initialize_at(i),
                 initialize_with_hyphen,
                 demote_ndp,
                 sort_separator,
-                hints.expand_given_names,
+                expand_at(i),
             )
         })
         .collect();
@@ -673,15 +774,29 @@ pub fn format_names(
                 original_idx,
                 &display_as_sort,
                 name_order,
-                initialize_with,
+                initialize_at(original_idx),
                 initialize_with_hyphen,
                 demote_ndp,
                 sort_separator,
-                hints.expand_given_names,
+                expand_at(original_idx),
             )
         })
         .collect();
 
+    // Whether the displayed name at `index` renders in sort order, for the
+    // after-inverted-name delimiter rules. Only long forms invert, and
+    // literal names never do.
+    let long_form = |index: usize| match form {
+        ContributorForm::Long | ContributorForm::Verb | ContributorForm::VerbShort => true,
+        ContributorForm::Short => expand_at(index),
+        ContributorForm::FamilyOnly | ContributorForm::Count => false,
+    };
+    let inverted = |index: usize| {
+        long_form(index)
+            && first_names[index].literal.is_none()
+            && is_inverted(index, &display_as_sort, name_order)
+    };
+
     // Determine "and" setting: use override if provided, else global config
     let mut and_option = and_override.or_else(|| config.and_then(|c| c.and.as_ref()));
 
@@ -732,21 +847,13 @@ pub fn format_names(
         formatted_first.join(delimiter)
     } else if formatted_first.len() == 2 {
         let conjunction = and_str.as_ref().unwrap();
-        // For two names: citations don't use delimiter before conjunction,
-        // but bibliographies do (contextual Oxford comma).
-        let use_delimiter = if options.context == RenderContext::Bibliography {
-            // In bibliography, check delimiter-precedes-last setting
-            match delimiter_precedes_last {
-                Some(DelimiterPrecedesLast::Always) => true,
-                Some(DelimiterPrecedesLast::Never) => false,
-                Some(DelimiterPrecedesLast::Contextual) | None => true, // Default: use comma in bibliography
-                Some(DelimiterPrecedesLast::AfterInvertedName) => display_as_sort
-                    .as_ref()
-                    .is_some_and(|das| matches!(das, DisplayAsSort::All | DisplayAsSort::First)),
-            }
-        } else {
-            // In citations, never use delimiter before conjunction for 2 names
-            false
+        // For two names the contextual rule omits the delimiter; when unset,
+        // bibliographies keep it and citations drop it.
+        let use_delimiter = match delimiter_precedes_last {
+            Some(DelimiterPrecedesLast::Always) => true,
+            Some(DelimiterPrecedesLast::Never) | Some(DelimiterPrecedesLast::Contextual) => false,
+            Some(DelimiterPrecedesLast::AfterInvertedName) => inverted(0),
+            None => options.context == RenderContext::Bibliography,
         };
 
         if use_delimiter {
@@ -769,12 +876,7 @@ pub fn format_names(
             Some(DelimiterPrecedesLast::Always) => true,
             Some(DelimiterPrecedesLast::Never) => false,
             Some(DelimiterPrecedesLast::Contextual) | None => true, // Default: comma for 3+ names
-            Some(DelimiterPrecedesLast::AfterInvertedName) => {
-                display_as_sort.as_ref().is_some_and(|das| {
-                    matches!(das, DisplayAsSort::All)
-                        || (matches!(das, DisplayAsSort::First) && first_names.len() == 1)
-                })
-            }
+            Some(DelimiterPrecedesLast::AfterInvertedName) => inverted(rest.len() - 1),
         };
         if use_delimiter {
             format!("{}{}{} {}", rest.join(delimiter), delimiter, and_str, last)
@@ -797,10 +899,7 @@ pub fn format_names(
                 Some(DelimiterPrecedesLast::Never) => false,
                 Some(DelimiterPrecedesLast::AfterInvertedName) => {
                     // Use delimiter if last displayed name was inverted (family-first)
-                    display_as_sort.as_ref().is_some_and(|das| {
-                        matches!(das, DisplayAsSort::All)
-                            || (matches!(das, DisplayAsSort::First) && first_names.len() == 1)
-                    })
+                    first_names.len().checked_sub(1).is_some_and(inverted)
                 }
                 Some(DelimiterPrecedesLast::Contextual) | None => {
                     // Default: use delimiter only if more than one name displayed
@@ -808,15 +907,10 @@ pub fn format_names(
                 }
             };
 
-            let and_others_term = match and_others {
-                AndOtherOptions::EtAl => locale.et_al(),
-                AndOtherOptions::Text => locale.et_al().trim_end_matches('.'),
-            };
-
             if use_delimiter {
-                format!("{}, {}", result, and_others_term)
+                format!("{}, {}", result, et_al_term)
             } else {
-                format!("{} {}", result, and_others_term)
+                format!("{} {}", result, et_al_term)
             }
         }
     } else {
@@ -824,6 +918,23 @@ pub fn format_names(
     }
 }
 
+/// Whether the name at `index` in a list is written family name first.
+fn is_inverted(
+    index: usize,
+    display_as_sort: &Option<DisplayAsSort>,
+    name_order: Option<&NameOrder>,
+) -> bool {
+    match name_order {
+        Some(NameOrder::GivenFirst) => false,
+        Some(NameOrder::FamilyFirst) => true,
+        None => match display_as_sort {
+            Some(DisplayAsSort::All) => true,
+            Some(DisplayAsSort::First) => index == 0,
+            _ => false,
+        },
+    }
+}
+
 /// Format a single name.
 #[allow(clippy::too_many_arguments)]
 pub fn format_single_name(
@@ -838,8 +949,6 @@ pub fn format_single_name(
     sort_separator: Option<&String>,
     expand_given_names: bool,
 ) -> String {
-    use csln_core::template::NameOrder;
-
     // Handle literal names (e.g., corporate authors)
     if let Some(literal) = &name.literal {
         return literal.clone();
@@ -852,15 +961,7 @@ pub fn format_single_name(
     let suffix = name.suffix.as_deref().unwrap_or("");
 
     // Determine if we should invert (Family, Given)
-    let inverted = match name_order {
-        Some(NameOrder::GivenFirst) => false,
-        Some(NameOrder::FamilyFirst) => true,
-        None => match display_as_sort {
-            Some(DisplayAsSort::All) => true,
-            Some(DisplayAsSort::First) => index == 0,
-            _ => false,
-        },
-    };
+    let inverted = is_inverted(index, display_as_sort, name_order);
 
     // Determine effective form
     let effective_form = if expand_given_names && matches!(form, ContributorForm::Short) {
@@ -874,14 +975,16 @@ pub fn format_single_name(
             // FamilyOnly form strictly outputs literally just the family name without non-dropping particles.
             family.to_string()
         }
-        ContributorForm::Short => {
+        // Count applies to whole lists (see `format_names`); a single name
+        // renders as in the short form.
+        ContributorForm::Short | ContributorForm::Count => {
             // Short form usually just family name, but includes non-dropping particle
             // e.g. "van Beethoven" (unless demoted? CSL spec says demote only affects sorting/display of full names mostly?)
             // Spec: "demote-non-dropping-particle ... This attribute does not affect ... the short form"
             // So for short form, we keep ndp with family.
 
             if !ndp.is_empty() {
-                format!("{} {}", ndp, family)
+                join_particle(ndp, family)
             } else {
                 family.to_string()
             }
@@ -891,17 +994,13 @@ pub fn format_single_name(
             let demote = matches!(demote_ndp, Some(DemoteNonDroppingParticle::DisplayAndSort));
 
             let family_part = if !ndp.is_empty() && !demote {
-                format!("{} {}", ndp, family)
+                join_particle(ndp, family)
             } else {
                 family.to_string()
             };
 
             let given_part = if let Some(init) = initialize_with {
-                let separators = if initialize_with_hyphen == Some(false) {
-                    vec![' ', '\u{00A0}'] // Non-breaking space too
-                } else {
-                    vec![' ', '-', '\u{00A0}']
-                };
+                let separators = [' ', '-', '\u{00A0}']; // Non-breaking space too
 
                 let mut result = String::new();
                 let mut current_part = String::new();
@@ -911,13 +1010,18 @@ pub fn format_single_name(
                         if !current_part.is_empty() {
                             if let Some(first) = current_part.chars().next() {
                                 result.push(first);
-                                result.push_str(init);
+                                // Hyphenated initials are joined tightly ("J.-P.").
+                                result.push_str(if c == '-' { init.trim_end() } else { init });
                             }
                             current_part.clear();
                         }
-                        // Push separator if: it's not whitespace (e.g., hyphen for J.-P.),
-                        // or if init already has whitespace (so we don't double-space)
-                        if !c.is_whitespace() || init.chars().any(|ic| ic.is_whitespace()) {
+                        // Keep the hyphen unless the style disables it ("J.P.")
+                        // or initials are unpunctuated ("JP").
+                        // Whitespace is dropped: init supplies any spacing.
+                        if c == '-'
+                            && initialize_with_hyphen != Some(false)
+                            && !init.trim().is_empty()
+                        {
                             result.push(c);
                         }
                     } else {
@@ -981,10 +1085,11 @@ pub fn format_single_name(
                 if !given_part.is_empty() {
                     parts.push(given_part);
                 }
-                if !particle_part.is_empty() {
+                if !particle_part.is_empty() && !family_part.is_empty() {
+                    parts.push(join_particle(&particle_part, &family_part));
+                } else if !particle_part.is_empty() {
                     parts.push(particle_part);
-                }
-                if !family_part.is_empty() {
+                } else if !family_part.is_empty() {
                     parts.push(family_part);
                 }
                 if !suffix.is_empty() {
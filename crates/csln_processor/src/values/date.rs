@@ -1,6 +1,11 @@
 use crate::reference::{EdtfString, Reference};
 use crate::values::{ComponentValues, ProcHints, ProcValues, RenderOptions};
-use csln_core::template::{DateForm, DateVariable as TemplateDateVar, TemplateDate};
+use csln_core::locale::Locale;
+use csln_core::locale::{GeneralTerm, TermForm};
+use csln_core::options::{DateRangeForm, EraForm, MonthFormat};
+use csln_core::template::{
+    DateForm, DateVariable as TemplateDateVar, TemplateComponent, TemplateDate,
+};
 
 impl ComponentValues for TemplateDate {
     fn values<F: crate::render::format::OutputFormat<Output = String>>(
@@ -12,7 +17,11 @@ impl ComponentValues for TemplateDate {
         let fmt = F::default();
         let date_opt: Option<EdtfString> = match self.date {
             TemplateDateVar::Issued => reference.issued(),
-            TemplateDateVar::Accessed => reference.accessed(),
+            TemplateDateVar::Accessed => reference
+                .accessed()
+                .filter(|_| !crate::values::access_suppressed(reference, options)),
+            TemplateDateVar::OriginalPublished => reference.original_date(),
+            TemplateDateVar::Submitted => reference.submitted(),
             _ => None,
         };
 
@@ -20,7 +29,16 @@ impl ComponentValues for TemplateDate {
             // Handle fallback if date is missing
             if let Some(fallbacks) = &self.fallback {
                 for component in fallbacks {
-                    if let Some(values) = component.values::<F>(reference, hints, options) {
+                    if let Some(mut values) = component.values::<F>(reference, hints, options) {
+                        // Undated works by the same author are grouped like a
+                        // shared year, so they get suffixes too ("n.d.-a").
+                        if matches!(component, TemplateComponent::Term(t) if t.term == GeneralTerm::NoDate)
+                            && hints.disamb_condition
+                            && year_suffix_enabled(options)
+                            && let Some(letter) = int_to_letter(hints.group_index as u32)
+                        {
+                            values.value = format!("{}-{}", values.value, letter);
+                        }
                         return Some(values);
                     }
                 }
@@ -30,6 +48,7 @@ impl ComponentValues for TemplateDate {
 
         let date = date_opt.unwrap();
         let locale = options.locale;
+        let date_locale = options.date_locale.unwrap_or(locale);
         let date_config = options.config.dates.as_ref();
         let effective_form = if options.context == crate::values::RenderContext::Citation
             && reference.ref_type() == "personal-communication"
@@ -65,64 +84,46 @@ impl ComponentValues for TemplateDate {
             }
         }
 
+        // Numeric dates render months as numbers and join parts with the
+        // locale's numeric delimiter; textual dates use a space.
+        let numeric = date_config.is_some_and(|c| c.month == MonthFormat::Numeric);
+        let numeric_months: Vec<String> = (1..=12).map(|m| m.to_string()).collect();
+        let months = if numeric {
+            &numeric_months
+        } else if effective_form == DateForm::DayMonthAbbrYear {
+            &date_locale.dates.months.short
+        } else {
+            &date_locale.dates.months.long
+        };
+        let delimiter = date_config
+            .and_then(|c| c.delimiter.as_deref())
+            .unwrap_or(if numeric {
+                date_locale
+                    .dates
+                    .numeric_delimiter
+                    .as_deref()
+                    .unwrap_or("/")
+            } else {
+                " "
+            });
+
+        let era = date_config.and_then(|c| c.era);
         let formatted = if date.is_range() {
             // Handle date ranges
-            let start = match effective_form {
-                DateForm::Year => date.year(),
-                DateForm::YearMonth => {
-                    let month = date.month(&locale.dates.months.long);
-                    let year = date.year();
-                    if month.is_empty() {
-                        year
-                    } else {
-                        format!("{} {}", month, year)
-                    }
-                }
-                DateForm::MonthDay => {
-                    let month = date.month(&locale.dates.months.long);
-                    let day = date.day();
-                    match day {
-                        Some(d) => format!("{} {}", month, d),
-                        None => month,
-                    }
-                }
-                DateForm::Full => {
-                    let year = date.year();
-                    let month = date.month(&locale.dates.months.long);
-                    let day = date.day();
-                    match (month.is_empty(), day) {
-                        (true, _) => year,
-                        (false, None) => format!("{} {}", month, year),
-                        (false, Some(d)) => format!("{} {}, {}", month, d, year),
-                    }
-                }
-                DateForm::YearMonthDay => {
-                    let year = date.year();
-                    let month = date.month(&locale.dates.months.long);
-                    let day = date.day();
-                    match (month.is_empty(), day) {
-                        (true, _) => year,
-                        (false, None) => format!("{}, {}", year, month),
-                        (false, Some(d)) => format!("{}, {} {}", year, month, d),
-                    }
-                }
-                DateForm::DayMonthAbbrYear => {
-                    let year = date.year();
-                    let month = date.month(&locale.dates.months.short);
-                    let day = date.day();
-                    match (month.is_empty(), day) {
-                        (true, _) => year,
-                        (false, None) => format!("{} {}", month, year),
-                        (false, Some(d)) => format!("{} {} {}", d, month, year),
-                    }
-                }
-            };
+            let start = format_date_parts(
+                &effective_form,
+                &with_era(&date.year(), era, date_locale),
+                &date.month(months),
+                date.day(),
+                delimiter,
+                numeric,
+            );
 
             if date.is_open_range() {
                 // Open-ended range (e.g., "1990/..")
                 if let Some(end_marker) = date_config
                     .and_then(|c| c.open_range_marker.as_deref())
-                    .or(locale.dates.open_ended_term.as_deref())
+                    .or(date_locale.dates.open_ended_term.as_deref())
                 {
                     // U+2013 en-dash is the Unicode standard range delimiter (not language-specific)
                     let delimiter = date_config
@@ -133,91 +134,67 @@ impl ComponentValues for TemplateDate {
                     // No open-ended term available - return start date only
                     Some(start)
                 }
-            } else if let Some(end) = date.range_end(&locale.dates.months.long) {
+            } else if let Some(end) = if numeric {
+                // Numeric ranges format the end like the start.
+                date.range_end_date().map(|end| {
+                    format_date_parts(
+                        &effective_form,
+                        &with_era(&end.year(), era, date_locale),
+                        &end.month(months),
+                        end.day(),
+                        delimiter,
+                        numeric,
+                    )
+                })
+            } else {
+                date.range_end(&date_locale.dates.months.long)
+                    .map(|end| with_era(&end, era, date_locale))
+            } {
                 // Closed range with end date
                 // U+2013 en-dash is the Unicode standard range delimiter (not language-specific)
                 let delimiter = date_config
                     .map(|c| c.range_delimiter.as_str())
                     .unwrap_or("–");
-                Some(format!("{}{}{}", start, delimiter, end))
+                let narrative = date_config
+                    .is_some_and(|c| c.range_form == Some(DateRangeForm::Narrative))
+                    .then(|| {
+                        let between =
+                            date_locale.general_term(&GeneralTerm::Between, TermForm::Long)?;
+                        let and = date_locale.general_term(&GeneralTerm::And, TermForm::Long)?;
+                        Some(format!("{} {} {} {}", between, start, and, end))
+                    })
+                    .flatten();
+                Some(narrative.unwrap_or_else(|| format!("{}{}{}", start, delimiter, end)))
             } else {
                 Some(start)
             }
         } else {
             // Single date (not a range)
-            match effective_form {
-                DateForm::Year => {
-                    let year = date.year();
-                    if year.is_empty() { None } else { Some(year) }
-                }
-                DateForm::YearMonth => {
-                    let year = date.year();
-                    if year.is_empty() {
-                        return None;
-                    }
-                    let month = date.month(&locale.dates.months.long);
-                    if month.is_empty() {
-                        Some(year)
-                    } else {
-                        Some(format!("{} {}", month, year))
-                    }
-                }
-                DateForm::MonthDay => {
-                    let month = date.month(&locale.dates.months.long);
-                    if month.is_empty() {
-                        return None;
-                    }
-                    let day = date.day();
-                    match day {
-                        Some(d) => Some(format!("{} {}", month, d)),
-                        None => Some(month),
-                    }
-                }
-                DateForm::Full => {
-                    let year = date.year();
-                    if year.is_empty() {
-                        return None;
-                    }
-                    let month = date.month(&locale.dates.months.long);
-                    let day = date.day();
-                    match (month.is_empty(), day) {
-                        (true, _) => Some(year),
-                        (false, None) => Some(format!("{} {}", month, year)),
-                        (false, Some(d)) => Some(format!("{} {}, {}", month, d, year)),
-                    }
-                }
-                DateForm::YearMonthDay => {
-                    let year = date.year();
-                    if year.is_empty() {
-                        return None;
-                    }
-                    let month = date.month(&locale.dates.months.long);
-                    let day = date.day();
-                    match (month.is_empty(), day) {
-                        (true, _) => Some(year),
-                        (false, None) => Some(format!("{}, {}", year, month)),
-                        (false, Some(d)) => Some(format!("{}, {} {}", year, month, d)),
-                    }
-                }
-                DateForm::DayMonthAbbrYear => {
-                    let year = date.year();
-                    if year.is_empty() {
-                        return None;
-                    }
-                    let month = date.month(&locale.dates.months.short);
-                    let day = date.day();
-                    match (month.is_empty(), day) {
-                        (true, _) => Some(year),
-                        (false, None) => Some(format!("{} {}", month, year)),
-                        (false, Some(d)) => Some(format!("{} {} {}", d, month, year)),
-                    }
-                }
+            let year = with_era(&date.year(), era, date_locale);
+            let month = date.month(months);
+            if (year.is_empty() && effective_form != DateForm::MonthDay)
+                || (month.is_empty() && effective_form == DateForm::MonthDay)
+            {
+                return None;
             }
+            Some(format_date_parts(
+                &effective_form,
+                &year,
+                &month,
+                date.day(),
+                delimiter,
+                numeric,
+            ))
         };
 
-        // Apply uncertainty and approximation markers
+        // Apply floruit, uncertainty, and approximation markers
+        let floruit = (self.floruit == Some(true))
+            .then(|| date_locale.general_term(&GeneralTerm::Floruit, TermForm::Short))
+            .flatten();
         let formatted = formatted.map(|mut value| {
-            if date.is_approximate()
+            if let Some(term) = floruit {
+                value = format!("{} {}", term, value);
+            } else if date.is_approximate()
                 && let Some(marker) = date_config.and_then(|c| c.approximation_marker.as_ref())
             {
                 value = format!("{}{}", marker, value);
@@ -234,21 +211,7 @@ impl ComponentValues for TemplateDate {
         let suffix = if hints.disamb_condition
             && formatted.as_ref().map(|s| s.len() == 4).unwrap_or(false)
         {
-            // Check if year suffix is enabled. Fall back to AuthorDate default
-            // (year_suffix: true) when processing is not explicitly set, matching
-            // the behavior in disambiguation.rs which uses unwrap_or_default().
-            let use_suffix = options
-                .config
-                .processing
-                .as_ref()
-                .unwrap_or(&csln_core::options::Processing::AuthorDate)
-                .config()
-                .disambiguate
-                .as_ref()
-                .map(|d| d.year_suffix)
-                .unwrap_or(false);
-
-            if use_suffix {
+            if year_suffix_enabled(options) {
                 int_to_letter(hints.group_index as u32).map(|s| fmt.text(&s))
             } else {
                 None
@@ -257,6 +220,28 @@ impl ComponentValues for TemplateDate {
             None
         };
 
+        // Introduce an original or submitted date with its localized phrase
+        let label_term = match self.date {
+            TemplateDateVar::OriginalPublished => {
+                Some(csln_core::locale::GeneralTerm::OriginalWorkPublished)
+            }
+            TemplateDateVar::Submitted => Some(csln_core::locale::GeneralTerm::Submitted),
+            _ => None,
+        };
+        let formatted = formatted.map(|value| {
+            match label_term
+                .filter(|_| self.show_label == Some(true))
+                .and_then(|term| locale.general_term(&term, csln_core::locale::TermForm::Long))
+            {
+                Some(term) => format!(
+                    "{} {}",
+                    crate::values::apply_text_case(term, self.label_text_case),
+                    value
+                ),
+                None => value,
+            }
+        });
+
         formatted.map(|value| ProcValues {
             value,
             prefix: None,
@@ -273,6 +258,94 @@ impl ComponentValues for TemplateDate {
     }
 }
 
+/// Label a bare year with its era (e.g., "-300" becomes "300 BCE").
+///
+/// Anything other than a bare year, such as a range end with a month, is
+/// returned unchanged.
+fn with_era(year: &str, era: Option<EraForm>, locale: &Locale) -> String {
+    let (Some(era), Ok(value)) = (era, year.parse::<i64>()) else {
+        return year.to_string();
+    };
+    let term = match (era, value < 0) {
+        (EraForm::Bce, true) => GeneralTerm::Bce,
+        (EraForm::Bce, false) => GeneralTerm::Ce,
+        (EraForm::Bc, true) => GeneralTerm::Bc,
+        (EraForm::Bc, false) => GeneralTerm::Ad,
+    };
+    if value >= 1000 {
+        return year.to_string();
+    }
+    match locale.general_term(&term, TermForm::Long) {
+        // Some locales carry their own leading (non-breaking) space.
+        Some(label) => {
+            let label = label.replace("&#160;", "\u{00A0}");
+            if label.starts_with(char::is_whitespace) {
+                format!("{}{}", value.abs(), label)
+            } else {
+                format!("{} {}", value.abs(), label)
+            }
+        }
+        None => year.to_string(),
+    }
+}
+
+/// Join date parts in the order given by `form`.
+///
+/// Textual forms keep the comma before or after the year (e.g., "May 15,
+/// 2023"); numeric forms join every part with `delimiter` (e.g., "5/15/2023").
+fn format_date_parts(
+    form: &DateForm,
+    year: &str,
+    month: &str,
+    day: Option<u32>,
+    delimiter: &str,
+    numeric: bool,
+) -> String {
+    let year_sep = if numeric { delimiter } else { ", " };
+    match form {
+        DateForm::Year => year.to_string(),
+        DateForm::YearMonth if month.is_empty() => year.to_string(),
+        DateForm::YearMonth => format!("{}{}{}", month, delimiter, year),
+        DateForm::MonthDay => match day {
+            Some(d) => format!("{}{}{}", month, delimiter, d),
+            None => month.to_string(),
+        },
+        DateForm::Full => match (month.is_empty(), day) {
+            (true, _) => year.to_string(),
+            (false, None) => format!("{}{}{}", month, delimiter, year),
+            (false, Some(d)) => format!("{}{}{}{}{}", month, delimiter, d, year_sep, year),
+        },
+        DateForm::YearMonthDay => match (month.is_empty(), day) {
+            (true, _) => year.to_string(),
+            (false, None) => format!("{}{}{}", year, year_sep, month),
+            (false, Some(d)) => format!("{}{}{}{}{}", year, year_sep, month, delimiter, d),
+        },
+        DateForm::DayMonthAbbrYear => match (month.is_empty(), day) {
+            (true, _) => year.to_string(),
+            (false, None) => format!("{}{}{}", month, delimiter, year),
+            (false, Some(d)) => format!("{}{}{}{}{}", d, delimiter, month, delimiter, year),
+        },
+    }
+}
+
+/// Whether the style disambiguates with year suffixes.
+///
+/// Falls back to the AuthorDate default (year_suffix: true) when processing
+/// is not explicitly set, matching disambiguation.rs, which uses
+/// unwrap_or_default().
+fn year_suffix_enabled(options: &RenderOptions<'_>) -> bool {
+    options
+        .config
+        .processing
+        .as_ref()
+        .unwrap_or(&csln_core::options::Processing::AuthorDate)
+        .config()
+        .disambiguate
+        .as_ref()
+        .map(|d| d.year_suffix)
+        .unwrap_or(false)
+}
+
 pub fn int_to_letter(n: u32) -> Option<String> {
     if n == 0 {
         return None;
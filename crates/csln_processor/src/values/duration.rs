@@ -0,0 +1,61 @@
+//! Formatting of running times for audiovisual media.
+
+use csln_core::locale::{GeneralTerm, Locale, TermForm};
+
+/// Format a running time with localized unit labels (e.g., "1 hr. 30 min.").
+///
+/// Accepts ISO 8601 durations ("PT1H30M"), clock times ("1:30:00" or
+/// "58:30"), and bare minute counts ("58"). Returns `None` for any other
+/// value, which callers render unchanged.
+pub fn format_duration(value: &str, locale: &Locale) -> Option<String> {
+    let (hours, minutes, seconds) = parse_duration(value.trim())?;
+    let parts: Vec<String> = [
+        (hours, GeneralTerm::Hour),
+        (minutes, GeneralTerm::Minute),
+        (seconds, GeneralTerm::Second),
+    ]
+    .into_iter()
+    .filter(|(amount, _)| *amount > 0)
+    .map(
+        |(amount, term)| match locale.general_term(&term, TermForm::Short) {
+            Some(label) => format!("{} {}", amount, label),
+            None => amount.to_string(),
+        },
+    )
+    .collect();
+    (!parts.is_empty()).then(|| parts.join(" "))
+}
+
+/// Split a duration into hours, minutes, and seconds.
+fn parse_duration(value: &str) -> Option<(u32, u32, u32)> {
+    if let Some(rest) = value.strip_prefix("PT") {
+        let (mut hours, mut minutes, mut seconds) = (0, 0, 0);
+        let mut digits = String::new();
+        for c in rest.chars() {
+            if c.is_ascii_digit() {
+                digits.push(c);
+                continue;
+            }
+            let amount = digits.parse().ok()?;
+            digits.clear();
+            match c {
+                'H' => hours = amount,
+                'M' => minutes = amount,
+                'S' => seconds = amount,
+                _ => return None,
+            }
+        }
+        return digits.is_empty().then_some((hours, minutes, seconds));
+    }
+
+    let fields: Vec<u32> = value
+        .split(':')
+        .map(|field| field.parse().ok())
+        .collect::<Option<_>>()?;
+    match fields.as_slice() {
+        [minutes] => Some((minutes / 60, minutes % 60, 0)),
+        [minutes, seconds] => Some((minutes / 60, minutes % 60, *seconds)),
+        [hours, minutes, seconds] => Some((*hours, *minutes, *seconds)),
+        _ => None,
+    }
+}
@@ -0,0 +1,125 @@
+//! Normalization of standard identifiers (ISBN, ISSN).
+
+use csln_core::template::IsbnForm;
+
+/// Publisher-code lengths for the English-language registration groups,
+/// keyed by the upper bound of the seven-digit range that follows the group.
+const GROUP_0_RANGES: &[(u32, usize)] = &[
+    (1_999_999, 2),
+    (6_999_999, 3),
+    (8_499_999, 4),
+    (8_999_999, 5),
+    (9_499_999, 6),
+    (9_999_999, 7),
+];
+const GROUP_1_RANGES: &[(u32, usize)] = &[
+    (999_999, 2),
+    (3_999_999, 3),
+    (5_499_999, 4),
+    (8_697_999, 5),
+    (9_989_999, 6),
+    (9_999_999, 7),
+];
+
+/// Format an ISBN-10 or ISBN-13 in the requested form.
+///
+/// Values that are not valid ISBNs (wrong length or checksum) are returned
+/// unchanged. Hyphenation is only known for the English-language
+/// registration groups (0 and 1); other valid ISBNs are returned compact.
+pub fn format_isbn(value: &str, form: &IsbnForm) -> String {
+    let Some(digits) = isbn_digits(value) else {
+        return value.to_string();
+    };
+    match form {
+        IsbnForm::Compact => digits,
+        IsbnForm::Hyphenated => hyphenate_isbn(&digits).unwrap_or(digits),
+    }
+}
+
+/// Format an ISSN in the requested form.
+///
+/// Values that are not valid ISSNs are returned unchanged.
+pub fn format_issn(value: &str, form: &IsbnForm) -> String {
+    let Some(digits) = issn_digits(value) else {
+        return value.to_string();
+    };
+    match form {
+        IsbnForm::Compact => digits,
+        IsbnForm::Hyphenated => format!("{}-{}", &digits[..4], &digits[4..]),
+    }
+}
+
+/// Strip separators and validate the checksum, returning the bare digits.
+fn isbn_digits(value: &str) -> Option<String> {
+    let digits = compact(value);
+    let valid = match digits.len() {
+        13 => {
+            let sum: u32 = digits
+                .chars()
+                .enumerate()
+                .map(|(i, c)| {
+                    let d = c.to_digit(10)?;
+                    Some(if i % 2 == 0 { d } else { d * 3 })
+                })
+                .sum::<Option<u32>>()?;
+            sum.is_multiple_of(10)
+        }
+        10 => weighted_sum(&digits)?.is_multiple_of(11),
+        _ => false,
+    };
+    valid.then_some(digits)
+}
+
+fn issn_digits(value: &str) -> Option<String> {
+    let digits = compact(value);
+    (digits.len() == 8 && weighted_sum(&digits)?.is_multiple_of(11)).then_some(digits)
+}
+
+/// Remove hyphens and spaces, uppercasing a trailing "x" check digit.
+fn compact(value: &str) -> String {
+    value
+        .chars()
+        .filter(|c| !matches!(c, '-' | ' ' | '\u{2010}' | '\u{2013}'))
+        .collect::<String>()
+        .to_uppercase()
+}
+
+/// Descending-weight checksum used by ISBN-10 and ISSN (weights n..1).
+fn weighted_sum(digits: &str) -> Option<u32> {
+    let len = digits.len() as u32;
+    digits
+        .chars()
+        .enumerate()
+        .map(|(i, c)| {
+            let d = match c {
+                'X' if i + 1 == digits.len() => 10,
+                _ => c.to_digit(10)?,
+            };
+            Some(d * (len - i as u32))
+        })
+        .sum()
+}
+
+fn hyphenate_isbn(digits: &str) -> Option<String> {
+    let (prefix, body) = match digits.len() {
+        13 if digits.starts_with("978") => (Some(&digits[..3]), &digits[3..]),
+        10 => (None, digits),
+        _ => return None,
+    };
+    let group = &body[..1];
+    let ranges = match group {
+        "0" => GROUP_0_RANGES,
+        "1" => GROUP_1_RANGES,
+        _ => return None,
+    };
+    let key: u32 = body[1..8].parse().ok()?;
+    let publisher_len = ranges.iter().find(|(max, _)| key <= *max)?.1;
+    let publisher = &body[1..1 + publisher_len];
+    let title = &body[1 + publisher_len..body.len() - 1];
+    let check = &body[body.len() - 1..];
+    let parts = [group, publisher, title, check].join("-");
+    Some(match prefix {
+        Some(prefix) => format!("{}-{}", prefix, parts),
+        None => parts,
+    })
+}
@@ -8,11 +8,15 @@ SPDX-FileCopyrightText: © 2023-2026 Bruce D'Arcus
 //! This module provides the logic to extract formatted values from references
 //! based on template component specifications.
 
+pub mod conditional;
 pub mod contributor;
 pub mod date;
+pub mod duration;
+pub mod identifier;
 pub mod list;
 pub mod number;
 pub mod term;
+pub mod text;
 pub mod title;
 pub mod variable;
 
@@ -225,13 +229,21 @@ pub fn resolve_url(
             .map(|u| u.to_string())
             .or_else(|| reference.doi().map(|d| format!("https://doi.org/{}", d))),
         LinkTarget::Pubmed => reference
-            .id()
-            .filter(|id| id.starts_with("pmid:"))
-            .map(|id| format!("https://pubmed.ncbi.nlm.nih.gov/{}/", &id[5..])),
+            .pmid()
+            .or_else(|| {
+                reference
+                    .id()
+                    .and_then(|id| id.strip_prefix("pmid:").map(str::to_string))
+            })
+            .map(|pmid| format!("https://pubmed.ncbi.nlm.nih.gov/{}/", pmid)),
         LinkTarget::Pmcid => reference
-            .id()
-            .filter(|id| id.starts_with("pmc:"))
-            .map(|id| format!("https://www.ncbi.nlm.nih.gov/pmc/articles/{}/", &id[4..])),
+            .pmcid()
+            .or_else(|| {
+                reference
+                    .id()
+                    .and_then(|id| id.strip_prefix("pmc:").map(str::to_string))
+            })
+            .map(|pmcid| format!("https://www.ncbi.nlm.nih.gov/pmc/articles/{}/", pmcid)),
     }
 }
 
@@ -293,14 +305,23 @@ pub struct ProcHints {
     pub group_key: String,
     /// Whether to expand given names for disambiguation.
     pub expand_given_names: bool,
+    /// Whether expanded given names are shown in full rather than initialized.
+    pub full_given_names: bool,
+    /// Whether given-name expansion is limited to the first name.
+    pub primary_name_only: bool,
     /// Minimum number of names to show to resolve ambiguity (overrides et-al-use-first).
     pub min_names_to_show: Option<usize>,
+    /// Whether to add the short title to the citation for disambiguation.
+    pub add_title: bool,
     /// Citation number for numeric citation styles (1-based).
     pub citation_number: Option<usize>,
+    /// Whether the reference was cited earlier in the document.
+    pub subsequent: bool,
 }
 
 /// Context for rendering (citation vs bibliography).
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, serde::Serialize)]
+#[serde(rename_all = "kebab-case")]
 pub enum RenderContext {
     #[default]
     Citation,
@@ -320,6 +341,11 @@ pub struct RenderOptions<'a> {
     pub locator: Option<&'a str>,
     /// Optional locator label (e.g. page, section)
     pub locator_label: Option<csln_core::citation::LocatorType>,
+    /// Optional composite locator; takes precedence over `locator`.
+    pub locators: Option<&'a [csln_core::citation::LocatorSegment]>,
+    /// Locale for month names and other date parts, when it differs from
+    /// `locale`.
+    pub date_locale: Option<&'a Locale>,
 }
 
 /// Trait for extracting values from template components.
@@ -347,6 +373,8 @@ impl ComponentValues for TemplateComponent {
             TemplateComponent::Variable(v) => v.values::<F>(reference, hints, options),
             TemplateComponent::List(l) => l.values::<F>(reference, hints, options),
             TemplateComponent::Term(t) => t.values::<F>(reference, hints, options),
+            TemplateComponent::Text(t) => t.values::<F>(reference, hints, options),
+            TemplateComponent::Conditional(c) => c.values::<F>(reference, hints, options),
             _ => None,
         }
     }
@@ -375,3 +403,92 @@ pub fn should_strip_periods(
 pub fn strip_trailing_periods(s: &str) -> String {
     s.trim_end_matches('.').to_string()
 }
+
+/// Whether the style omits this reference's URL and accessed date because
+/// it has a DOI (`suppress-access-when-doi`).
+pub fn access_suppressed(reference: &Reference, options: &RenderOptions<'_>) -> bool {
+    options.config.suppress_access_when_doi == Some(true) && reference.doi().is_some()
+}
+
+/// Uppercase the first character of a string (e.g., "second" → "Second").
+pub fn capitalize_first(s: &str) -> String {
+    let mut chars = s.chars();
+    chars
+        .next()
+        .map(|first| first.to_uppercase().chain(chars).collect())
+        .unwrap_or_default()
+}
+
+/// Apply a style's text case to rendered text, leaving it as written when
+/// no case is set.
+pub fn apply_text_case(s: &str, text_case: Option<csln_core::template::TextCase>) -> String {
+    use csln_core::template::TextCase;
+    match text_case {
+        Some(TextCase::Lowercase) => s.to_lowercase(),
+        Some(TextCase::CapitalizeFirst) => capitalize_first(s),
+        Some(TextCase::Sentence) => sentence_case(s, true),
+        Some(TextCase::SentenceFirstWord) => sentence_case(s, false),
+        None => s.to_string(),
+    }
+}
+
+/// Sentence-case a title (e.g., "The Structure of DNA: A Review" →
+/// "The structure of DNA: A review").
+///
+/// Words with a capital past their first letter ("DNA", "iPhone") are kept
+/// as written. When `subtitles` is true, the first word after a colon,
+/// question mark, exclamation mark, or em dash is also capitalized.
+pub fn sentence_case(s: &str, subtitles: bool) -> String {
+    let mut capitalize_next = true;
+    s.split(' ')
+        .map(|word| {
+            let mut parts = Vec::new();
+            for part in word.split('-') {
+                let mut letters = part.chars().filter(|c| c.is_alphabetic());
+                let Some(_) = letters.next() else {
+                    parts.push(part.to_string());
+                    continue;
+                };
+                let cased = if letters.any(char::is_uppercase) {
+                    part.to_string()
+                } else if capitalize_next {
+                    capitalize_first_letter(part)
+                } else {
+                    part.to_lowercase()
+                };
+                capitalize_next = false;
+                parts.push(cased);
+            }
+            if subtitles && word.ends_with([':', '?', '!', '\u{2014}']) {
+                capitalize_next = true;
+            }
+            parts.join("-")
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Uppercase the first letter of a word, skipping leading punctuation.
+fn capitalize_first_letter(word: &str) -> String {
+    match word.find(char::is_alphabetic) {
+        Some(i) => format!("{}{}", &word[..i], capitalize_first(&word[i..])),
+        None => word.to_string(),
+    }
+}
+
+/// Shorten text to at most `max` characters, cutting at a word boundary and
+/// ending with an ellipsis (e.g., "A long abstract" → "A long…").
+pub fn truncate_words(s: &str, max: usize) -> String {
+    if s.chars().count() <= max {
+        return s.to_string();
+    }
+    let cut: String = s.chars().take(max).collect();
+    let kept = match cut.rfind(char::is_whitespace) {
+        Some(index) => &cut[..index],
+        None => cut.as_str(),
+    };
+    format!(
+        "{}…",
+        kept.trim_end_matches(|c: char| c.is_whitespace() || c.is_ascii_punctuation())
+    )
+}
@@ -1,7 +1,7 @@
 use crate::reference::Reference;
 use crate::values::{ComponentValues, ProcHints, ProcValues, RenderOptions};
-use csln_core::locale::TermForm;
-use csln_core::template::{NumberVariable, TemplateNumber};
+use csln_core::locale::{GeneralTerm, Locale, TermForm};
+use csln_core::template::{EditionForm, NumberVariable, TemplateNumber};
 
 impl ComponentValues for TemplateNumber {
     fn values<F: crate::render::format::OutputFormat<Output = String>>(
@@ -18,7 +18,7 @@ impl ComponentValues for TemplateNumber {
             NumberVariable::Issue => reference.issue().map(|v| v.to_string()),
             NumberVariable::Pages => {
                 if options.context == crate::values::RenderContext::Citation
-                    && options.locator.is_some()
+                    && (options.locator.is_some() || options.locators.is_some())
                     && matches!(
                         options.config.processing,
                         Some(csln_core::options::Processing::Note)
@@ -32,6 +32,8 @@ impl ComponentValues for TemplateNumber {
                 }
             }
             NumberVariable::Edition => reference.edition(),
+            NumberVariable::NumberOfVolumes => reference.number_of_volumes(),
+            NumberVariable::NumberOfPages => reference.number_of_pages(),
             NumberVariable::CollectionNumber => reference.collection_number(),
             NumberVariable::Number => reference.number(),
             NumberVariable::DocketNumber => match reference {
@@ -50,6 +52,7 @@ impl ComponentValues for TemplateNumber {
                 Reference::Monograph(r) => r.report_number.clone(),
                 _ => None,
             },
+            NumberVariable::Section => reference.section(),
             NumberVariable::CitationNumber => hints.citation_number.map(|n| n.to_string()),
             NumberVariable::CitationLabel => {
                 let config = match options.config.processing.as_ref() {
@@ -72,6 +75,15 @@ impl ComponentValues for TemplateNumber {
         };
 
         value.filter(|s| !s.is_empty()).map(|value| {
+            // Numeric editions take an ordinal form and the edition term.
+            let (value, edition_suffix) = match (&self.number, &self.edition_form) {
+                (NumberVariable::Edition, Some(form)) => match value.trim().parse::<u32>() {
+                    Ok(n) => format_edition(n, form, options.locale),
+                    Err(_) => (value, None),
+                },
+                _ => (value, None),
+            };
+
             // Resolve effective rendering options
             let mut effective_rendering = self.rendering.clone();
             if let Some(overrides) = &self.overrides {
@@ -97,11 +109,31 @@ impl ComponentValues for TemplateNumber {
                 }
             }
 
-            // Handle label if label_form is specified
-            let prefix = if let Some(label_form) = &self.label_form {
+            // Handle label if label_form is specified. Totals such as
+            // number-of-volumes take the label after the count ("3 vols.").
+            let is_total = matches!(
+                self.number,
+                NumberVariable::NumberOfVolumes | NumberVariable::NumberOfPages
+            );
+            let ref_type = reference.ref_type();
+            let label_form = self
+                .label_forms
+                .as_ref()
+                .and_then(|forms| {
+                    forms
+                        .iter()
+                        .find(|(selector, _)| selector.matches(&ref_type))
+                        .map(|(_, form)| form)
+                })
+                .or(self.label_form.as_ref());
+            let label = if let Some(label_form) = label_form {
                 if let Some(locator_type) = number_var_to_locator_type(&self.number) {
                     // Check pluralization
-                    let plural = check_plural(&value, &locator_type);
+                    let plural = if is_total {
+                        value.trim() != "1"
+                    } else {
+                        check_plural(&value, &locator_type)
+                    };
 
                     let term_form = match label_form {
                         LabelForm::Long => TermForm::Long,
@@ -109,31 +141,40 @@ impl ComponentValues for TemplateNumber {
                         LabelForm::Symbol => TermForm::Symbol,
                     };
 
+                    // Locators without a symbol fall back to the short form.
                     options
                         .locale
                         .locator_term(&locator_type, plural, term_form)
+                        .or_else(|| {
+                            options
+                                .locale
+                                .locator_term(&locator_type, plural, TermForm::Short)
+                        })
                         .map(|t| {
-                            let term_str = if crate::values::should_strip_periods(
-                                &effective_rendering,
-                                options,
-                            ) {
+                            if crate::values::should_strip_periods(&effective_rendering, options) {
                                 crate::values::strip_trailing_periods(t)
                             } else {
                                 t.to_string()
-                            };
-                            fmt.text(&format!("{} ", term_str))
+                            }
                         })
+                        .map(|t| crate::values::apply_text_case(&t, self.label_text_case))
                 } else {
                     None
                 }
             } else {
                 None
             };
+            let space = fmt.label_space(options.locale.label_space);
+            let (prefix, suffix) = match label {
+                Some(term_str) if is_total => (None, Some(space + &fmt.text(&term_str))),
+                Some(term_str) => (Some(fmt.text(&term_str) + &space), None),
+                None => (None, edition_suffix.map(|s| fmt.text(&s))),
+            };
 
             ProcValues {
                 value,
                 prefix,
-                suffix: None,
+                suffix,
                 url: crate::values::resolve_effective_url(
                     self.links.as_ref(),
                     options.config.links.as_ref(),
@@ -147,6 +188,23 @@ impl ComponentValues for TemplateNumber {
     }
 }
 
+/// Format an edition number with the localized edition term.
+fn format_edition(n: u32, form: &EditionForm, locale: &Locale) -> (String, Option<String>) {
+    let (value, term_form) = match form {
+        EditionForm::Numeric => (n.to_string(), TermForm::Short),
+        EditionForm::Ordinal => (locale.ordinal(n), TermForm::Short),
+        EditionForm::LongOrdinal => (
+            crate::values::capitalize_first(&locale.long_ordinal(n)),
+            TermForm::Long,
+        ),
+    };
+    let term = locale
+        .general_term(&GeneralTerm::Edition, term_form)
+        .filter(|t| !t.is_empty())
+        .map(|t| format!(" {}", t));
+    (value, term)
+}
+
 pub fn number_var_to_locator_type(
     var: &NumberVariable,
 ) -> Option<csln_core::citation::LocatorType> {
@@ -163,6 +221,7 @@ pub fn number_var_to_locator_type(
         | NumberVariable::StandardNumber
         | NumberVariable::ReportNumber => Some(LocatorType::Number),
         NumberVariable::Issue => Some(LocatorType::Issue),
+        NumberVariable::Section => Some(LocatorType::Section),
         _ => None,
     }
 }
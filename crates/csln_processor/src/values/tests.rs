@@ -53,6 +53,8 @@ fn test_contributor_values() {
         suppress_author: false,
         locator: None,
         locator_label: None,
+        locators: None,
+        date_locale: None,
     };
     let reference = make_reference();
     let hints = ProcHints::default();
@@ -90,6 +92,8 @@ fn test_date_values() {
         suppress_author: false,
         locator: None,
         locator_label: None,
+        locators: None,
+        date_locale: None,
     };
     let reference = make_reference();
     let hints = ProcHints::default();
@@ -97,6 +101,9 @@ fn test_date_values() {
     let component = TemplateDate {
         date: TemplateDateVar::Issued,
         form: DateForm::Year,
+        show_label: None,
+        label_text_case: None,
+        floruit: None,
         fallback: None,
         rendering: Default::default(),
         links: None,
@@ -122,6 +129,8 @@ fn test_et_al() {
         suppress_author: false,
         locator: None,
         locator_label: None,
+        locators: None,
+        date_locale: None,
     };
     let hints = ProcHints::default();
 
@@ -157,6 +166,56 @@ fn test_et_al() {
     assert_eq!(values.value, "LeCun et al.");
 }
 
+#[test]
+fn test_contributor_count() {
+    let config = make_config();
+    let locale = make_locale();
+    let options = RenderOptions {
+        config: &config,
+        locale: &locale,
+        context: RenderContext::Citation,
+        mode: csln_core::citation::CitationMode::NonIntegral,
+        suppress_author: false,
+        locator: None,
+        locator_label: None,
+        locators: None,
+        date_locale: None,
+    };
+    let hints = ProcHints::default();
+    let count = |contributor: ContributorRole, reference: &Reference| {
+        TemplateContributor {
+            contributor,
+            form: ContributorForm::Count,
+            ..Default::default()
+        }
+        .values::<PlainText>(reference, &hints, &options)
+        .map(|values| values.value)
+    };
+
+    assert_eq!(
+        count(ContributorRole::Author, &make_reference()),
+        Some("1".to_string())
+    );
+
+    // All names count, even though the list would shorten to "LeCun et al.".
+    let multi = Reference::from(LegacyReference {
+        id: "multi".to_string(),
+        ref_type: "article-journal".to_string(),
+        author: Some(vec![
+            Name::new("LeCun", "Yann"),
+            Name::new("Bengio", "Yoshua"),
+            Name::new("Hinton", "Geoffrey"),
+        ]),
+        ..Default::default()
+    });
+    assert_eq!(
+        count(ContributorRole::Author, &multi),
+        Some("3".to_string())
+    );
+
+    assert_eq!(count(ContributorRole::Editor, &make_reference()), None);
+}
+
 #[test]
 fn test_format_page_range_expanded() {
     use csln_core::options::PageRangeFormat;
@@ -226,6 +285,82 @@ fn test_format_page_range_no_format() {
     assert_eq!(number::format_page_range("321-328", None), "321–328");
 }
 
+#[test]
+fn test_delimiter_precedes_last_modes() {
+    use csln_core::options::DelimiterPrecedesLast;
+
+    let render = |mode: DelimiterPrecedesLast, authors: Vec<Name>| {
+        let mut config = make_config();
+        if let Some(ref mut contributors) = config.contributors {
+            contributors.shorten = None;
+            contributors.and = Some(AndOptions::Text);
+            contributors.delimiter_precedes_last = Some(mode);
+        }
+        let locale = make_locale();
+        let options = RenderOptions {
+            config: &config,
+            locale: &locale,
+            context: RenderContext::Bibliography,
+            mode: csln_core::citation::CitationMode::NonIntegral,
+            suppress_author: false,
+            locator: None,
+            locator_label: None,
+            locators: None,
+            date_locale: None,
+        };
+        let reference = Reference::from(LegacyReference {
+            id: "multi".to_string(),
+            ref_type: "book".to_string(),
+            author: Some(authors),
+            ..Default::default()
+        });
+        TemplateContributor {
+            contributor: ContributorRole::Author,
+            form: ContributorForm::Long,
+            ..Default::default()
+        }
+        .values::<PlainText>(&reference, &ProcHints::default(), &options)
+        .unwrap()
+        .value
+    };
+    let two = || vec![Name::new("Smith", "John"), Name::new("Jones", "Jane")];
+    let three = || {
+        vec![
+            Name::new("Smith", "John"),
+            Name::new("Jones", "Jane"),
+            Name::new("Lee", "Ann"),
+        ]
+    };
+
+    // Only the first name is inverted (display-as-sort: first).
+    let cases = [
+        (
+            DelimiterPrecedesLast::Always,
+            "Smith, John, and Jane Jones",
+            "Smith, John, Jane Jones, and Ann Lee",
+        ),
+        (
+            DelimiterPrecedesLast::Never,
+            "Smith, John and Jane Jones",
+            "Smith, John, Jane Jones and Ann Lee",
+        ),
+        (
+            DelimiterPrecedesLast::Contextual,
+            "Smith, John and Jane Jones",
+            "Smith, John, Jane Jones, and Ann Lee",
+        ),
+        (
+            DelimiterPrecedesLast::AfterInvertedName,
+            "Smith, John, and Jane Jones",
+            "Smith, John, Jane Jones and Ann Lee",
+        ),
+    ];
+    for (mode, expected_two, expected_three) in cases {
+        assert_eq!(render(mode, two()), expected_two, "{:?}", mode);
+        assert_eq!(render(mode, three()), expected_three, "{:?}", mode);
+    }
+}
+
 #[test]
 fn test_et_al_delimiter_never() {
     use csln_core::options::DelimiterPrecedesLast;
@@ -249,6 +384,8 @@ fn test_et_al_delimiter_never() {
         suppress_author: false,
         locator: None,
         locator_label: None,
+        locators: None,
+        date_locale: None,
     };
     let hints = ProcHints::default();
 
@@ -304,6 +441,8 @@ fn test_et_al_delimiter_always() {
         suppress_author: false,
         locator: None,
         locator_label: None,
+        locators: None,
+        date_locale: None,
     };
     let hints = ProcHints::default();
 
@@ -413,6 +552,181 @@ fn test_demote_non_dropping_particle() {
     assert_eq!(res_straight, "Ludwig van Beethoven");
 }
 
+#[test]
+fn test_multiple_and_elided_particles() {
+    use csln_core::options::DemoteNonDroppingParticle;
+
+    let format = |name: &FlatName, display_as_sort, demote| {
+        contributor::format_single_name(
+            name,
+            &ContributorForm::Long,
+            0,
+            &Some(display_as_sort),
+            None,
+            None,
+            None,
+            Some(&demote),
+            None,
+            false,
+        )
+    };
+    let van_der = FlatName {
+        family: Some("Berg".to_string()),
+        given: Some("Anna".to_string()),
+        non_dropping_particle: Some("van der".to_string()),
+        ..Default::default()
+    };
+    let dalembert = FlatName {
+        family: Some("Alembert".to_string()),
+        given: Some("Jean".to_string()),
+        non_dropping_particle: Some("d'".to_string()),
+        ..Default::default()
+    };
+
+    assert_eq!(
+        format(
+            &van_der,
+            DisplayAsSort::None,
+            DemoteNonDroppingParticle::SortOnly
+        ),
+        "Anna van der Berg"
+    );
+    assert_eq!(
+        format(
+            &van_der,
+            DisplayAsSort::All,
+            DemoteNonDroppingParticle::SortOnly
+        ),
+        "van der Berg, Anna"
+    );
+    assert_eq!(
+        format(
+            &van_der,
+            DisplayAsSort::All,
+            DemoteNonDroppingParticle::DisplayAndSort
+        ),
+        "Berg, Anna van der"
+    );
+    assert_eq!(
+        format(
+            &dalembert,
+            DisplayAsSort::None,
+            DemoteNonDroppingParticle::Never
+        ),
+        "Jean d'Alembert"
+    );
+    assert_eq!(
+        format(
+            &dalembert,
+            DisplayAsSort::None,
+            DemoteNonDroppingParticle::DisplayAndSort
+        ),
+        "Jean d'Alembert"
+    );
+    assert_eq!(
+        format(
+            &dalembert,
+            DisplayAsSort::All,
+            DemoteNonDroppingParticle::Never
+        ),
+        "d'Alembert, Jean"
+    );
+}
+
+#[test]
+fn test_initialize_hyphenated_given_names() {
+    let name = FlatName {
+        family: Some("Sartre".to_string()),
+        given: Some("Jean-Paul".to_string()),
+        ..Default::default()
+    };
+    let init = ". ".to_string();
+    let format = |hyphen| {
+        contributor::format_single_name(
+            &name,
+            &ContributorForm::Long,
+            0,
+            &None,
+            None,
+            Some(&init),
+            hyphen,
+            None,
+            None,
+            false,
+        )
+    };
+
+    assert_eq!(format(None), "J.-P. Sartre");
+    assert_eq!(format(Some(true)), "J.-P. Sartre");
+    assert_eq!(format(Some(false)), "J.P. Sartre");
+    let two_names = FlatName {
+        given: Some("Jean Paul".to_string()),
+        ..name.clone()
+    };
+    assert_eq!(
+        contributor::format_single_name(
+            &two_names,
+            &ContributorForm::Long,
+            0,
+            &None,
+            None,
+            Some(&init),
+            None,
+            None,
+            None,
+            false,
+        ),
+        "J. P. Sartre"
+    );
+}
+
+#[test]
+fn test_initialize_without_locale_periods() {
+    let config = Config {
+        contributors: Some(ContributorConfig {
+            initialize_with: Some(". ".to_string()),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let mut locale = make_locale();
+    locale.unpunctuated_initials = true;
+    let options = RenderOptions {
+        config: &config,
+        locale: &locale,
+        context: RenderContext::Citation,
+        mode: csln_core::citation::CitationMode::NonIntegral,
+        suppress_author: false,
+        locator: None,
+        locator_label: None,
+        locators: None,
+        date_locale: None,
+    };
+    let names = |given: &str| {
+        vec![FlatName {
+            family: Some("Sartre".to_string()),
+            given: Some(given.to_string()),
+            ..Default::default()
+        }]
+    };
+    let format = |given: &str| {
+        contributor::format_names(
+            &names(given),
+            &ContributorForm::Long,
+            &options,
+            None,
+            None,
+            None,
+            None,
+            None,
+            &ProcHints::default(),
+        )
+    };
+
+    assert_eq!(format("Jean Paul"), "JP Sartre");
+    assert_eq!(format("Jean-Paul"), "JP Sartre");
+}
+
 #[test]
 fn test_template_list_suppression() {
     let config = make_config();
@@ -425,6 +739,8 @@ fn test_template_list_suppression() {
         suppress_author: false,
         locator: None,
         locator_label: None,
+        locators: None,
+        date_locale: None,
     };
     let reference = Reference::from(LegacyReference {
         id: "multi".to_string(),
@@ -472,6 +788,8 @@ fn test_et_al_use_last() {
         suppress_author: false,
         locator: None,
         locator_label: None,
+        locators: None,
+        date_locale: None,
     };
     let hints = ProcHints::default();
 
@@ -522,6 +840,8 @@ fn test_et_al_use_last_overlap() {
         suppress_author: false,
         locator: None,
         locator_label: None,
+        locators: None,
+        date_locale: None,
     };
     let hints = ProcHints::default();
 
@@ -565,6 +885,8 @@ fn test_title_hyperlink() {
         suppress_author: false,
         locator: None,
         locator_label: None,
+        locators: None,
+        date_locale: None,
     };
     let hints = ProcHints::default();
 
@@ -609,6 +931,8 @@ fn test_title_hyperlink_url_fallback() {
         suppress_author: false,
         locator: None,
         locator_label: None,
+        locators: None,
+        date_locale: None,
     };
     let hints = ProcHints::default();
 
@@ -652,6 +976,8 @@ fn test_variable_hyperlink() {
         suppress_author: false,
         locator: None,
         locator_label: None,
+        locators: None,
+        date_locale: None,
     };
     let hints = ProcHints::default();
 
@@ -680,6 +1006,87 @@ fn test_variable_hyperlink() {
     assert_eq!(values.url, Some("https://doi.org/10.1234/pub".to_string()));
 }
 
+#[test]
+fn test_genre_values() {
+    let config = make_config();
+    let locale = make_locale();
+    let options = RenderOptions {
+        config: &config,
+        locale: &locale,
+        context: RenderContext::Bibliography,
+        mode: csln_core::citation::CitationMode::NonIntegral,
+        suppress_author: false,
+        locator: None,
+        locator_label: None,
+        locators: None,
+        date_locale: None,
+    };
+    let hints = ProcHints::default();
+
+    let component = TemplateVariable {
+        variable: SimpleVariable::Genre,
+        type_fallback: Some(true),
+        text_case: Some(TextCase::CapitalizeFirst),
+        rendering: Rendering {
+            wrap: Some(WrapPunctuation::Brackets),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    let thesis = Reference::from(LegacyReference {
+        id: "thesis".to_string(),
+        ref_type: "thesis".to_string(),
+        title: Some("On Citations".to_string()),
+        genre: Some("PhD thesis".to_string()),
+        ..Default::default()
+    });
+    let values = component
+        .values::<PlainText>(&thesis, &hints, &options)
+        .unwrap();
+    assert_eq!(values.value, "PhD thesis");
+
+    let report = Reference::from(LegacyReference {
+        id: "report".to_string(),
+        ref_type: "report".to_string(),
+        title: Some("Annual Review".to_string()),
+        ..Default::default()
+    });
+    let values = component
+        .values::<PlainText>(&report, &hints, &options)
+        .unwrap();
+    assert_eq!(values.value, "Report");
+    let (rendered, _) = crate::values::list::render_items::<PlainText>(
+        &[TemplateComponent::Variable(component.clone())],
+        &report,
+        &hints,
+        &options,
+    );
+    assert_eq!(rendered, vec!["[Report]"]);
+
+    // The fallback term is used as the locale writes it unless the
+    // component sets a text case.
+    let uncased = TemplateVariable {
+        text_case: None,
+        ..component.clone()
+    };
+    let values = uncased
+        .values::<PlainText>(&report, &hints, &options)
+        .unwrap();
+    assert_eq!(values.value, "report");
+
+    // Without the fallback, an empty genre renders nothing.
+    let component = TemplateVariable {
+        variable: SimpleVariable::Genre,
+        ..Default::default()
+    };
+    assert!(
+        component
+            .values::<PlainText>(&report, &hints, &options)
+            .is_none()
+    );
+}
+
 #[test]
 fn test_editor_label_format() {
     let mut config = make_config();
@@ -713,6 +1120,8 @@ fn test_editor_label_format() {
             suppress_author: false,
             locator: None,
             locator_label: None,
+            locators: None,
+            date_locale: None,
         };
         let values = component
             .values::<PlainText>(&reference, &hints, &options)
@@ -734,6 +1143,8 @@ fn test_editor_label_format() {
             suppress_author: false,
             locator: None,
             locator_label: None,
+            locators: None,
+            date_locale: None,
         };
         let values = component
             .values::<PlainText>(&reference, &hints, &options)
@@ -755,6 +1166,8 @@ fn test_editor_label_format() {
             suppress_author: false,
             locator: None,
             locator_label: None,
+            locators: None,
+            date_locale: None,
         };
         let values = component
             .values::<PlainText>(&reference, &hints, &options)
@@ -776,6 +1189,8 @@ fn test_term_values() {
         suppress_author: false,
         locator: None,
         locator_label: None,
+        locators: None,
+        date_locale: None,
     };
     let reference = make_reference();
     let hints = ProcHints::default();
@@ -806,6 +1221,8 @@ fn test_template_list_term_suppression() {
         suppress_author: false,
         locator: None,
         locator_label: None,
+        locators: None,
+        date_locale: None,
     };
     // Reference with no editor
     let reference = make_reference();
@@ -845,6 +1262,8 @@ fn test_date_fallback() {
         suppress_author: false,
         locator: None,
         locator_label: None,
+        locators: None,
+        date_locale: None,
     };
     // Reference with NO issued date
     let reference = Reference::from(LegacyReference {
@@ -897,6 +1316,8 @@ fn test_strip_periods_global_config() {
         suppress_author: false,
         locator: None,
         locator_label: None,
+        locators: None,
+        date_locale: None,
     };
 
     let component = TemplateContributor {
@@ -937,6 +1358,8 @@ fn test_strip_periods_component_override() {
         suppress_author: false,
         locator: None,
         locator_label: None,
+        locators: None,
+        date_locale: None,
     };
 
     // Component overrides global setting
@@ -981,6 +1404,8 @@ fn test_strip_periods_no_strip_by_default() {
         suppress_author: false,
         locator: None,
         locator_label: None,
+        locators: None,
+        date_locale: None,
     };
 
     let component = TemplateContributor {
@@ -1021,6 +1446,8 @@ fn test_should_strip_periods_precedence() {
         suppress_author: false,
         locator: None,
         locator_label: None,
+        locators: None,
+        date_locale: None,
     };
 
     // Component override takes precedence
@@ -1050,6 +1477,304 @@ fn test_should_strip_periods_precedence() {
         suppress_author: false,
         locator: None,
         locator_label: None,
+        locators: None,
+        date_locale: None,
     };
     assert!(!should_strip_periods(&rendering_default, &options_none));
 }
+
+#[test]
+fn test_edition_forms() {
+    let config = make_config();
+    let locale = make_locale();
+    let options = RenderOptions {
+        config: &config,
+        locale: &locale,
+        context: RenderContext::Bibliography,
+        mode: csln_core::citation::CitationMode::NonIntegral,
+        suppress_author: false,
+        locator: None,
+        locator_label: None,
+        locators: None,
+        date_locale: None,
+    };
+    let hints = ProcHints::default();
+    let reference = Reference::from(LegacyReference {
+        id: "kuhn1970".to_string(),
+        ref_type: "book".to_string(),
+        title: Some("The Structure of Scientific Revolutions".to_string()),
+        edition: Some(csl_legacy::csl_json::StringOrNumber::Number(2)),
+        ..Default::default()
+    });
+    let render = |edition_form| {
+        let component = TemplateNumber {
+            number: NumberVariable::Edition,
+            edition_form,
+            ..Default::default()
+        };
+        let values = component
+            .values::<PlainText>(&reference, &hints, &options)
+            .unwrap();
+        format!("{}{}", values.value, values.suffix.unwrap_or_default())
+    };
+
+    assert_eq!(render(Some(EditionForm::Ordinal)), "2nd ed.");
+    assert_eq!(render(Some(EditionForm::LongOrdinal)), "Second edition");
+    assert_eq!(render(Some(EditionForm::Numeric)), "2 ed.");
+    assert_eq!(render(None), "2");
+}
+
+fn render_editors(
+    count: usize,
+    delimiter_precedes_last: DelimiterPrecedesLast,
+    display_as_sort: Option<DisplayAsSort>,
+) -> String {
+    let config = Config {
+        contributors: Some(ContributorConfig {
+            and: Some(AndOptions::Text),
+            display_as_sort,
+            delimiter_precedes_last: Some(delimiter_precedes_last),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let locale = make_locale();
+    let options = RenderOptions {
+        config: &config,
+        locale: &locale,
+        context: RenderContext::Citation,
+        mode: csln_core::citation::CitationMode::NonIntegral,
+        suppress_author: false,
+        locator: None,
+        locator_label: None,
+        locators: None,
+        date_locale: None,
+    };
+    let editors = [
+        Name::new("Smith", "John"),
+        Name::new("Jones", "Jane"),
+        Name::new("Brown", "Anne"),
+    ];
+    let reference = Reference::from(LegacyReference {
+        id: "edited".to_string(),
+        ref_type: "book".to_string(),
+        editor: Some(editors[..count].to_vec()),
+        ..Default::default()
+    });
+    let component = TemplateContributor {
+        contributor: ContributorRole::Editor,
+        form: ContributorForm::Long,
+        ..Default::default()
+    };
+    component
+        .values::<PlainText>(&reference, &ProcHints::default(), &options)
+        .unwrap()
+        .value
+}
+
+#[test]
+fn test_delimiter_precedes_last_contextual() {
+    let mode = DelimiterPrecedesLast::Contextual;
+    assert_eq!(render_editors(2, mode, None), "John Smith and Jane Jones");
+    assert_eq!(
+        render_editors(3, mode, None),
+        "John Smith, Jane Jones, and Anne Brown"
+    );
+}
+
+#[test]
+fn test_delimiter_precedes_last_always_and_never() {
+    assert_eq!(
+        render_editors(2, DelimiterPrecedesLast::Always, None),
+        "John Smith, and Jane Jones"
+    );
+    assert_eq!(
+        render_editors(3, DelimiterPrecedesLast::Always, None),
+        "John Smith, Jane Jones, and Anne Brown"
+    );
+    assert_eq!(
+        render_editors(2, DelimiterPrecedesLast::Never, None),
+        "John Smith and Jane Jones"
+    );
+    assert_eq!(
+        render_editors(3, DelimiterPrecedesLast::Never, None),
+        "John Smith, Jane Jones and Anne Brown"
+    );
+}
+
+#[test]
+fn test_delimiter_precedes_last_after_inverted_name() {
+    let mode = DelimiterPrecedesLast::AfterInvertedName;
+    assert_eq!(
+        render_editors(2, mode, Some(DisplayAsSort::First)),
+        "Smith, John, and Jane Jones"
+    );
+    assert_eq!(
+        render_editors(3, mode, Some(DisplayAsSort::First)),
+        "Smith, John, Jane Jones and Anne Brown"
+    );
+    assert_eq!(
+        render_editors(3, mode, Some(DisplayAsSort::All)),
+        "Smith, John, Jones, Jane, and Brown, Anne"
+    );
+    assert_eq!(render_editors(2, mode, None), "John Smith and Jane Jones");
+}
+
+#[test]
+fn test_after_inverted_name_with_no_names_shown() {
+    let config = Config {
+        contributors: Some(ContributorConfig {
+            shorten: Some(ShortenListOptions {
+                min: 2,
+                use_first: 0,
+                ..Default::default()
+            }),
+            delimiter_precedes_et_al: Some(DelimiterPrecedesLast::AfterInvertedName),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let locale = make_locale();
+    let options = RenderOptions {
+        config: &config,
+        locale: &locale,
+        context: RenderContext::Citation,
+        mode: csln_core::citation::CitationMode::NonIntegral,
+        suppress_author: false,
+        locator: None,
+        locator_label: None,
+        locators: None,
+        date_locale: None,
+    };
+    let names: Vec<FlatName> = ["Smith", "Jones"]
+        .into_iter()
+        .map(|family| FlatName {
+            family: Some(family.to_string()),
+            ..Default::default()
+        })
+        .collect();
+
+    // Must not panic when shortening leaves no names before "et al."
+    contributor::format_names(
+        &names,
+        &ContributorForm::Long,
+        &options,
+        None,
+        None,
+        None,
+        None,
+        None,
+        &ProcHints::default(),
+    );
+}
+
+#[test]
+fn test_isbn_hyphenation() {
+    assert_eq!(
+        identifier::format_isbn("9780306406157", &IsbnForm::Hyphenated),
+        "978-0-306-40615-7"
+    );
+    assert_eq!(
+        identifier::format_isbn("978-0-306-40615-7", &IsbnForm::Compact),
+        "9780306406157"
+    );
+    assert_eq!(
+        identifier::format_issn("03785955", &IsbnForm::Hyphenated),
+        "0378-5955"
+    );
+}
+
+#[test]
+fn test_invalid_isbn_passes_through() {
+    assert_eq!(
+        identifier::format_isbn("9780306406158", &IsbnForm::Hyphenated),
+        "9780306406158"
+    );
+    assert_eq!(
+        identifier::format_issn("0378-5956", &IsbnForm::Hyphenated),
+        "0378-5956"
+    );
+}
+
+#[test]
+fn test_isbn_form_on_variable() {
+    let config = make_config();
+    let locale = make_locale();
+    let options = RenderOptions {
+        config: &config,
+        locale: &locale,
+        context: RenderContext::Bibliography,
+        mode: csln_core::citation::CitationMode::NonIntegral,
+        suppress_author: false,
+        locator: None,
+        locator_label: None,
+        locators: None,
+        date_locale: None,
+    };
+    let reference = Reference::from(LegacyReference {
+        id: "isbn".to_string(),
+        ref_type: "book".to_string(),
+        isbn: Some("9780306406157".to_string()),
+        ..Default::default()
+    });
+    let component = TemplateVariable {
+        variable: SimpleVariable::Isbn,
+        isbn_form: Some(IsbnForm::Hyphenated),
+        ..Default::default()
+    };
+    let values = component
+        .values::<PlainText>(&reference, &ProcHints::default(), &options)
+        .unwrap();
+    assert_eq!(values.value, "978-0-306-40615-7");
+}
+
+#[test]
+fn test_sentence_case_capitalizes_subtitle() {
+    assert_eq!(
+        sentence_case("Main Title: A Subtitle", true),
+        "Main title: A subtitle"
+    );
+    assert_eq!(
+        sentence_case("Main Title: A Subtitle", false),
+        "Main title: a subtitle"
+    );
+    assert_eq!(
+        sentence_case(
+            "Sequencing DNA With The iPhone: Self-Reported Results",
+            true
+        ),
+        "Sequencing DNA with the iPhone: Self-reported results"
+    );
+}
+
+#[test]
+fn test_title_sentence_case() {
+    let config = make_config();
+    let locale = make_locale();
+    let options = RenderOptions {
+        config: &config,
+        locale: &locale,
+        context: RenderContext::Bibliography,
+        mode: csln_core::citation::CitationMode::NonIntegral,
+        suppress_author: false,
+        locator: None,
+        locator_label: None,
+        locators: None,
+        date_locale: None,
+    };
+    let reference = Reference::from(LegacyReference {
+        id: "title".to_string(),
+        ref_type: "article-journal".to_string(),
+        title: Some("Main Title: A Subtitle".to_string()),
+        ..Default::default()
+    });
+    let component = TemplateTitle {
+        title: TitleType::Primary,
+        text_case: Some(TextCase::Sentence),
+        ..Default::default()
+    };
+    let values = component
+        .values::<PlainText>(&reference, &ProcHints::default(), &options)
+        .unwrap();
+    assert_eq!(values.value, "Main title: A subtitle");
+}
@@ -0,0 +1,23 @@
+/*
+SPDX-License-Identifier: MPL-2.0
+SPDX-FileCopyrightText: © 2023-2026 Bruce D'Arcus
+*/
+
+use crate::reference::Reference;
+use crate::values::{ComponentValues, ProcHints, ProcValues, RenderOptions};
+use csln_core::template::TemplateText;
+
+impl ComponentValues for TemplateText {
+    fn values<F: crate::render::format::OutputFormat<Output = String>>(
+        &self,
+        _reference: &Reference,
+        _hints: &ProcHints,
+        _options: &RenderOptions<'_>,
+    ) -> Option<ProcValues<F::Output>> {
+        (!self.text.is_empty()).then(|| ProcValues {
+            value: self.text.clone(),
+            pre_formatted: false,
+            ..Default::default()
+        })
+    }
+}
@@ -6,7 +6,7 @@ SPDX-FileCopyrightText: © 2023-2026 Bruce D'Arcus
 use crate::reference::Reference;
 use crate::values::{ComponentValues, ProcHints, ProcValues, RenderOptions};
 use csln_core::reference::Parent;
-use csln_core::template::{TemplateTitle, TitleType};
+use csln_core::template::{TemplateTitle, TitleForm, TitleType};
 
 fn smarten_apostrophes(input: &str) -> String {
     let mut out = String::with_capacity(input.len());
@@ -28,6 +28,45 @@ fn smarten_apostrophes(input: &str) -> String {
     out
 }
 
+/// The short form of a title: an explicit shorthand, the main title of a
+/// structured title, or, when `split_subtitle` is set, the text before a
+/// subtitle colon.
+fn short_title(title: &csln_core::reference::types::Title, split_subtitle: bool) -> Option<String> {
+    use csln_core::reference::types::Title;
+
+    match title {
+        Title::Shorthand(short, _) => Some(short.clone()),
+        Title::Structured(s) => Some(s.main.clone()),
+        Title::Single(s) if split_subtitle => {
+            s.split_once(':').map(|(main, _)| main.trim().to_string())
+        }
+        _ => None,
+    }
+}
+
+/// The localized "review of" phrase that introduces a reviewed title.
+fn review_phrase(
+    component: &TemplateTitle,
+    reference: &Reference,
+    options: &RenderOptions<'_>,
+) -> Option<String> {
+    if component.title != TitleType::Reviewed
+        || !matches!(reference.ref_type().as_str(), "review" | "review-book")
+    {
+        return None;
+    }
+    let term = options.locale.general_term(
+        &csln_core::locale::GeneralTerm::ReviewOf,
+        csln_core::locale::TermForm::Long,
+    )?;
+    (!term.is_empty()).then(|| {
+        format!(
+            "{} ",
+            crate::values::apply_text_case(term, component.label_text_case)
+        )
+    })
+}
+
 impl ComponentValues for TemplateTitle {
     fn values<F: crate::render::format::OutputFormat<Output = String>>(
         &self,
@@ -62,6 +101,7 @@ impl ComponentValues for TemplateTitle {
                 _ => None,
             }
             .cloned(),
+            TitleType::Reviewed => reference.reviewed_title(),
             _ => None,
         };
 
@@ -69,6 +109,11 @@ impl ComponentValues for TemplateTitle {
         let value = raw_title.map(|title| {
             use csln_core::reference::types::Title;
 
+            if self.form == Some(TitleForm::Short)
+                && let Some(short) = short_title(&title, self.split_subtitle == Some(true))
+            {
+                return short;
+            }
             match title {
                 Title::Single(s) => s.clone(),
                 Title::Multilingual(m) => {
@@ -97,6 +142,8 @@ impl ComponentValues for TemplateTitle {
             }
         });
 
+        let value = value.map(|value| crate::values::apply_text_case(&value, self.text_case));
+
         value.filter(|s: &String| !s.is_empty()).map(|value| {
             use csln_core::options::LinkAnchor;
             let url = crate::values::resolve_effective_url(
@@ -107,7 +154,8 @@ impl ComponentValues for TemplateTitle {
             );
             ProcValues {
                 value: smarten_apostrophes(&value),
-                prefix: None,
+                prefix: review_phrase(self, reference, options)
+                    .map(|phrase| F::default().text(&phrase)),
                 suffix: None,
                 url,
                 substituted_key: None,
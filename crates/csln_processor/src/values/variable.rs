@@ -1,6 +1,111 @@
 use crate::reference::Reference;
+use crate::render::format::OutputFormat;
 use crate::values::{ComponentValues, ProcHints, ProcValues, RenderOptions};
-use csln_core::template::{SimpleVariable, TemplateVariable};
+use csln_core::citation::LocatorType;
+use csln_core::locale::{GeneralTerm, TermForm};
+use csln_core::options::LinkPrecedence;
+use csln_core::template::{LabelForm, SimpleVariable, SourcePhrase, TemplateVariable, UrlWrap};
+
+/// Resolve the localized item-type name used when a reference has no genre.
+fn fallback_genre(reference: &Reference, options: &RenderOptions<'_>) -> Option<String> {
+    options
+        .locale
+        .item_type_term(&reference.ref_type(), TermForm::Long)
+        .filter(|term| !term.is_empty())
+        .map(str::to_string)
+}
+
+/// Shorten a URL to its domain and an ellipsis (e.g., "example.com…").
+fn shorten_url(url: &str) -> String {
+    let rest = url.split_once("://").map_or(url, |(_, rest)| rest);
+    match rest.split_once(['/', '?', '#']) {
+        Some((domain, path)) if !path.is_empty() => format!("{}…", domain),
+        Some((domain, _)) => domain.to_string(),
+        None => rest.to_string(),
+    }
+}
+
+/// Prefix a value with its localized label when `show-label` is set.
+///
+/// The result is already in the output format, since the space after the
+/// label may be format markup (e.g., `&nbsp;`).
+fn with_label<F: OutputFormat<Output = String>>(
+    variable: &TemplateVariable,
+    value: String,
+    term: &GeneralTerm,
+    form: TermForm,
+    options: &RenderOptions<'_>,
+) -> String {
+    let fmt = F::default();
+    match (variable.show_label == Some(true))
+        .then(|| options.locale.general_term(term, form))
+        .flatten()
+    {
+        Some(label) => {
+            fmt.text(label) + &fmt.label_space(options.locale.label_space) + &fmt.text(&value)
+        }
+        None => fmt.text(&value),
+    }
+}
+
+/// Render a locator value with its localized label (e.g., "p. 23").
+///
+/// Page labels are omitted in note styles and when `show-label` is false.
+/// Like [`with_label`], the result is already in the output format.
+fn format_locator<F: OutputFormat<Output = String>>(
+    variable: &TemplateVariable,
+    loc: &str,
+    label: Option<&LocatorType>,
+    options: &RenderOptions<'_>,
+) -> String {
+    let fmt = F::default();
+    let Some(label_type) = label else {
+        return fmt.text(loc);
+    };
+    if variable.show_label == Some(false) && matches!(label_type, LocatorType::Page) {
+        return fmt.text(loc);
+    }
+
+    // Chicago-style notes typically render page locators bare ("23"),
+    // while most non-note styles expect labels ("p. 23").
+    if matches!(label_type, LocatorType::Page)
+        && matches!(
+            options.config.processing,
+            Some(csln_core::options::Processing::Note)
+        )
+    {
+        return fmt.text(loc);
+    }
+
+    // Check if value is plural (contains hyphen, comma, or space)
+    let is_plural = loc.contains('-') || loc.contains(',') || loc.contains(' ');
+
+    // Look up term from locale
+    match options
+        .locale
+        .locator_term(label_type, is_plural, TermForm::Short)
+    {
+        Some(term) if variable.strip_label_periods == Some(true) => fmt.text(&format!(
+            "{}{}",
+            crate::values::strip_trailing_periods(term),
+            loc
+        )),
+        Some(term) => {
+            fmt.text(term) + &fmt.label_space(options.locale.label_space) + &fmt.text(loc)
+        }
+        None => fmt.text(loc),
+    }
+}
+
+/// Prefix an identifier with its fixed label (e.g., "PMID: 123") when
+/// `show-label` is set.
+fn with_identifier_label(variable: &TemplateVariable, value: String, label: &str) -> String {
+    if variable.show_label == Some(true) {
+        format!("{}: {}", label, value)
+    } else {
+        value
+    }
+}
 
 impl ComponentValues for TemplateVariable {
     fn values<F: crate::render::format::OutputFormat<Output = String>>(
@@ -10,13 +115,39 @@ impl ComponentValues for TemplateVariable {
         options: &RenderOptions<'_>,
     ) -> Option<ProcValues<F::Output>> {
         let value = match self.variable {
-            SimpleVariable::Doi => reference.doi(),
-            SimpleVariable::Url => reference.url().map(|u| u.to_string()),
-            SimpleVariable::Isbn => reference.isbn(),
-            SimpleVariable::Issn => reference.issn(),
+            SimpleVariable::Doi => reference.doi().filter(|_| {
+                options.config.link_precedence != Some(LinkPrecedence::UrlThenDoi)
+                    || reference.url().is_none()
+                    || crate::values::access_suppressed(reference, options)
+            }),
+            SimpleVariable::Url => reference.url().map(|u| u.to_string()).filter(|_| {
+                (options.config.link_precedence != Some(LinkPrecedence::DoiThenUrl)
+                    || reference.doi().is_none())
+                    && !crate::values::access_suppressed(reference, options)
+            }),
+            SimpleVariable::Isbn => reference.isbn().map(|isbn| match &self.isbn_form {
+                Some(form) => crate::values::identifier::format_isbn(&isbn, form),
+                None => isbn,
+            }),
+            SimpleVariable::Issn => reference.issn().map(|issn| match &self.isbn_form {
+                Some(form) => crate::values::identifier::format_issn(&issn, form),
+                None => issn,
+            }),
+            SimpleVariable::Pmid => reference
+                .pmid()
+                .map(|pmid| with_identifier_label(self, pmid, "PMID")),
+            SimpleVariable::Pmcid => reference
+                .pmcid()
+                .map(|pmcid| with_identifier_label(self, pmcid, "PMCID")),
             SimpleVariable::Publisher => reference.publisher_str(),
             SimpleVariable::PublisherPlace => reference.publisher_place(),
-            SimpleVariable::Genre => reference.genre(),
+            SimpleVariable::OriginalPublisher => reference.original_publisher(),
+            SimpleVariable::OriginalPublisherPlace => reference.original_publisher_place(),
+            SimpleVariable::Genre => reference.genre().filter(|g| !g.is_empty()).or_else(|| {
+                (self.type_fallback == Some(true))
+                    .then(|| fallback_genre(reference, options))
+                    .flatten()
+            }),
             SimpleVariable::Medium => reference.medium(),
             SimpleVariable::Abstract => reference.abstract_text(),
             SimpleVariable::Note => reference.note(),
@@ -30,9 +161,16 @@ impl ComponentValues for TemplateVariable {
                 _ => None,
             },
             SimpleVariable::PatentNumber => match reference {
-                Reference::Patent(r) => Some(r.patent_number.clone()),
+                Reference::Patent(r) => Some(with_label::<F>(
+                    self,
+                    r.patent_number.clone(),
+                    &GeneralTerm::Patent,
+                    TermForm::Long,
+                    options,
+                )),
                 _ => None,
             },
+            SimpleVariable::Status => reference.status(),
             SimpleVariable::StandardNumber => match reference {
                 Reference::Standard(r) => Some(r.standard_number.clone()),
                 _ => None,
@@ -41,55 +179,77 @@ impl ComponentValues for TemplateVariable {
                 Reference::Monograph(r) => r.report_number.clone(),
                 _ => None,
             },
-            SimpleVariable::Version => reference.version(),
-            SimpleVariable::Locator => {
-                // If we have a locator value in options, use it
-                options.locator.map(|loc| {
-                    if let Some(label_type) = &options.locator_label {
-                        if self.show_label == Some(false)
-                            && matches!(label_type, csln_core::citation::LocatorType::Page)
-                        {
-                            return loc.to_string();
-                        }
-
-                        // Chicago-style notes typically render page locators bare ("23"),
-                        // while most non-note styles expect labels ("p. 23").
-                        if matches!(label_type, csln_core::citation::LocatorType::Page)
-                            && matches!(
-                                options.config.processing,
-                                Some(csln_core::options::Processing::Note)
-                            )
-                        {
-                            return loc.to_string();
-                        }
-
-                        // Check if value is plural (contains hyphen, comma, or space)
-                        let is_plural = loc.contains('-') || loc.contains(',') || loc.contains(' ');
-
-                        // Look up term from locale
-                        if let Some(term) = options.locale.locator_term(
-                            label_type,
-                            is_plural,
-                            csln_core::locale::TermForm::Short,
-                        ) {
-                            if self.strip_label_periods == Some(true) {
-                                let locator_term = crate::values::strip_trailing_periods(term);
-                                format!("{}{}", locator_term, loc)
-                            } else {
-                                format!("{} {}", term, loc)
-                            }
-                        } else {
-                            loc.to_string()
-                        }
-                    } else {
-                        loc.to_string()
-                    }
-                })
-            }
+            SimpleVariable::Version => reference.version().map(|version| {
+                let form = match self.label_form {
+                    Some(LabelForm::Long) => TermForm::Long,
+                    _ => TermForm::Short,
+                };
+                with_label::<F>(self, version, &GeneralTerm::Version, form, options)
+            }),
+            SimpleVariable::Archive => reference.archive(),
+            SimpleVariable::ArchiveLocation => reference.archive_location(),
+            SimpleVariable::ArchivePlace => reference.archive_place(),
+            SimpleVariable::CallNumber => reference.call_number().map(|number| {
+                with_label::<F>(
+                    self,
+                    number,
+                    &GeneralTerm::CallNumber,
+                    TermForm::Short,
+                    options,
+                )
+            }),
+            SimpleVariable::Scale => reference.scale().map(|scale| {
+                with_label::<F>(self, scale, &GeneralTerm::Scale, TermForm::Long, options)
+            }),
+            SimpleVariable::Dimensions => reference.dimensions().map(|dimensions| match reference
+                .ref_type()
+                .as_str()
+            {
+                "broadcast" | "motion-picture" | "song" => {
+                    crate::values::duration::format_duration(&dimensions, options.locale)
+                        .unwrap_or(dimensions)
+                }
+                _ => dimensions,
+            }),
+            SimpleVariable::Source => reference.source().map(|source| {
+                let locale = options.locale;
+                let phrase = match self.source_phrase {
+                    Some(SourcePhrase::RetrievedFrom) => locale
+                        .general_term(&GeneralTerm::Retrieved, TermForm::Long)
+                        .zip(locale.general_term(&GeneralTerm::From, TermForm::Long))
+                        .map(|(retrieved, from)| format!("{} {}", retrieved, from)),
+                    Some(SourcePhrase::AvailableAt) => locale
+                        .general_term(&GeneralTerm::AvailableAt, TermForm::Long)
+                        .map(str::to_string),
+                    None => None,
+                };
+                match phrase {
+                    Some(phrase) => format!("{} {}", phrase, source),
+                    None => source,
+                }
+            }),
+            SimpleVariable::Locator => match options.locators.filter(|parts| !parts.is_empty()) {
+                Some(parts) => Some(
+                    parts
+                        .iter()
+                        .map(|part| {
+                            format_locator::<F>(self, &part.locator, Some(&part.label), options)
+                        })
+                        .collect::<Vec<_>>()
+                        .join(
+                            &F::default().text(self.locator_delimiter.as_deref().unwrap_or(", ")),
+                        ),
+                ),
+                None => options.locator.map(|loc| {
+                    format_locator::<F>(self, loc, options.locator_label.as_ref(), options)
+                }),
+            },
             _ => None,
         };
 
         value.filter(|s: &String| !s.is_empty()).map(|value| {
+            let value = crate::values::apply_text_case(&value, self.text_case);
+
             // Resolve effective rendering options
             let mut effective_rendering = self.rendering.clone();
             if let Some(overrides) = &self.overrides {
@@ -146,13 +306,41 @@ impl ComponentValues for TemplateVariable {
                 }
             }
 
+            // A shortened URL keeps the full address as its link target.
+            let value = if self.variable == SimpleVariable::Url && self.url_shorten == Some(true) {
+                url = url.or_else(|| Some(value.clone()));
+                shorten_url(&value)
+            } else {
+                value
+            };
+
+            // Wrapped URLs are formatted here so each backend can escape
+            // the delimiters in the visible text.
+            let wrap = match self.variable {
+                SimpleVariable::Url => self.url_wrap.filter(|w| *w != UrlWrap::None),
+                _ => None,
+            };
+            // Labelled values are formatted along with their label.
+            let labelled = matches!(
+                self.variable,
+                SimpleVariable::PatentNumber
+                    | SimpleVariable::Version
+                    | SimpleVariable::CallNumber
+                    | SimpleVariable::Scale
+                    | SimpleVariable::Locator
+            );
+            let (value, pre_formatted) = match wrap {
+                Some(wrap) => (F::default().url_wrap(wrap, &value), true),
+                None => (value, labelled),
+            };
+
             ProcValues {
                 value,
                 prefix: None,
                 suffix: None,
                 url,
                 substituted_key: None,
-                pre_formatted: false,
+                pre_formatted,
             }
         })
     }
@@ -248,3 +248,1641 @@ fn test_numeric_bibliography() {
     let result = processor.render_bibliography();
     assert_eq!(result, "1. John Smith (2020)");
 }
+
+// --- Medium Tests ---
+
+fn build_medium_style() -> Style {
+    Style {
+        options: Some(Config {
+            bibliography: Some(BibliographyConfig {
+                entry_suffix: Some(".".to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }),
+        bibliography: Some(BibliographySpec {
+            template: Some(vec![
+                csln_core::tc_title!(Primary),
+                csln_core::tc_variable!(
+                    Medium,
+                    prefix = " ",
+                    wrap = csln_core::template::WrapPunctuation::Brackets
+                ),
+                csln_core::tc_date!(Issued, Year, prefix = ", "),
+            ]),
+            ..Default::default()
+        }),
+        ..Default::default()
+    }
+}
+
+fn make_legacy(id: &str, ref_type: &str, title: &str, medium: &str) -> csln_processor::Reference {
+    csln_processor::Reference::from(csl_legacy::csl_json::Reference {
+        id: id.to_string(),
+        ref_type: ref_type.to_string(),
+        title: Some(title.to_string()),
+        medium: Some(medium.to_string()),
+        issued: Some(csl_legacy::csl_json::DateVariable::year(2001)),
+        ..Default::default()
+    })
+}
+
+#[test]
+fn test_medium_for_film() {
+    let bib = csln_core::bib_map![
+        "film" => make_legacy("film", "motion-picture", "Spirited Away", "DVD"),
+    ];
+    let processor = Processor::new(build_medium_style(), bib);
+    assert_eq!(
+        processor.render_bibliography(),
+        "Spirited Away [DVD], 2001."
+    );
+}
+
+#[test]
+fn test_medium_for_ebook() {
+    let bib = csln_core::bib_map![
+        "ebook" => make_legacy("ebook", "book", "Middlemarch", "Kindle edition"),
+    ];
+    let processor = Processor::new(build_medium_style(), bib);
+    assert_eq!(
+        processor.render_bibliography(),
+        "Middlemarch [Kindle edition], 2001."
+    );
+}
+
+#[test]
+fn test_medium_for_song() {
+    let reference = make_legacy("song", "song", "Blue in Green", "Vinyl");
+    assert_eq!(reference.ref_type(), "song");
+
+    let bib = csln_core::bib_map!["song" => reference];
+    let processor = Processor::new(build_medium_style(), bib);
+    assert_eq!(
+        processor.render_bibliography(),
+        "Blue in Green [Vinyl], 2001."
+    );
+}
+
+// --- Audiovisual Tests ---
+
+/// Director with role label, then title, then a "[Medium]. Studio" group
+/// that drops out entirely when both parts are missing.
+const AV_STYLE: &str = r#"
+info:
+  title: AV Test
+options:
+  contributors:
+    initialize-with: ". "
+    display-as-sort: all
+bibliography:
+  template:
+    - contributor: director
+      form: long
+      label:
+        term: director
+        form: long
+        placement: suffix
+      suffix: ". "
+    - title: primary
+    - items:
+        - variable: medium
+          wrap: brackets
+        - variable: publisher
+      delimiter: ". "
+      prefix: ". "
+"#;
+
+fn render_film(medium: Option<&str>, publisher: Option<&str>) -> String {
+    let style: Style = serde_yaml::from_str(AV_STYLE).unwrap();
+    let film = csln_processor::Reference::from(csl_legacy::csl_json::Reference {
+        id: "inception".to_string(),
+        ref_type: "motion_picture".to_string(),
+        title: Some("Inception".to_string()),
+        director: Some(vec![csl_legacy::csl_json::Name::new(
+            "Nolan",
+            "Christopher",
+        )]),
+        medium: medium.map(str::to_string),
+        publisher: publisher.map(str::to_string),
+        issued: Some(csl_legacy::csl_json::DateVariable::year(2010)),
+        ..Default::default()
+    });
+    Processor::new(style, csln_core::bib_map!["inception" => film]).render_bibliography()
+}
+
+#[test]
+fn test_motion_picture_director_medium_studio() {
+    assert_eq!(
+        render_film(Some("Film"), Some("Warner Bros.")),
+        "Nolan, C., director. Inception. [Film]. Warner Bros."
+    );
+}
+
+#[test]
+fn test_motion_picture_group_suppresses_missing_parts() {
+    assert_eq!(
+        render_film(None, Some("Warner Bros.")),
+        "Nolan, C., director. Inception. Warner Bros."
+    );
+    assert_eq!(render_film(None, None), "Nolan, C., director. Inception");
+}
+
+#[test]
+fn test_role_labels_for_composer_and_director() {
+    let style: Style = serde_yaml::from_str(
+        r#"
+info:
+  title: Role Label Test
+options:
+  contributors:
+    initialize-with: ". "
+    display-as-sort: all
+bibliography:
+  template:
+    - contributor: composer
+      form: long
+      label:
+        term: composer
+        form: long
+        placement: suffix
+      suffix: ". "
+    - contributor: director
+      form: long
+      suffix: ". "
+    - title: primary
+"#,
+    )
+    .unwrap();
+    let song = csln_processor::Reference::from(csl_legacy::csl_json::Reference {
+        id: "song".to_string(),
+        ref_type: "song".to_string(),
+        title: Some("Symphony No. 5".to_string()),
+        composer: Some(vec![csl_legacy::csl_json::Name::new(
+            "Beethoven",
+            "Ludwig van",
+        )]),
+        ..Default::default()
+    });
+    let film = csln_processor::Reference::from(csl_legacy::csl_json::Reference {
+        id: "film".to_string(),
+        ref_type: "motion_picture".to_string(),
+        title: Some("Inception".to_string()),
+        director: Some(vec![csl_legacy::csl_json::Name::new(
+            "Nolan",
+            "Christopher",
+        )]),
+        ..Default::default()
+    });
+    let processor = Processor::new(style, csln_core::bib_map!["song" => song, "film" => film]);
+    assert_eq!(
+        processor.render_bibliography(),
+        "Nolan, C. (Dir.). Inception\n\nBeethoven, L. v., composer. Symphony No. 5"
+    );
+}
+
+#[test]
+fn test_role_label_text_case() {
+    let style: Style = serde_yaml::from_str(
+        r#"
+info:
+  title: Label Case Test
+options:
+  contributors:
+    initialize-with: ". "
+bibliography:
+  template:
+    - contributor: editor
+      form: long
+      label:
+        term: editor
+        form: verb
+        placement: prefix
+        text-case: capitalize-first
+      suffix: ". "
+    - title: primary
+    - contributor: translator
+      form: long
+      label:
+        term: translator
+        form: verb
+        placement: prefix
+        text-case: lowercase
+      prefix: ", "
+"#,
+    )
+    .unwrap();
+    let book = csln_processor::Reference::from(csl_legacy::csl_json::Reference {
+        id: "book".to_string(),
+        ref_type: "book".to_string(),
+        title: Some("Collected Essays".to_string()),
+        editor: Some(vec![csl_legacy::csl_json::Name::new("Doe", "Jane")]),
+        translator: Some(vec![csl_legacy::csl_json::Name::new("Roe", "Rick")]),
+        ..Default::default()
+    });
+    let processor = Processor::new(style, csln_core::bib_map!["book" => book]);
+    assert_eq!(
+        processor.render_bibliography(),
+        "Edited by J. Doe. Collected Essays, translated by R. Roe"
+    );
+}
+
+#[test]
+fn test_pubmed_identifiers() {
+    let style: Style = serde_yaml::from_str(
+        r#"
+info:
+  title: PubMed Test
+bibliography:
+  template:
+    - title: primary
+    - title: parent-serial
+      prefix: ". "
+    - variable: pmid
+      show-label: true
+      prefix: ". "
+    - variable: pmcid
+      show-label: true
+      prefix: ". "
+"#,
+    )
+    .unwrap();
+    let legacy: csl_legacy::csl_json::Reference = serde_json::from_value(serde_json::json!({
+        "id": "article",
+        "type": "article-journal",
+        "title": "Aspirin and colorectal cancer",
+        "container-title": "Lancet",
+        "issued": {"date-parts": [[2010]]},
+        "PMID": "20888456",
+        "PMCID": "PMC2974213"
+    }))
+    .unwrap();
+    let reference = csln_processor::Reference::from(legacy);
+    assert_eq!(reference.pmid().as_deref(), Some("20888456"));
+    let processor = Processor::new(style, csln_core::bib_map!["article" => reference]);
+    assert_eq!(
+        processor.render_bibliography(),
+        "Aspirin and colorectal cancer. Lancet. PMID: 20888456. PMCID: PMC2974213"
+    );
+}
+
+fn build_totals_style() -> Style {
+    let total = |number| {
+        csln_core::template::TemplateComponent::Number(csln_core::template::TemplateNumber {
+            number,
+            label_form: Some(csln_core::template::LabelForm::Short),
+            rendering: csln_core::template::Rendering {
+                prefix: Some(", ".to_string()),
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+    };
+    Style {
+        bibliography: Some(BibliographySpec {
+            template: Some(vec![
+                csln_core::tc_title!(Primary),
+                total(csln_core::template::NumberVariable::NumberOfVolumes),
+                total(csln_core::template::NumberVariable::NumberOfPages),
+            ]),
+            ..Default::default()
+        }),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_number_of_volumes_label() {
+    let reference = csln_processor::Reference::from(csl_legacy::csl_json::Reference {
+        id: "set".to_string(),
+        ref_type: "book".to_string(),
+        title: Some("The Lord of the Rings".to_string()),
+        number_of_volumes: Some(csl_legacy::csl_json::StringOrNumber::Number(3)),
+        ..Default::default()
+    });
+    let processor = Processor::new(
+        build_totals_style(),
+        csln_core::bib_map!["set" => reference],
+    );
+    assert_eq!(
+        processor.render_bibliography(),
+        "The Lord of the Rings, 3 vols."
+    );
+}
+
+#[test]
+fn test_number_of_pages_label() {
+    let reference = csln_processor::Reference::from(csl_legacy::csl_json::Reference {
+        id: "book".to_string(),
+        ref_type: "book".to_string(),
+        title: Some("Walden".to_string()),
+        number_of_pages: Some(csl_legacy::csl_json::StringOrNumber::String(
+            "250".to_string(),
+        )),
+        ..Default::default()
+    });
+    let processor = Processor::new(
+        build_totals_style(),
+        csln_core::bib_map!["book" => reference],
+    );
+    assert_eq!(processor.render_bibliography(), "Walden, 250 pp.");
+}
+
+#[test]
+fn test_manuscript_archive_and_call_number() {
+    let legacy: csl_legacy::csl_json::Reference = serde_json::from_value(serde_json::json!({
+        "id": "ms",
+        "type": "manuscript",
+        "title": "Letter to Lord Byron",
+        "archive": "British Library",
+        "archive-place": "London",
+        "archive_location": "Box 3, Folder 12",
+        "call-number": "Add MS 42"
+    }))
+    .unwrap();
+    let reference = csln_processor::Reference::from(legacy);
+    assert_eq!(reference.ref_type(), "manuscript");
+
+    let call_number =
+        csln_core::template::TemplateComponent::Variable(csln_core::template::TemplateVariable {
+            variable: csln_core::template::SimpleVariable::CallNumber,
+            show_label: Some(true),
+            ..Default::default()
+        });
+    let style = Style {
+        bibliography: Some(BibliographySpec {
+            template: Some(vec![
+                csln_core::tc_title!(Primary, suffix = ". "),
+                csln_core::template::TemplateComponent::List(csln_core::template::TemplateList {
+                    items: vec![
+                        csln_core::tc_variable!(ArchiveLocation),
+                        call_number,
+                        csln_core::tc_variable!(Archive),
+                        csln_core::tc_variable!(ArchivePlace),
+                    ],
+                    delimiter: Some(csln_core::template::DelimiterPunctuation::Comma),
+                    ..Default::default()
+                }),
+            ]),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let processor = Processor::new(style, csln_core::bib_map!["ms" => reference]);
+    assert_eq!(
+        processor.render_bibliography(),
+        "Letter to Lord Byron. Box 3, Folder 12, call no. Add MS 42, British Library, London"
+    );
+}
+
+fn render_title_and_variable(
+    legacy: serde_json::Value,
+    variable: csln_core::template::TemplateComponent,
+) -> String {
+    let legacy: csl_legacy::csl_json::Reference = serde_json::from_value(legacy).unwrap();
+    let reference = csln_processor::Reference::from(legacy);
+    let style = Style {
+        bibliography: Some(BibliographySpec {
+            template: Some(vec![csln_core::tc_title!(Primary, suffix = ". "), variable]),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let processor = Processor::new(style, csln_core::bib_map!["item" => reference]);
+    processor.render_bibliography()
+}
+
+#[test]
+fn test_map_scale() {
+    let scale =
+        csln_core::template::TemplateComponent::Variable(csln_core::template::TemplateVariable {
+            variable: csln_core::template::SimpleVariable::Scale,
+            show_label: Some(true),
+            ..Default::default()
+        });
+    let output = render_title_and_variable(
+        serde_json::json!({
+            "id": "item",
+            "type": "map",
+            "title": "Lake District",
+            "scale": "1:50,000"
+        }),
+        scale,
+    );
+    assert_eq!(output, "Lake District. scale 1:50,000");
+}
+
+#[test]
+fn test_figure_dimensions() {
+    let output = render_title_and_variable(
+        serde_json::json!({
+            "id": "item",
+            "type": "figure",
+            "title": "Water Lilies",
+            "dimensions": "200 x 180 cm"
+        }),
+        csln_core::tc_variable!(Dimensions),
+    );
+    assert_eq!(output, "Water Lilies. 200 x 180 cm");
+}
+
+fn source_variable(
+    phrase: csln_core::template::SourcePhrase,
+    text_case: Option<csln_core::template::TextCase>,
+) -> csln_core::template::TemplateComponent {
+    csln_core::template::TemplateComponent::Variable(csln_core::template::TemplateVariable {
+        variable: csln_core::template::SimpleVariable::Source,
+        source_phrase: Some(phrase),
+        text_case,
+        ..Default::default()
+    })
+}
+
+#[test]
+fn test_dataset_source_retrieved_from() {
+    let output = render_title_and_variable(
+        serde_json::json!({
+            "id": "item",
+            "type": "dataset",
+            "title": "Global Ocean Temperatures",
+            "archive": "Zenodo"
+        }),
+        source_variable(
+            csln_core::template::SourcePhrase::RetrievedFrom,
+            Some(csln_core::template::TextCase::CapitalizeFirst),
+        ),
+    );
+    assert_eq!(output, "Global Ocean Temperatures. Retrieved from Zenodo");
+}
+
+#[test]
+fn test_dataset_source_available_at() {
+    let output = render_title_and_variable(
+        serde_json::json!({
+            "id": "item",
+            "type": "dataset",
+            "title": "Global Ocean Temperatures",
+            "source": "Dryad"
+        }),
+        source_variable(csln_core::template::SourcePhrase::AvailableAt, None),
+    );
+    assert_eq!(output, "Global Ocean Temperatures. available at Dryad");
+}
+
+#[test]
+fn test_reprint_original_date() {
+    use csln_core::template::{
+        DateForm, DateVariable, Rendering, TemplateComponent, TemplateDate, TextCase,
+        WrapPunctuation,
+    };
+
+    let legacy: csl_legacy::csl_json::Reference = serde_json::from_value(serde_json::json!({
+        "id": "james",
+        "type": "book",
+        "title": "The Principles of Psychology",
+        "issued": {"date-parts": [[1950]]},
+        "original-date": {"date-parts": [[1890]]}
+    }))
+    .unwrap();
+    let reference = csln_processor::Reference::from(legacy);
+    let original = TemplateComponent::Date(TemplateDate {
+        date: DateVariable::OriginalPublished,
+        form: DateForm::Year,
+        show_label: Some(true),
+        label_text_case: Some(TextCase::CapitalizeFirst),
+        rendering: Rendering {
+            prefix: Some(" ".to_string()),
+            wrap: Some(WrapPunctuation::Parentheses),
+            ..Default::default()
+        },
+        ..Default::default()
+    });
+    let style = Style {
+        bibliography: Some(BibliographySpec {
+            template: Some(vec![
+                csln_core::tc_title!(Primary),
+                csln_core::tc_date!(Issued, Year, prefix = " (", suffix = ")"),
+                original,
+            ]),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let processor = Processor::new(style, csln_core::bib_map!["james" => reference]);
+    assert_eq!(
+        processor.render_bibliography(),
+        "The Principles of Psychology (1950) (Original work published 1890)"
+    );
+}
+
+#[test]
+fn test_reprint_original_publisher() {
+    use csln_core::template::{
+        DateForm, DateVariable, DelimiterPunctuation, Rendering, TemplateComponent, TemplateDate,
+        TemplateList, TextCase, WrapPunctuation,
+    };
+
+    let legacy: csl_legacy::csl_json::Reference = serde_json::from_value(serde_json::json!({
+        "id": "james",
+        "type": "book",
+        "title": "The Principles of Psychology",
+        "issued": {"date-parts": [[1950]]},
+        "original-date": {"date-parts": [[1890]]},
+        "original-publisher": "Henry Holt",
+        "original-publisher-place": "New York"
+    }))
+    .unwrap();
+    let reference = csln_processor::Reference::from(legacy);
+    let original_date = TemplateComponent::Date(TemplateDate {
+        date: DateVariable::OriginalPublished,
+        form: DateForm::Year,
+        show_label: Some(true),
+        label_text_case: Some(TextCase::CapitalizeFirst),
+        ..Default::default()
+    });
+    let original_imprint = TemplateComponent::List(TemplateList {
+        items: vec![
+            csln_core::tc_variable!(OriginalPublisherPlace),
+            csln_core::tc_variable!(OriginalPublisher),
+        ],
+        delimiter: Some(DelimiterPunctuation::Colon),
+        ..Default::default()
+    });
+    let original = TemplateComponent::List(TemplateList {
+        items: vec![original_date, original_imprint],
+        delimiter: Some(DelimiterPunctuation::Comma),
+        rendering: Rendering {
+            prefix: Some(" ".to_string()),
+            wrap: Some(WrapPunctuation::Parentheses),
+            ..Default::default()
+        },
+        ..Default::default()
+    });
+    let style = Style {
+        bibliography: Some(BibliographySpec {
+            template: Some(vec![
+                csln_core::tc_title!(Primary),
+                csln_core::tc_date!(Issued, Year, prefix = " (", suffix = ")"),
+                original,
+            ]),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let processor = Processor::new(style, csln_core::bib_map!["james" => reference]);
+    assert_eq!(
+        processor.render_bibliography(),
+        "The Principles of Psychology (1950) (Original work published 1890, New York: Henry Holt)"
+    );
+}
+
+#[test]
+fn test_bill_authority() {
+    let output = render_title_and_variable(
+        serde_json::json!({
+            "id": "item",
+            "type": "bill",
+            "title": "Clean Air Act",
+            "authority": "U.S. Congress"
+        }),
+        csln_core::tc_variable!(Authority),
+    );
+    assert_eq!(output, "Clean Air Act. U.S. Congress");
+}
+
+#[test]
+fn test_patent_number_and_status() {
+    let number =
+        csln_core::template::TemplateComponent::Variable(csln_core::template::TemplateVariable {
+            variable: csln_core::template::SimpleVariable::PatentNumber,
+            show_label: Some(true),
+            ..Default::default()
+        });
+    let output = render_title_and_variable(
+        serde_json::json!({
+            "id": "item",
+            "type": "patent",
+            "title": "Telephone Improvements",
+            "number": "174,465"
+        }),
+        number,
+    );
+    assert_eq!(output, "Telephone Improvements. patent 174,465");
+
+    let output = render_title_and_variable(
+        serde_json::json!({
+            "id": "item",
+            "type": "patent",
+            "title": "Telephone Improvements",
+            "number": "174,465",
+            "status": "pending"
+        }),
+        csln_core::tc_variable!(Status),
+    );
+    assert_eq!(output, "Telephone Improvements. pending");
+}
+
+fn build_annotated_processor(annotate: bool) -> Processor {
+    let style = Style {
+        options: Some(Config {
+            bibliography: Some(BibliographyConfig {
+                annotate,
+                ..Default::default()
+            }),
+            ..Default::default()
+        }),
+        bibliography: Some(BibliographySpec {
+            template: Some(vec![csln_core::tc_title!(Primary)]),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let reference = csln_processor::Reference::from(csl_legacy::csl_json::Reference {
+        id: "walden".to_string(),
+        ref_type: "book".to_string(),
+        title: Some("Walden".to_string()),
+        note: Some("A reflection on simple living.".to_string()),
+        ..Default::default()
+    });
+    Processor::new(style, csln_core::bib_map!["walden" => reference])
+}
+
+#[test]
+fn test_annotation_note_after_entry() {
+    let processor = build_annotated_processor(true);
+    assert_eq!(
+        processor.render_bibliography(),
+        "Walden\nA reflection on simple living."
+    );
+
+    let html = processor.render_bibliography_with_format::<csln_processor::render::html::Html>();
+    assert!(
+        html.contains(r#"<div class="csln-annotation">A reflection on simple living.</div></div>"#)
+    );
+
+    let processor = build_annotated_processor(false);
+    assert_eq!(processor.render_bibliography(), "Walden");
+}
+
+fn build_abstract_processor(abstract_max_length: Option<usize>) -> Processor {
+    let style = Style {
+        options: Some(Config {
+            bibliography: Some(BibliographyConfig {
+                show_abstract: true,
+                abstract_max_length,
+                ..Default::default()
+            }),
+            ..Default::default()
+        }),
+        bibliography: Some(BibliographySpec {
+            template: Some(vec![csln_core::tc_title!(Primary)]),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let reference = csln_processor::Reference::from(csl_legacy::csl_json::Reference {
+        id: "walden".to_string(),
+        ref_type: "book".to_string(),
+        title: Some("Walden".to_string()),
+        abstract_text: Some(
+            "Thoreau reflects on simple living in natural surroundings.".to_string(),
+        ),
+        ..Default::default()
+    });
+    Processor::new(style, csln_core::bib_map!["walden" => reference])
+}
+
+#[test]
+fn test_abstract_block_after_entry() {
+    let processor = build_abstract_processor(None);
+    assert_eq!(
+        processor.render_bibliography(),
+        "Walden\n\nThoreau reflects on simple living in natural surroundings."
+    );
+
+    let html = processor.render_bibliography_with_format::<csln_processor::render::html::Html>();
+    assert!(html.contains(
+        r#"<div class="csln-abstract">Thoreau reflects on simple living in natural surroundings.</div></div>"#
+    ));
+}
+
+#[test]
+fn test_abstract_block_truncated() {
+    let processor = build_abstract_processor(Some(30));
+    assert_eq!(
+        processor.render_bibliography(),
+        "Walden\n\nThoreau reflects on simple…"
+    );
+}
+
+#[test]
+fn test_url_wrap_angle() {
+    let style: Style = serde_yaml::from_str(
+        r#"
+info:
+  title: URL Wrap Test
+bibliography:
+  template:
+    - title: primary
+      suffix: ". "
+    - variable: url
+      url-wrap: angle
+      links:
+        url: true
+"#,
+    )
+    .unwrap();
+    let reference = csln_processor::Reference::from(csl_legacy::csl_json::Reference {
+        id: "page".to_string(),
+        ref_type: "webpage".to_string(),
+        title: Some("Home".to_string()),
+        url: Some("https://example.com/".to_string()),
+        ..Default::default()
+    });
+    let processor = Processor::new(style, csln_core::bib_map!["page" => reference]);
+    assert_eq!(
+        processor.render_bibliography(),
+        "Home. <https://example.com/>"
+    );
+
+    let html = processor.render_bibliography_with_format::<csln_processor::render::html::Html>();
+    assert!(
+        html.contains(r#"<a href="https://example.com/">&lt;https://example.com/&gt;</a>"#),
+        "{html}"
+    );
+}
+
+#[test]
+fn test_url_wrap_angle_djot() {
+    let style: Style = serde_yaml::from_str(
+        r#"
+info:
+  title: URL Wrap Test
+bibliography:
+  template:
+    - variable: url
+      url-wrap: angle
+"#,
+    )
+    .unwrap();
+    let reference = csln_processor::Reference::from(csl_legacy::csl_json::Reference {
+        id: "page".to_string(),
+        ref_type: "webpage".to_string(),
+        url: Some("https://example.com/a_b_c".to_string()),
+        ..Default::default()
+    });
+    let processor = Processor::new(style, csln_core::bib_map!["page" => reference]);
+    let djot = processor.render_bibliography_with_format::<csln_processor::render::djot::Djot>();
+    assert_eq!(djot, r"[\<https://example.com/a\_b\_c\>]{.csln-url}");
+
+    // The brackets and underscores survive conversion rather than becoming
+    // an autolink and emphasis.
+    let html = csln_processor::processor::document::djot::djot_to_html(&djot);
+    assert!(html.contains("&lt;https://example.com/a_b_c&gt;"), "{html}");
+}
+
+#[test]
+fn test_url_shorten_keeps_full_link() {
+    let style: Style = serde_yaml::from_str(
+        r#"
+info:
+  title: URL Shorten Test
+bibliography:
+  template:
+    - title: primary
+      suffix: ". "
+    - variable: url
+      url-shorten: true
+"#,
+    )
+    .unwrap();
+    let url = "https://www.example.org/research/posters/2024/long-poster-title.pdf";
+    let reference = csln_processor::Reference::from(csl_legacy::csl_json::Reference {
+        id: "poster".to_string(),
+        ref_type: "webpage".to_string(),
+        title: Some("Poster".to_string()),
+        url: Some(url.to_string()),
+        ..Default::default()
+    });
+    let processor = Processor::new(style, csln_core::bib_map!["poster" => reference]);
+    assert_eq!(processor.render_bibliography(), "Poster. www.example.org…");
+
+    let html = processor.render_bibliography_with_format::<csln_processor::render::html::Html>();
+    assert!(
+        html.contains(&format!(r#"<a href="{url}">www.example.org…</a>"#)),
+        "{html}"
+    );
+}
+
+#[test]
+fn test_software_version_label() {
+    let legacy: csl_legacy::csl_json::Reference = serde_json::from_value(serde_json::json!({
+        "id": "rcore",
+        "type": "software",
+        "title": "R",
+        "author": [{ "literal": "R Core Team" }],
+        "issued": { "date-parts": [[2021]] },
+        "version": "4.1.0"
+    }))
+    .unwrap();
+    let bibliography = csln_core::bib_map!["rcore" => csln_processor::Reference::from(legacy)];
+    let style_with = |label: &str| -> Style {
+        serde_yaml::from_str(&format!(
+            r#"
+info:
+  title: Version Test
+bibliography:
+  template:
+    - title: primary
+    - variable: version
+      show-label: true
+      prefix: ", "{label}
+"#
+        ))
+        .unwrap()
+    };
+
+    let processor = Processor::new(style_with(""), bibliography.clone());
+    assert_eq!(processor.render_bibliography(), "R, v. 4.1.0");
+
+    let processor = Processor::new(style_with("\n      label-form: long"), bibliography);
+    assert_eq!(processor.render_bibliography(), "R, version 4.1.0");
+}
+
+#[test]
+fn test_link_precedence() {
+    let reference = csln_processor::Reference::from(csl_legacy::csl_json::Reference {
+        id: "paper".to_string(),
+        ref_type: "article-journal".to_string(),
+        title: Some("Paper".to_string()),
+        doi: Some("10.1234/abc".to_string()),
+        url: Some("https://example.com/paper".to_string()),
+        ..Default::default()
+    });
+    let bibliography = csln_core::bib_map!["paper" => reference];
+    let render = |precedence: &str| {
+        let style: Style = serde_yaml::from_str(&format!(
+            r#"
+info:
+  title: Link Precedence Test
+options:
+  link-precedence: {precedence}
+bibliography:
+  template:
+    - title: primary
+    - variable: doi
+      prefix: ". DOI: "
+    - variable: url
+      prefix: ". "
+"#
+        ))
+        .unwrap();
+        Processor::new(style, bibliography.clone()).render_bibliography()
+    };
+
+    assert_eq!(render("doi-then-url"), "Paper. DOI: 10.1234/abc");
+    assert_eq!(render("url-then-doi"), "Paper. https://example.com/paper");
+    assert_eq!(
+        render("both"),
+        "Paper. DOI: 10.1234/abc. https://example.com/paper"
+    );
+}
+
+#[test]
+fn test_suppress_access_when_doi() {
+    let style: Style = serde_yaml::from_str(
+        r#"
+info:
+  title: Access Suppression Test
+options:
+  suppress-access-when-doi: true
+bibliography:
+  template:
+    - title: primary
+    - variable: doi
+      prefix: ". "
+    - date: accessed
+      form: year
+      prefix: ". Retrieved "
+    - variable: url
+      prefix: ", from "
+"#,
+    )
+    .unwrap();
+    let paper = |id: &str, doi: Option<&str>| {
+        let legacy: csl_legacy::csl_json::Reference = serde_json::from_value(serde_json::json!({
+            "id": id,
+            "type": "webpage",
+            "title": "Paper",
+            "DOI": doi,
+            "URL": "https://example.com/paper",
+            "accessed": {"date-parts": [[2024, 3, 1]]}
+        }))
+        .unwrap();
+        csln_processor::Reference::from(legacy)
+    };
+    let render = |reference| {
+        Processor::new(style.clone(), csln_core::bib_map!["paper" => reference])
+            .render_bibliography()
+    };
+
+    assert_eq!(
+        render(paper("paper", Some("10.1234/abc"))),
+        "Paper. 10.1234/abc"
+    );
+    assert_eq!(
+        render(paper("paper", None)),
+        "Paper. Retrieved 2024, from https://example.com/paper"
+    );
+}
+
+#[test]
+fn test_broadcast_running_time() {
+    let style: Style = serde_yaml::from_str(
+        r#"
+info:
+  title: Running Time Test
+bibliography:
+  template:
+    - title: primary
+      suffix: ". "
+    - variable: dimensions
+"#,
+    )
+    .unwrap();
+    let broadcast = |id: &str, dimensions: &str| {
+        let legacy: csl_legacy::csl_json::Reference = serde_json::from_value(serde_json::json!({
+            "id": id,
+            "type": "broadcast",
+            "title": "The Sun Rises",
+            "dimensions": dimensions
+        }))
+        .unwrap();
+        csln_processor::Reference::from(legacy)
+    };
+
+    for (dimensions, expected) in [
+        ("PT58M", "The Sun Rises. 58 min."),
+        ("1:30:00", "The Sun Rises. 1 hr. 30 min."),
+        ("58", "The Sun Rises. 58 min."),
+        ("about an hour", "The Sun Rises. about an hour"),
+    ] {
+        let processor = Processor::new(
+            style.clone(),
+            csln_core::bib_map!["sun" => broadcast("sun", dimensions)],
+        );
+        assert_eq!(processor.render_bibliography(), expected);
+    }
+}
+
+fn build_display_as_sort_style(display_as_sort: DisplayAsSort) -> Style {
+    Style {
+        options: Some(Config {
+            contributors: Some(ContributorConfig {
+                display_as_sort: Some(display_as_sort),
+                and: Some(csln_core::options::AndOptions::Text),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }),
+        bibliography: Some(BibliographySpec {
+            template: Some(vec![csln_core::tc_contributor!(Author, Long)]),
+            ..Default::default()
+        }),
+        ..Default::default()
+    }
+}
+
+fn three_author_bib() -> csln_processor::Bibliography {
+    csln_core::bib_map![
+        "kuhn" => make_book_multi_author(
+            "kuhn",
+            vec![("Kuhn", "Thomas"), ("Smith", "John"), ("Doe", "Jane")],
+            1962,
+            "Title",
+        ),
+    ]
+}
+
+#[test]
+fn test_display_as_sort_first_inverts_leading_author() {
+    let processor = Processor::new(
+        build_display_as_sort_style(DisplayAsSort::First),
+        three_author_bib(),
+    );
+    assert_eq!(
+        processor.render_bibliography(),
+        "Kuhn, Thomas, John Smith, and Jane Doe"
+    );
+}
+
+#[test]
+fn test_display_as_sort_all_inverts_every_author() {
+    let processor = Processor::new(
+        build_display_as_sort_style(DisplayAsSort::All),
+        three_author_bib(),
+    );
+    assert_eq!(
+        processor.render_bibliography(),
+        "Kuhn, Thomas, Smith, John, and Doe, Jane"
+    );
+}
+
+#[test]
+fn test_literal_author_is_atomic() {
+    let mut style = build_display_as_sort_style(DisplayAsSort::All);
+    if let Some(contributors) = style.options.as_mut().and_then(|o| o.contributors.as_mut()) {
+        contributors.initialize_with = Some(". ".to_string());
+    }
+    style.options.as_mut().unwrap().processing = Some(Processing::Custom(ProcessingCustom {
+        sort: Some(Sort {
+            template: vec![SortSpec {
+                key: SortKey::Author,
+                ascending: true,
+            }],
+            shorten_names: false,
+            render_substitutions: false,
+        }),
+        ..Default::default()
+    }));
+
+    let who = csln_processor::Reference::from(csl_legacy::csl_json::Reference {
+        id: "who".to_string(),
+        ref_type: "report".to_string(),
+        author: Some(vec![csl_legacy::csl_json::Name {
+            literal: Some("World Health Organization".to_string()),
+            ..Default::default()
+        }]),
+        ..Default::default()
+    });
+    let bib = csln_core::bib_map![
+        "who" => who,
+        "wilson" => make_book("wilson", "Wilson", "Edward", 1975, "Sociobiology"),
+    ];
+    let processor = Processor::new(style, bib);
+    assert_eq!(
+        processor.render_bibliography(),
+        "Wilson, E.\n\nWorld Health Organization"
+    );
+}
+
+#[test]
+fn test_date_position_by_type() {
+    let style: Style = serde_yaml::from_str(
+        r#"
+info:
+  title: Date Position Test
+bibliography:
+  template:
+    - contributor: author
+      form: short
+    - date: issued
+      form: year
+      wrap: parentheses
+      overrides:
+        book:
+          position: end
+    - title: primary
+"#,
+    )
+    .unwrap();
+    let reference = |id: &str, ref_type: &str, author: &str| {
+        csln_processor::Reference::from(csl_legacy::csl_json::Reference {
+            id: id.to_string(),
+            ref_type: ref_type.to_string(),
+            title: Some("Title".to_string()),
+            author: Some(vec![csl_legacy::csl_json::Name::new(author, "John")]),
+            issued: Some(csl_legacy::csl_json::DateVariable::year(2020)),
+            ..Default::default()
+        })
+    };
+    let processor = Processor::new(
+        style,
+        csln_core::bib_map![
+            "article" => reference("article", "article-journal", "Adams"),
+            "book" => reference("book", "book", "Brown")
+        ],
+    );
+    assert_eq!(
+        processor.render_bibliography(),
+        "Adams (2020). Title\n\nBrown. Title (2020)"
+    );
+}
+
+#[test]
+fn test_preprint_submitted_date() {
+    let render = |label_text_case: &str| {
+        let style: Style = serde_yaml::from_str(&format!(
+            r#"
+info:
+  title: Submitted Date Test
+bibliography:
+  template:
+    - contributor: author
+      form: short
+      suffix: ". "
+    - title: primary
+    - date: submitted
+      form: year-month
+      show-label: true
+      {label_text_case}
+      prefix: ". "
+"#
+        ))
+        .unwrap();
+        let legacy: csl_legacy::csl_json::Reference = serde_json::from_value(serde_json::json!({
+            "id": "preprint",
+            "type": "article",
+            "title": "Scaling laws",
+            "author": [{"family": "Smith", "given": "Jane"}],
+            "submitted": {"date-parts": [[2020, 6]]}
+        }))
+        .unwrap();
+        Processor::new(
+            style,
+            csln_core::bib_map!["preprint" => csln_processor::Reference::from(legacy)],
+        )
+        .render_bibliography()
+    };
+    assert_eq!(
+        render("label-text-case: capitalize-first"),
+        "Smith. Scaling laws. Submitted June 2020"
+    );
+    // Without a label case the term is used as the locale writes it.
+    assert_eq!(render(""), "Smith. Scaling laws. submitted June 2020");
+}
+
+fn sort_names_for_locale(locale: &str) -> String {
+    let style: Style = serde_yaml::from_str(
+        r#"
+info:
+  title: Collation Test
+options:
+  processing: author-date
+bibliography:
+  template:
+    - contributor: author
+      form: short
+"#,
+    )
+    .unwrap();
+    let mut bibliography = indexmap::IndexMap::new();
+    for family in ["Zander", "Ångström", "Adams"] {
+        bibliography.insert(
+            family.to_string(),
+            csln_processor::Reference::from(csl_legacy::csl_json::Reference {
+                id: family.to_string(),
+                ref_type: "book".to_string(),
+                author: Some(vec![csl_legacy::csl_json::Name::new(family, "A")]),
+                issued: Some(csl_legacy::csl_json::DateVariable::year(2000)),
+                ..Default::default()
+            }),
+        );
+    }
+    let locale = csln_processor::Locale {
+        locale: locale.to_string(),
+        ..csln_processor::Locale::en_us()
+    };
+    Processor::with_locale(style, bibliography, locale).render_bibliography()
+}
+
+#[test]
+fn test_accented_names_sort_by_collation() {
+    assert_eq!(
+        sort_names_for_locale("en-US"),
+        "Adams\n\nÅngström\n\nZander"
+    );
+}
+
+#[cfg(feature = "icu")]
+#[test]
+fn test_swedish_collation_sorts_ring_a_last() {
+    assert_eq!(
+        sort_names_for_locale("sv-SE"),
+        "Adams\n\nZander\n\nÅngström"
+    );
+}
+
+#[test]
+fn test_bold_literal_text_between_components() {
+    let style: Style = serde_yaml::from_str(
+        r#"
+info:
+  title: Literal Text Test
+bibliography:
+  template:
+    - title: primary
+      suffix: ". "
+    - text: In
+      strong: true
+      suffix: " "
+    - variable: publisher
+"#,
+    )
+    .unwrap();
+    let reference = csln_processor::Reference::from(csl_legacy::csl_json::Reference {
+        id: "ch".to_string(),
+        ref_type: "book".to_string(),
+        title: Some("Walden".to_string()),
+        publisher: Some("Ticknor".to_string()),
+        ..Default::default()
+    });
+    let processor = Processor::new(style, csln_core::bib_map!["ch" => reference]);
+    assert_eq!(processor.render_bibliography(), "Walden. **In** Ticknor");
+
+    let html = processor.render_bibliography_with_format::<csln_processor::render::html::Html>();
+    assert!(html.contains("<b>In</b>"), "{html}");
+}
+
+#[test]
+fn test_conditional_on_doi_presence() {
+    let style: Style = serde_yaml::from_str(
+        r#"
+info:
+  title: Conditional Test
+bibliography:
+  template:
+    - title: primary
+      suffix: ". "
+    - if:
+        present: [doi]
+      then:
+        - variable: doi
+          prefix: "https://doi.org/"
+      else:
+        - text: Retrieved from
+          suffix: " "
+        - variable: url
+"#,
+    )
+    .unwrap();
+    let with_doi = csln_processor::Reference::from(csl_legacy::csl_json::Reference {
+        id: "a".to_string(),
+        ref_type: "article-journal".to_string(),
+        title: Some("Alpha".to_string()),
+        doi: Some("10.1000/xyz".to_string()),
+        url: Some("https://example.com/a".to_string()),
+        ..Default::default()
+    });
+    let without_doi = csln_processor::Reference::from(csl_legacy::csl_json::Reference {
+        id: "b".to_string(),
+        ref_type: "webpage".to_string(),
+        title: Some("Beta".to_string()),
+        url: Some("https://example.com/b".to_string()),
+        ..Default::default()
+    });
+    let without_link = csln_processor::Reference::from(csl_legacy::csl_json::Reference {
+        id: "c".to_string(),
+        ref_type: "book".to_string(),
+        title: Some("Gamma".to_string()),
+        ..Default::default()
+    });
+    let processor = Processor::new(
+        style,
+        csln_core::bib_map!["a" => with_doi, "b" => without_doi, "c" => without_link],
+    );
+    assert_eq!(
+        processor.render_bibliography(),
+        "Alpha. https://doi.org/10.1000/xyz\n\nBeta. Retrieved from https://example.com/b\n\nGamma. "
+    );
+}
+
+#[test]
+fn test_number_labels_per_type() {
+    let style: Style = serde_yaml::from_str(
+        r#"
+info:
+  title: Number Label Test
+bibliography:
+  template:
+    - title: primary
+      suffix: ", "
+    - number: section
+      label-form: short
+      label-forms:
+        statute: symbol
+        all: long
+    - number: issue
+      label-form: short
+      label-text-case: capitalize-first
+"#,
+    )
+    .unwrap();
+    let statute: csl_legacy::csl_json::Reference = serde_json::from_value(serde_json::json!({
+        "id": "act",
+        "type": "legislation",
+        "title": "Clean Air Act",
+        "section": "5"
+    }))
+    .unwrap();
+    let article: csl_legacy::csl_json::Reference = serde_json::from_value(serde_json::json!({
+        "id": "art",
+        "type": "article-journal",
+        "title": "Air Quality",
+        "issue": "3"
+    }))
+    .unwrap();
+    let processor = Processor::new(
+        style,
+        csln_core::bib_map![
+            "act" => csln_processor::Reference::from(statute),
+            "art" => csln_processor::Reference::from(article)
+        ],
+    );
+    assert_eq!(
+        processor.render_bibliography(),
+        "Air Quality, No. 3\n\nClean Air Act, § 5"
+    );
+}
+
+#[test]
+fn test_label_space_from_locale() {
+    let style: Style = serde_yaml::from_str(
+        r#"
+info:
+  title: Label Space Test
+bibliography:
+  template:
+    - title: primary
+      suffix: ", "
+    - number: section
+      label-form: symbol
+"#,
+    )
+    .unwrap();
+    let statute: csl_legacy::csl_json::Reference = serde_json::from_value(serde_json::json!({
+        "id": "act",
+        "type": "legislation",
+        "title": "Clean Air Act",
+        "section": "5"
+    }))
+    .unwrap();
+    let render = |label_space: &str| {
+        let locale = csln_processor::Locale::from_yaml_str(&format!(
+            "locale: en-US\nlabel-space: {label_space}\n"
+        ))
+        .unwrap();
+        let processor = Processor::with_locale(
+            style.clone(),
+            csln_core::bib_map!["act" => csln_processor::Reference::from(statute.clone())],
+            locale,
+        );
+        (
+            processor.render_bibliography(),
+            processor.render_bibliography_with_format::<csln_processor::render::html::Html>(),
+            processor.render_bibliography_with_format::<csln_processor::render::latex::Latex>(),
+            processor.render_bibliography_with_format::<csln_processor::render::djot::Djot>(),
+        )
+    };
+
+    let (plain, html, latex, djot) = render("no-break");
+    assert_eq!(plain, "Clean Air Act, § 5");
+    assert!(html.contains("§&nbsp;5"), "{html}");
+    assert!(latex.contains("§~5"), "{latex}");
+    assert!(djot.contains("§\\ 5"), "{djot}");
+
+    let (plain, html, latex, djot) = render("narrow-no-break");
+    assert_eq!(plain, "Clean Air Act, § 5");
+    assert!(html.contains("§&#8239;5"), "{html}");
+    assert!(latex.contains(r"§\,5"), "{latex}");
+    assert!(djot.contains("§\u{202F}5"), "{djot}");
+
+    // No-break spaces in the data itself are left alone.
+    let (_, html, latex, _) = render("space");
+    assert!(html.contains("§ 5"), "{html}");
+    assert!(latex.contains("§ 5"), "{latex}");
+}
+
+#[test]
+fn test_date_locale_overrides_month_names() {
+    let locales = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../../locales");
+    let style: Style = serde_yaml::from_str(
+        r#"
+info:
+  title: Date Locale Test
+bibliography:
+  template:
+    - title: primary
+      suffix: ". "
+    - term: accessed
+      suffix: " "
+    - date: accessed
+      form: full
+"#,
+    )
+    .unwrap();
+    let reference: csl_legacy::csl_json::Reference = serde_json::from_value(serde_json::json!({
+        "id": "page",
+        "type": "webpage",
+        "title": "Startseite",
+        "accessed": {"date-parts": [[2020, 5, 15]]}
+    }))
+    .unwrap();
+    let mut processor = Processor::with_locale(
+        style,
+        csln_core::bib_map!["page" => csln_processor::Reference::from(reference)],
+        csln_processor::Locale::load("de-DE", &locales),
+    );
+    assert_eq!(
+        processor.render_bibliography(),
+        "Startseite. zugegriffen Mai 15, 2020"
+    );
+
+    processor.set_date_locale(csln_processor::Locale::load("en-US", &locales));
+    assert_eq!(
+        processor.render_bibliography(),
+        "Startseite. zugegriffen May 15, 2020"
+    );
+}
+
+#[test]
+fn test_and_others_term_from_locale() {
+    let locales = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../../locales");
+    let style = |term: &str| -> Style {
+        serde_yaml::from_str(&format!(
+            r#"
+info:
+  title: And Others Test
+bibliography:
+  template:
+    - contributor: author
+      form: short
+      shorten:
+        min: 3
+        use-first: 1
+        and-others: text
+{}"#,
+            term
+        ))
+        .unwrap()
+    };
+    let reference: csl_legacy::csl_json::Reference = serde_json::from_value(serde_json::json!({
+        "id": "multi",
+        "type": "book",
+        "author": [
+            {"family": "Müller", "given": "Anna"},
+            {"family": "Schmidt", "given": "Bernd"},
+            {"family": "Weber", "given": "Clara"}
+        ]
+    }))
+    .unwrap();
+    let render = |style: Style| {
+        Processor::with_locale(
+            style,
+            csln_core::bib_map!["multi" => csln_processor::Reference::from(reference.clone())],
+            csln_processor::Locale::load("de-DE", &locales),
+        )
+        .render_bibliography()
+    };
+
+    assert_eq!(render(style("")), "Müller und andere");
+    assert_eq!(
+        render(style("        term: und Mitarbeiter")),
+        "Müller und Mitarbeiter"
+    );
+}
+
+#[test]
+fn test_book_review_reviewed_work() {
+    let locales = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../../locales");
+    let style: Style = serde_yaml::from_str(
+        r#"
+info:
+  title: Review Test
+bibliography:
+  template:
+    - contributor: author
+      form: long
+      suffix: ". "
+    - title: primary
+      suffix: ". "
+    - title: reviewed
+      label-text-case: capitalize-first
+      emph: true
+    - contributor: reviewed-author
+      form: long
+      name-order: given-first
+      prefix: ", "
+      label:
+        term: reviewed-author
+        form: verb
+        placement: prefix
+"#,
+    )
+    .unwrap();
+    let review: csl_legacy::csl_json::Reference = serde_json::from_value(serde_json::json!({
+        "id": "rev",
+        "type": "review-book",
+        "title": "A Long Walk",
+        "author": [{"family": "Jones", "given": "Ann"}],
+        "container-title": "Review of Books",
+        "reviewed-title": "Walden",
+        "reviewed-author": [{"family": "Thoreau", "given": "Henry David"}]
+    }))
+    .unwrap();
+    let reference = csln_processor::Reference::from(review);
+    assert_eq!(reference.ref_type(), "review-book");
+
+    let processor = Processor::with_locale(
+        style,
+        csln_core::bib_map!["rev" => reference],
+        csln_processor::Locale::load("en-US", &locales),
+    );
+    assert_eq!(
+        processor.render_bibliography(),
+        "Ann Jones. A Long Walk. Review of _Walden_, by Henry David Thoreau"
+    );
+}
+
+#[test]
+fn test_validate_style_against_data_reports_missing_container_title() {
+    let style: Style = serde_yaml::from_str(
+        r#"
+info:
+  title: Preflight Test
+bibliography:
+  template:
+    - contributor: author
+      form: long
+    - title: primary
+    - title: parent-serial
+"#,
+    )
+    .unwrap();
+    let json = |value: serde_json::Value| {
+        let legacy: csl_legacy::csl_json::Reference = serde_json::from_value(value).unwrap();
+        csln_processor::Reference::from(legacy)
+    };
+    let book = json(serde_json::json!({
+        "id": "book",
+        "type": "book",
+        "title": "A Book",
+        "author": [{"family": "Smith", "given": "Jane"}]
+    }));
+    let article = json(serde_json::json!({
+        "id": "article",
+        "type": "article-journal",
+        "title": "An Article",
+        "author": [{"family": "Doe", "given": "John"}],
+        "container-title": "A Journal"
+    }));
+
+    let processor = Processor::new(
+        style,
+        csln_core::bib_map!["book" => book, "article" => article],
+    );
+    let missing = processor.validate_style_against_data();
+    assert_eq!(missing.len(), 1);
+    assert_eq!(missing[0].id, "book");
+    assert_eq!(missing[0].variables, vec!["title:parentserial".to_string()]);
+}
+
+#[test]
+fn test_genre_and_medium_group() {
+    let style: Style = serde_yaml::from_str(
+        r#"
+info:
+  title: Genre Medium Test
+bibliography:
+  template:
+    - title: primary
+    - items:
+        - variable: genre
+        - variable: medium
+      delimiter: comma
+      wrap: brackets
+      prefix: " "
+"#,
+    )
+    .unwrap();
+    let render = |fields: serde_json::Value| {
+        let mut item = serde_json::json!({
+            "id": "film",
+            "type": "motion_picture",
+            "title": "Night Watch"
+        });
+        item.as_object_mut()
+            .unwrap()
+            .extend(fields.as_object().unwrap().clone());
+        let reference: csl_legacy::csl_json::Reference = serde_json::from_value(item).unwrap();
+        Processor::new(
+            style.clone(),
+            csln_core::bib_map!["film" => csln_processor::Reference::from(reference)],
+        )
+        .render_bibliography()
+    };
+
+    assert_eq!(
+        render(serde_json::json!({"genre": "Film", "medium": "DVD"})),
+        "Night Watch [Film, DVD]"
+    );
+    assert_eq!(
+        render(serde_json::json!({"genre": "Film"})),
+        "Night Watch [Film]"
+    );
+    assert_eq!(
+        render(serde_json::json!({"medium": "DVD"})),
+        "Night Watch [DVD]"
+    );
+    assert_eq!(render(serde_json::json!({})), "Night Watch");
+}
@@ -73,6 +73,62 @@ fn test_disambiguate_yearsuffixmixeddates() {
     run_test_case_native(&input, &citation_items, expected, "citation");
 }
 
+/// Test year suffixes on the "n.d." fallback for undated same-author works.
+#[test]
+fn test_disambiguate_yearsuffix_no_date() {
+    use csln_core::template::{
+        DateForm, DateVariable, TemplateComponent, TemplateDate, TemplateTerm, WrapPunctuation,
+    };
+
+    let undated = |id: &str, title: &str| {
+        let mut reference = make_book(id, "Smith", "John", 2020, title);
+        if let csln_processor::Reference::Monograph(m) = &mut reference {
+            m.issued = csln_core::reference::EdtfString(String::new());
+        }
+        reference
+    };
+    let date = TemplateComponent::Date(TemplateDate {
+        date: DateVariable::Issued,
+        form: DateForm::Year,
+        fallback: Some(vec![TemplateComponent::Term(TemplateTerm {
+            term: csln_core::locale::GeneralTerm::NoDate,
+            ..Default::default()
+        })]),
+        rendering: csln_core::template::Rendering {
+            wrap: Some(WrapPunctuation::Parentheses),
+            ..Default::default()
+        },
+        ..Default::default()
+    });
+    let mut style = build_author_date_style(true, false, false, None, None);
+    let template = vec![csln_core::tc_contributor!(Author, Short), date];
+    if let Some(citation) = style.citation.as_mut() {
+        citation.template = Some(template.clone());
+    }
+    style.bibliography = Some(csln_core::BibliographySpec {
+        template: Some(template),
+        ..Default::default()
+    });
+    let processor = Processor::new(
+        style,
+        csln_core::bib_map![
+            "item1" => undated("item1", "Alpha"),
+            "item2" => undated("item2", "Beta"),
+        ],
+    );
+
+    assert_eq!(
+        processor
+            .process_citation(&csln_core::cite!("item2"))
+            .unwrap(),
+        "Smith, (n.d.-b)"
+    );
+    assert_eq!(
+        processor.render_bibliography(),
+        "Smith (n.d.-a)\n\nSmith (n.d.-b)"
+    );
+}
+
 /// Test given name expansion for authors with duplicate family names.
 #[test]
 fn test_disambiguate_bycitetwoauthorssamefamilyname() {
@@ -174,6 +230,9 @@ fn test_disambiguate_addnamesfailure() {
 }
 
 /// Test given name expansion with initial form (initialize_with).
+///
+/// Initials resolve the Does; the Smiths share an initial, so by-cite
+/// goes on to full given names.
 #[test]
 fn test_disambiguate_bycitegivennameshortforminitializewith() {
     let input = vec![
@@ -190,7 +249,7 @@ fn test_disambiguate_bycitegivennameshortforminitializewith() {
     ];
     let expected = "Roe, (2000)
 J Doe, (2000); A Doe, (2000)
-T Smith, (2000); T Smith, (2000)";
+Thomas Smith, (2000); Ted Smith, (2000)";
 
     run_test_case_native_with_options(
         &input,
@@ -320,6 +379,267 @@ fn test_numeric_citation() {
     );
 }
 
+#[test]
+fn test_citation_number_forms() {
+    use csln_core::template::CitationNumberForm;
+    use csln_processor::render::{html::Html, plain::PlainText};
+
+    let number = r#"<span class="csln-citation" data-ref="item2"><span class="csln-citation-number">2</span></span>"#;
+    let cases = [
+        (CitationNumberForm::Bracket, "[2]", format!("[{number}]")),
+        (
+            CitationNumberForm::Parenthesis,
+            "(2)",
+            format!("({number})"),
+        ),
+        (
+            CitationNumberForm::Superscript,
+            "²",
+            format!("<sup>{number}</sup>"),
+        ),
+        (CitationNumberForm::Plain, "2", number.to_string()),
+    ];
+    for (form, plain, html) in cases {
+        let mut style = build_numeric_style();
+        if let Some(citation) = style.citation.as_mut() {
+            citation.citation_number_form = Some(form);
+        }
+        let bib = csln_core::bib_map![
+            "item1" => make_book("item1", "Smith", "John", 2020, "Title A"),
+            "item2" => make_book("item2", "Doe", "Jane", 2021, "Title B"),
+        ];
+        let processor = Processor::new(style, bib);
+        let citation = csln_core::cite!("item2");
+        assert_eq!(
+            processor
+                .process_citation_with_format::<PlainText>(&citation)
+                .unwrap(),
+            plain
+        );
+        assert_eq!(
+            processor
+                .process_citation_with_format::<Html>(&citation)
+                .unwrap(),
+            html
+        );
+    }
+}
+
+// --- Et Al. Tests ---
+
+fn build_et_al_style(et_al_emph: bool, template: csln_core::Template) -> Style {
+    Style {
+        options: Some(Config {
+            contributors: Some(csln_core::options::ContributorConfig {
+                shorten: Some(csln_core::options::ShortenListOptions {
+                    min: 3,
+                    use_first: 1,
+                    et_al_emph,
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }),
+        citation: Some(CitationSpec {
+            template: Some(template),
+            delimiter: Some(String::new()),
+            ..Default::default()
+        }),
+        ..Default::default()
+    }
+}
+
+fn render_et_al<F>(et_al_emph: bool, template: csln_core::Template) -> String
+where
+    F: csln_processor::render::format::OutputFormat<Output = String>,
+{
+    let bib = csln_core::bib_map![
+        "item1" => make_book_multi_author(
+            "item1",
+            vec![("Smith", "John"), ("Jones", "Jane"), ("Brown", "Anne")],
+            2020,
+            "Title",
+        ),
+    ];
+    let processor = Processor::new(build_et_al_style(et_al_emph, template), bib);
+    processor
+        .process_citation_with_format::<F>(&csln_core::cite!("item1"))
+        .unwrap()
+}
+
+#[test]
+fn test_et_al_followed_by_comma() {
+    use csln_processor::render::{html::Html, plain::PlainText};
+
+    let template = || {
+        vec![
+            csln_core::tc_contributor!(Author, Short),
+            csln_core::tc_date!(Issued, Year, prefix = ", "),
+        ]
+    };
+    assert_eq!(
+        render_et_al::<PlainText>(false, template()),
+        "Smith et al., 2020"
+    );
+    assert_eq!(
+        render_et_al::<PlainText>(true, template()),
+        "Smith _et al_., 2020"
+    );
+    assert_eq!(
+        render_et_al::<Html>(true, template()),
+        r#"<span class="csln-citation" data-ref="item1">Smith <i>et al</i>.<span class="csln-issued">, 2020</span></span>"#
+    );
+}
+
+#[test]
+fn test_et_al_followed_by_period() {
+    use csln_processor::render::plain::PlainText;
+
+    let template = || {
+        vec![
+            csln_core::tc_contributor!(Author, Short),
+            csln_core::tc_title!(Primary, prefix = ". "),
+        ]
+    };
+    assert_eq!(
+        render_et_al::<PlainText>(false, template()),
+        "Smith et al. Title"
+    );
+    assert_eq!(
+        render_et_al::<PlainText>(true, template()),
+        "Smith _et al_. Title"
+    );
+}
+
+/// Citation and bibliography options carry separate et-al thresholds.
+#[test]
+fn test_et_al_threshold_per_context() {
+    let contributors = |min: u8| csln_core::options::ContributorConfig {
+        shorten: Some(csln_core::options::ShortenListOptions {
+            min,
+            use_first: 1,
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let style = Style {
+        citation: Some(CitationSpec {
+            options: Some(Config {
+                contributors: Some(contributors(3)),
+                ..Default::default()
+            }),
+            template: Some(vec![csln_core::tc_contributor!(Author, Short)]),
+            ..Default::default()
+        }),
+        bibliography: Some(csln_core::BibliographySpec {
+            options: Some(Config {
+                contributors: Some(contributors(21)),
+                ..Default::default()
+            }),
+            template: Some(vec![csln_core::tc_contributor!(Author, Short)]),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let bib = csln_core::bib_map![
+        "item1" => make_book_multi_author(
+            "item1",
+            vec![("Smith", "John"), ("Jones", "Jane"), ("Brown", "Anne"), ("Green", "Paul")],
+            2020,
+            "Title",
+        ),
+    ];
+    let processor = Processor::new(style, bib);
+
+    assert_eq!(
+        processor
+            .process_citation(&csln_core::cite!("item1"))
+            .unwrap(),
+        "Smith et al."
+    );
+    let bibliography = processor.render_bibliography();
+    assert!(bibliography.contains("Green"), "{bibliography}");
+    assert!(!bibliography.contains("et al."), "{bibliography}");
+}
+
+/// Later citations of a reference use the subsequent et-al thresholds.
+#[test]
+fn test_et_al_subsequent() {
+    let style = |subsequent| Style {
+        options: Some(Config {
+            contributors: Some(csln_core::options::ContributorConfig {
+                shorten: Some(csln_core::options::ShortenListOptions {
+                    min: 4,
+                    use_first: 4,
+                    subsequent,
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }),
+        citation: Some(CitationSpec {
+            template: Some(vec![csln_core::tc_contributor!(Author, Short)]),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let bib = || {
+        csln_core::bib_map![
+            "item1" => make_book_multi_author(
+                "item1",
+                vec![("Smith", "John"), ("Jones", "Jane"), ("Brown", "Anne")],
+                2020,
+                "Title",
+            ),
+            "item2" => make_book_multi_author(
+                "item2",
+                vec![("Green", "Paul"), ("White", "Mary"), ("Black", "Tom")],
+                2021,
+                "Other Title",
+            ),
+        ]
+    };
+    let citations = [
+        csln_core::cite!("item1"),
+        csln_core::cite!("item2"),
+        csln_core::cite!("item1"),
+    ];
+
+    let processor = Processor::new(
+        style(Some(csln_core::options::ShortenSubsequent {
+            min: 3,
+            use_first: 1,
+        })),
+        bib(),
+    );
+    assert_eq!(
+        processor.process_citations(&citations).unwrap(),
+        vec!["Smith, Jones, Brown", "Green, White, Black", "Smith et al."]
+    );
+    // Positions are per run, so rendering again gives the same output and a
+    // lone citation is always a first citation.
+    assert_eq!(
+        processor.process_citations(&citations).unwrap(),
+        vec!["Smith, Jones, Brown", "Green, White, Black", "Smith et al."]
+    );
+    assert_eq!(
+        processor.process_citation(&citations[0]).unwrap(),
+        "Smith, Jones, Brown"
+    );
+
+    let processor = Processor::new(style(None), bib());
+    assert_eq!(
+        processor.process_citations(&citations).unwrap(),
+        vec![
+            "Smith, Jones, Brown",
+            "Green, White, Black",
+            "Smith, Jones, Brown"
+        ]
+    );
+}
+
 // --- Sorting and Grouping Tests ---
 
 /// Test basic multi-item citation sorting by author.
@@ -349,3 +669,285 @@ fn test_grouped_citation_sorting_by_year() {
 
     run_test_case_native(&input, &citation_items, expected, "citation");
 }
+
+// --- Cluster Wrapping Tests ---
+
+fn render_cluster(merge_parentheticals: Option<bool>, prefix: Option<&str>) -> String {
+    let style = Style {
+        citation: Some(CitationSpec {
+            template: Some(vec![
+                csln_core::tc_contributor!(Author, Short),
+                csln_core::tc_date!(Issued, Year),
+            ]),
+            delimiter: Some(" ".to_string()),
+            wrap: Some(csln_core::template::WrapPunctuation::Parentheses),
+            merge_parentheticals,
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let bibliography = csln_core::bib_map![
+        "hawking" => make_book("hawking", "Hawking", "Stephen", 1988, "A Brief History of Time"),
+        "kuhn" => make_book("kuhn", "Kuhn", "Thomas", 1962, "The Structure of Scientific Revolutions"),
+    ];
+    let processor = Processor::new(style, bibliography);
+    let citation = csln_processor::Citation {
+        items: ["hawking", "kuhn"]
+            .map(|id| csln_processor::CitationItem {
+                id: id.to_string(),
+                ..Default::default()
+            })
+            .to_vec(),
+        prefix: prefix.map(str::to_string),
+        ..Default::default()
+    };
+    processor.process_citation(&citation).unwrap()
+}
+
+#[test]
+fn test_cluster_merged_parenthetical() {
+    assert_eq!(render_cluster(None, None), "(Hawking 1988; Kuhn 1962)");
+    assert_eq!(
+        render_cluster(Some(true), None),
+        "(Hawking 1988; Kuhn 1962)"
+    );
+}
+
+#[test]
+fn test_cluster_separate_parentheticals() {
+    assert_eq!(
+        render_cluster(Some(false), None),
+        "(Hawking 1988) (Kuhn 1962)"
+    );
+    assert_eq!(
+        render_cluster(Some(false), Some("see")),
+        "(see Hawking 1988) (Kuhn 1962)"
+    );
+}
+
+#[test]
+fn test_and_term_per_context() {
+    let style: Style = serde_yaml::from_str(
+        r#"
+info:
+  title: And Test
+options:
+  contributors:
+    display-as-sort: none
+    delimiter-precedes-last: contextual
+citation:
+  options:
+    contributors:
+      and: symbol
+  template:
+    - contributor: author
+      form: short
+    - date: issued
+      form: year
+  delimiter: ", "
+  wrap: parentheses
+bibliography:
+  options:
+    contributors:
+      and: text
+  template:
+    - contributor: author
+      form: short
+"#,
+    )
+    .unwrap();
+    let reference = csln_processor::Reference::from(csl_legacy::csl_json::Reference {
+        id: "item".to_string(),
+        ref_type: "book".to_string(),
+        author: Some(vec![
+            csl_legacy::csl_json::Name::new("Smith", "John"),
+            csl_legacy::csl_json::Name::new("Jones", "Jane"),
+        ]),
+        issued: Some(csl_legacy::csl_json::DateVariable::year(2020)),
+        ..Default::default()
+    });
+    let processor = Processor::new(
+        style.clone(),
+        csln_core::bib_map!["item" => reference.clone()],
+    );
+    let citation = csln_core::citation::Citation {
+        items: vec![csln_core::citation::CitationItem {
+            id: "item".to_string(),
+            ..Default::default()
+        }],
+        ..Default::default()
+    };
+    assert_eq!(
+        processor.process_citation(&citation).unwrap(),
+        "(Smith & Jones, 2020)"
+    );
+    assert_eq!(processor.render_bibliography(), "Smith and Jones");
+
+    // A component-level `and` overrides the context options.
+    let mut style = style;
+    let template = style
+        .bibliography
+        .as_mut()
+        .unwrap()
+        .template
+        .as_mut()
+        .unwrap();
+    let csln_core::template::TemplateComponent::Contributor(author) = &mut template[0] else {
+        panic!("expected a contributor component");
+    };
+    author.and = Some(csln_core::options::AndOptions::Symbol);
+    let processor = Processor::new(style, csln_core::bib_map!["item" => reference]);
+    assert_eq!(processor.render_bibliography(), "Smith & Jones");
+}
+
+#[test]
+fn test_year_form_in_citation_with_full_date() {
+    let style: Style = serde_yaml::from_str(
+        r#"
+info:
+  title: Date Form Test
+citation:
+  template:
+    - contributor: author
+      form: short
+    - date: issued
+      form: year
+  delimiter: ", "
+  wrap: parentheses
+bibliography:
+  template:
+    - contributor: author
+      form: short
+      suffix: ". "
+    - date: issued
+      form: full
+"#,
+    )
+    .unwrap();
+    let reference = csln_processor::Reference::from(csl_legacy::csl_json::Reference {
+        id: "item".to_string(),
+        ref_type: "article-newspaper".to_string(),
+        author: Some(vec![csl_legacy::csl_json::Name::new("Smith", "John")]),
+        issued: Some(csl_legacy::csl_json::DateVariable::full(2020, 5, 15)),
+        ..Default::default()
+    });
+    let processor = Processor::new(style, csln_core::bib_map!["item" => reference]);
+    let citation = csln_core::citation::Citation {
+        items: vec![csln_core::citation::CitationItem {
+            id: "item".to_string(),
+            ..Default::default()
+        }],
+        ..Default::default()
+    };
+    assert_eq!(
+        processor.process_citation(&citation).unwrap(),
+        "(Smith, 2020)"
+    );
+    assert_eq!(processor.render_bibliography(), "Smith. May 15, 2020");
+}
+
+#[test]
+fn test_affix_smart_spacing() {
+    let style: Style = serde_yaml::from_str(
+        r#"
+info:
+  title: Affix Spacing Test
+citation:
+  template:
+    - contributor: author
+      form: short
+    - date: issued
+      form: year
+  delimiter: ", "
+  wrap: parentheses
+"#,
+    )
+    .unwrap();
+    let processor = Processor::new(
+        style,
+        csln_core::bib_map!["kuhn" => make_book("kuhn", "Kuhn", "Thomas", 1962, "Structure")],
+    );
+    let cite = |prefix: &str, suffix: &str| {
+        let citation = csln_core::citation::Citation {
+            items: vec![csln_core::citation::CitationItem {
+                id: "kuhn".to_string(),
+                prefix: Some(prefix.to_string()),
+                suffix: Some(suffix.to_string()),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        processor.process_citation(&citation).unwrap()
+    };
+
+    assert_eq!(cite("see", ""), "(see Kuhn, 1962)");
+    assert_eq!(cite("see ", ""), "(see Kuhn, 1962)");
+    assert_eq!(cite("", "chap. 3"), "(Kuhn, 1962 chap. 3)");
+    assert_eq!(cite("", ", chap. 3"), "(Kuhn, 1962, chap. 3)");
+
+    let citation = csln_core::citation::Citation {
+        prefix: Some("see".to_string()),
+        suffix: Some(", for a review".to_string()),
+        items: vec![csln_core::citation::CitationItem {
+            id: "kuhn".to_string(),
+            ..Default::default()
+        }],
+        ..Default::default()
+    };
+    assert_eq!(
+        processor.process_citation(&citation).unwrap(),
+        "(see Kuhn, 1962, for a review)"
+    );
+}
+
+#[test]
+fn test_classical_citation_year_era() {
+    let style = |era: &str, floruit: bool| -> Style {
+        serde_yaml::from_str(&format!(
+            r#"
+info:
+  title: Classics Test
+options:
+  dates:
+    month: long
+    era: {era}
+citation:
+  delimiter: " "
+  template:
+    - contributor: author
+      form: short
+    - date: issued
+      form: year
+      floruit: {floruit}
+"#
+        ))
+        .unwrap()
+    };
+    let reference = |id: &str, family: &str, year: i32| {
+        let reference: csl_legacy::csl_json::Reference =
+            serde_json::from_value(serde_json::json!({
+                "id": id,
+                "type": "book",
+                "title": "Works",
+                "author": [{"family": family}],
+                "issued": {"date-parts": [[year]]}
+            }))
+            .unwrap();
+        csln_processor::Reference::from(reference)
+    };
+    let bib = csln_core::bib_map![
+        "aristotle" => reference("aristotle", "Aristotle", -350),
+        "strabo" => reference("strabo", "Strabo", 14),
+        "gibbon" => reference("gibbon", "Gibbon", 1776),
+    ];
+    let cite = |style: Style, id: &str| {
+        Processor::new(style, bib.clone())
+            .process_citation(&csln_core::cite!(id))
+            .unwrap()
+    };
+
+    assert_eq!(cite(style("bce", false), "aristotle"), "Aristotle 350 BCE");
+    assert_eq!(cite(style("bce", false), "strabo"), "Strabo 14 CE");
+    assert_eq!(cite(style("bce", false), "gibbon"), "Gibbon 1776");
+    assert_eq!(cite(style("bc", true), "aristotle"), "Aristotle fl. 350 BC");
+}
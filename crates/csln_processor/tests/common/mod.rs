@@ -50,12 +50,16 @@ pub fn make_book_multi_author(
         author: Some(Contributor::ContributorList(ContributorList(author_list))),
         editor: None,
         translator: None,
+        composer: None,
+        illustrator: None,
         issued: EdtfString(year.to_string()),
+        submitted: None,
         publisher: None,
         url: None,
         accessed: None,
         language: None,
         note: None,
+        abstract_text: None,
         isbn: None,
         doi: None,
         edition: None,
@@ -66,6 +70,15 @@ pub fn make_book_multi_author(
         keywords: None,
         original_date: None,
         original_title: None,
+        original_publisher: None,
+        number_of_volumes: None,
+        number_of_pages: None,
+        archive: None,
+        archive_place: None,
+        archive_location: None,
+        call_number: None,
+        scale: None,
+        dimensions: None,
     }))
 }
 
@@ -100,7 +113,9 @@ pub fn make_article_multi_author(
         title: Some(Title::Single(title.to_string())),
         author: Some(Contributor::ContributorList(ContributorList(author_list))),
         translator: None,
+        director: None,
         issued: EdtfString(year.to_string()),
+        submitted: None,
         parent: Parent::Embedded(Serial {
             r#type: SerialType::AcademicJournal,
             title: Title::Single(String::new()),
@@ -112,13 +127,19 @@ pub fn make_article_multi_author(
         accessed: None,
         language: None,
         note: None,
+        abstract_text: None,
         doi: None,
         pages: None,
         volume: None,
         issue: None,
         genre: None,
         medium: None,
+        pmid: None,
+        pmcid: None,
+        dimensions: None,
         keywords: None,
+        reviewed_title: None,
+        reviewed_author: None,
     }))
 }
 
@@ -167,12 +188,16 @@ pub fn make_multilingual_book(
         })),
         editor: None,
         translator: None,
+        composer: None,
+        illustrator: None,
         issued: EdtfString(year.to_string()),
+        submitted: None,
         publisher: None,
         url: None,
         accessed: None,
         language: None,
         note: None,
+        abstract_text: None,
         isbn: None,
         doi: None,
         edition: None,
@@ -183,6 +208,15 @@ pub fn make_multilingual_book(
         keywords: None,
         original_date: None,
         original_title: None,
+        original_publisher: None,
+        number_of_volumes: None,
+        number_of_pages: None,
+        archive: None,
+        archive_place: None,
+        archive_location: None,
+        call_number: None,
+        scale: None,
+        dimensions: None,
     }))
 }
 
@@ -312,6 +346,8 @@ pub fn build_author_date_style(
             year_suffix: disambiguate_year_suffix,
             names: disambiguate_names,
             add_givenname: disambiguate_givenname,
+            strategy: None,
+            givenname_rule: None,
         })
     } else {
         None
@@ -360,12 +396,14 @@ pub fn build_author_date_style(
                         ascending: true,
                         order: None,
                         sort_order: None,
+                        empty_author: None,
                     },
                     csln_core::grouping::GroupSortKey {
                         key: csln_core::grouping::SortKey::Issued,
                         ascending: true,
                         order: None,
                         sort_order: None,
+                        empty_author: None,
                     },
                 ],
             }),
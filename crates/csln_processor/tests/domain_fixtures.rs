@@ -82,6 +82,15 @@ fn test_scientific_fixture_is_covered_in_processor_tests() {
     assert!(software.contains("R Core Team"));
     assert!(rendered_bib.contains("[Dataset]"));
     assert!(rendered_bib.contains("Patent No. 7,347,809"));
+    // Software converts to its own type, so APA's software override drops
+    // the title emphasis it gives books.
+    assert!(
+        rendered_bib.contains(
+            "R Core Team. (2021). R: A language and environment for statistical computing. \
+             R Foundation for Statistical Computing."
+        ),
+        "{rendered_bib}"
+    );
 }
 
 #[test]
@@ -99,3 +108,35 @@ fn test_multilingual_fixture_is_covered_in_processor_tests() {
     assert!(rendered_bib.contains("Nhà xuất bản"));
     assert!(rendered_bib.contains("Oxford University Press"));
 }
+
+#[test]
+fn test_springer_and_others_term() {
+    let root = project_root();
+    let style = load_style(&root.join("styles/springer-basic-author-date.yaml"));
+    let reference: csl_legacy::csl_json::Reference = serde_json::from_value(serde_json::json!({
+        "id": "many",
+        "type": "book",
+        "title": "Collected Papers",
+        "issued": {"date-parts": [[2020]]},
+        "author": [
+            {"family": "Adams", "given": "Ann"},
+            {"family": "Baker", "given": "Ben"},
+            {"family": "Clark", "given": "Cora"},
+            {"family": "Davis", "given": "Dan"},
+            {"family": "Evans", "given": "Eve"}
+        ]
+    }))
+    .unwrap();
+
+    let processor = Processor::new(
+        style,
+        csln_core::bib_map!["many" => csln_processor::Reference::from(reference)],
+    );
+    let rendered = processor.render_bibliography();
+
+    // The style spells out its own unpunctuated "et al".
+    assert!(
+        rendered.starts_with("Adams A, Baker B, Clark C, et al (2020)"),
+        "{rendered}"
+    );
+}
@@ -497,12 +497,16 @@ fn test_multilingual_rendering_numeric_integral_translated() {
                 })),
                 editor: None,
                 translator: None,
+                composer: None,
+                illustrator: None,
                 issued: csln_core::reference::EdtfString("1869".to_string()),
+                submitted: None,
                 publisher: None,
                 url: None,
                 accessed: None,
                 language: None,
                 note: None,
+                abstract_text: None,
                 isbn: None,
                 doi: None,
                 edition: None,
@@ -513,6 +517,15 @@ fn test_multilingual_rendering_numeric_integral_translated() {
                 keywords: None,
                 original_date: None,
                 original_title: None,
+                original_publisher: None,
+                number_of_volumes: None,
+                number_of_pages: None,
+                archive: None,
+                archive_place: None,
+                archive_location: None,
+                call_number: None,
+                scale: None,
+                dimensions: None,
             },
         )),
     );
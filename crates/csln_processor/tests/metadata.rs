@@ -8,8 +8,8 @@ use common::*;
 
 use csln_core::{
     CitationSpec, Style, StyleInfo,
-    locale::{GeneralTerm, TermForm},
-    options::{Config, ContributorConfig, Processing, ShortenListOptions},
+    locale::{GeneralTerm, Locale, TermForm},
+    options::{Config, ContributorConfig, DateConfig, MonthFormat, Processing, ShortenListOptions},
     template::{
         ContributorForm, ContributorRole, DateForm, DateVariable as TDateVar, TemplateComponent,
         TemplateContributor, TemplateDate, TemplateTerm,
@@ -74,6 +74,42 @@ fn build_date_style(form: DateForm) -> Style {
     }
 }
 
+fn render_date(form: DateForm, dates: DateConfig, locale: Locale, edtf: &str) -> String {
+    let mut style = build_date_style(form);
+    if let Some(options) = style.options.as_mut() {
+        options.dates = Some(dates);
+    }
+    let mut item = make_book("item1", "Smith", "J", 2020, "Title");
+    if let csln_core::reference::InputReference::Monograph(m) = &mut item {
+        m.issued = csln_core::reference::EdtfString(edtf.to_string());
+    }
+    let processor = Processor::with_locale(style, csln_core::bib_map!["item1" => item], locale);
+    processor
+        .process_citation(&csln_core::cite!("item1"))
+        .unwrap()
+}
+
+fn de_de_locale() -> Locale {
+    Locale::from_yaml_str(
+        r#"
+locale: de-DE
+dates:
+  months:
+    long: [Januar, Februar, März, April, Mai, Juni, Juli, August, September, Oktober, November, Dezember]
+    short: [Jan., Feb., März, Apr., Mai, Juni, Juli, Aug., Sep., Okt., Nov., Dez.]
+  numeric-delimiter: "."
+"#,
+    )
+    .unwrap()
+}
+
+fn numeric_dates() -> DateConfig {
+    DateConfig {
+        month: MonthFormat::Numeric,
+        ..Default::default()
+    }
+}
+
 // --- Name Rendering Tests ---
 
 #[test]
@@ -267,6 +303,98 @@ fn test_date_rendering_day_month_abbr_year() {
     );
 }
 
+#[test]
+fn test_date_delimiter_numeric_by_locale() {
+    assert_eq!(
+        render_date(
+            DateForm::Full,
+            numeric_dates(),
+            Locale::en_us(),
+            "2020-05-15"
+        ),
+        "5/15/2020"
+    );
+    assert_eq!(
+        render_date(
+            DateForm::DayMonthAbbrYear,
+            numeric_dates(),
+            de_de_locale(),
+            "2020-05-15"
+        ),
+        "15.5.2020"
+    );
+}
+
+#[test]
+fn test_date_numeric_closed_range() {
+    assert_eq!(
+        render_date(
+            DateForm::Full,
+            numeric_dates(),
+            Locale::en_us(),
+            "2020-05-15/2020-06-01"
+        ),
+        "5/15/2020–6/1/2020"
+    );
+    assert_eq!(
+        render_date(
+            DateForm::DayMonthAbbrYear,
+            numeric_dates(),
+            de_de_locale(),
+            "2020-05-15/2020-06-01"
+        ),
+        "15.5.2020–1.6.2020"
+    );
+}
+
+#[test]
+fn test_date_delimiter_long_by_locale() {
+    assert_eq!(
+        render_date(
+            DateForm::Full,
+            DateConfig::default(),
+            Locale::en_us(),
+            "2020-05-15"
+        ),
+        "May 15, 2020"
+    );
+    assert_eq!(
+        render_date(
+            DateForm::DayMonthAbbrYear,
+            DateConfig::default(),
+            de_de_locale(),
+            "2020-05-15"
+        ),
+        "15 Mai 2020"
+    );
+}
+
+#[test]
+fn test_date_delimiter_explicit_override() {
+    let dates = DateConfig {
+        delimiter: Some("-".to_string()),
+        ..numeric_dates()
+    };
+    assert_eq!(
+        render_date(
+            DateForm::YearMonthDay,
+            dates.clone(),
+            Locale::en_us(),
+            "2020-05-15"
+        ),
+        "2020-5-15"
+    );
+    assert_eq!(
+        render_date(
+            DateForm::DayMonthAbbrYear,
+            dates,
+            de_de_locale(),
+            "2020-05-15"
+        ),
+        "15-5-2020"
+    );
+}
+
 #[test]
 fn test_date_rendering_range() {
     let style = build_date_style(DateForm::Year);
@@ -289,6 +417,27 @@ fn test_date_rendering_range() {
     );
 }
 
+#[test]
+fn test_date_range_dash_and_narrative_forms() {
+    assert_eq!(
+        render_date(
+            DateForm::Year,
+            DateConfig::default(),
+            Locale::en_us(),
+            "2004/2006"
+        ),
+        "2004–2006"
+    );
+    let narrative = DateConfig {
+        range_form: Some(csln_core::options::DateRangeForm::Narrative),
+        ..Default::default()
+    };
+    assert_eq!(
+        render_date(DateForm::Year, narrative, Locale::en_us(), "2004/2006"),
+        "between 2004 and 2006"
+    );
+}
+
 #[test]
 fn test_date_rendering_open_range() {
     let style = build_date_style(DateForm::Year);